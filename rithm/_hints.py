@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+from collections.abc import Iterable as _Iterable
 from numbers import Rational as _Rational
 from typing import (
     SupportsIndex as _SupportsIndex,
@@ -21,8 +22,21 @@ if TYPE_CHECKING:
         @property
         def denominator(self) -> Int: ...
 
+        def continued_fraction_terms(self, /) -> list[Int]: ...
+
+        def convergents(self, /) -> list[_Self]: ...
+
+        def limit_denominator(
+            self, max_denominator: Int | int, /
+        ) -> _Self: ...
+
         def round(self, tie_breaking: _TieBreaking, /) -> Int: ...
 
+        @classmethod
+        def from_continued_fraction_terms(
+            cls, terms: _Iterable[Int | int], /
+        ) -> _Self: ...
+
         __module__ = 'rithm.fraction'
 
         @_overload
@@ -55,7 +69,7 @@ if TYPE_CHECKING:
         ) -> tuple[Int, _Self]: ...
 
         @_overload
-        def __eq__(self, other: _Self | Int | int, /) -> bool: ...
+        def __eq__(self, other: _Self | Int | float | int, /) -> bool: ...
 
         @_overload
         def __eq__(self, other: _Any, /) -> _Any: ...
@@ -68,15 +82,15 @@ if TYPE_CHECKING:
 
         def __floordiv__(self, divisor: _Self | Int | int, /) -> Int: ...
 
-        def __ge__(self, other: _Self | Int | int, /) -> bool: ...
+        def __ge__(self, other: _Self | Int | float | int, /) -> bool: ...
 
-        def __gt__(self, other: _Self | Int | int, /) -> bool: ...
+        def __gt__(self, other: _Self | Int | float | int, /) -> bool: ...
 
         def __hash__(self, /) -> int: ...
 
-        def __le__(self, other: _Self | Int | int, /) -> bool: ...
+        def __le__(self, other: _Self | Int | float | int, /) -> bool: ...
 
-        def __lt__(self, other: _Self | Int | int, /) -> bool: ...
+        def __lt__(self, other: _Self | Int | float | int, /) -> bool: ...
 
         def __mod__(self, divisor: _Self | Int | int, /) -> _Self: ...
 
@@ -173,7 +187,7 @@ if TYPE_CHECKING:
         def __divmod__(self, other: _Self | int, /) -> tuple[_Self, _Self]: ...
 
         @_overload
-        def __eq__(self, other: _Self | int, /) -> bool: ...
+        def __eq__(self, other: _Self | float | int, /) -> bool: ...
 
         @_overload
         def __eq__(self, other: _Any, /) -> _Any: ...
@@ -186,11 +200,11 @@ if TYPE_CHECKING:
 
         def __floordiv__(self, other: _Self | int, /) -> _Self: ...
 
-        def __ge__(self, other: _Self | int, /) -> bool: ...
+        def __ge__(self, other: _Self | float | int, /) -> bool: ...
 
         def __reduce__(self, /) -> tuple[tuple[_Self], tuple[int]]: ...
 
-        def __gt__(self, other: _Self | int, /) -> bool: ...
+        def __gt__(self, other: _Self | float | int, /) -> bool: ...
 
         def __hash__(self, /) -> int: ...
 
@@ -200,11 +214,11 @@ if TYPE_CHECKING:
 
         def __invert__(self, /) -> _Self: ...
 
-        def __le__(self, other: _Self | int, /) -> bool: ...
+        def __le__(self, other: _Self | float | int, /) -> bool: ...
 
         def __lshift__(self, other: _Self | int, /) -> _Self: ...
 
-        def __lt__(self, other: _Self | int, /) -> bool: ...
+        def __lt__(self, other: _Self | float | int, /) -> bool: ...
 
         def __mod__(self, other: _Self | int, /) -> _Self: ...
 
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
-from math import gcd as _gcd
+from collections.abc import Iterable
+from math import gcd as _gcd, isfinite as _isfinite
 from numbers import Integral, Rational
 from operator import mul
 from sys import hash_info
@@ -112,7 +113,7 @@ class Int:
         return type(self)(quotient), type(self)(remainder)
 
     @overload
-    def __eq__(self, other: Self | int, /) -> bool: ...
+    def __eq__(self, other: Self | float | int, /) -> bool: ...
 
     @overload
     def __eq__(self, other: Any, /) -> Any: ...
@@ -123,7 +124,7 @@ class Int:
             if isinstance(other, Int)
             else (
                 self._value == other
-                if isinstance(other, int)
+                if isinstance(other, (float, int))
                 else NotImplemented
             )
         )
@@ -145,13 +146,13 @@ class Int:
             )
         )
 
-    def __ge__(self, other: Int | int, /) -> bool:
+    def __ge__(self, other: Int | float | int, /) -> bool:
         return (
             self._value >= other._value
             if isinstance(other, Int)
             else (
                 self._value >= other
-                if isinstance(other, int)
+                if isinstance(other, (float, int))
                 else NotImplemented
             )
         )
@@ -159,13 +160,13 @@ class Int:
     def __reduce__(self, /) -> tuple[type[Self], tuple[int]]:
         return type(self), (self._value,)
 
-    def __gt__(self, other: Self | int, /) -> bool:
+    def __gt__(self, other: Self | float | int, /) -> bool:
         return (
             self._value > other._value
             if isinstance(other, Int)
             else (
                 self._value > other
-                if isinstance(other, int)
+                if isinstance(other, (float, int))
                 else NotImplemented
             )
         )
@@ -181,13 +182,13 @@ class Int:
 
     __int__ = __index__
 
-    def __le__(self, other: Self | int, /) -> bool:
+    def __le__(self, other: Self | float | int, /) -> bool:
         return (
             self._value <= other._value
             if isinstance(other, Int)
             else (
                 self._value <= other
-                if isinstance(other, int)
+                if isinstance(other, (float, int))
                 else NotImplemented
             )
         )
@@ -203,13 +204,13 @@ class Int:
             )
         )
 
-    def __lt__(self, other: Int | int, /) -> bool:
+    def __lt__(self, other: Int | float | int, /) -> bool:
         return (
             self._value < other._value
             if isinstance(other, Int)
             else (
                 self._value < other
-                if isinstance(other, int)
+                if isinstance(other, (float, int))
                 else NotImplemented
             )
         )
@@ -440,6 +441,61 @@ class Fraction:
     def numerator(self) -> _Int:
         return self._numerator
 
+    def continued_fraction_terms(self, /) -> list[_Int]:
+        result = []
+        dividend, divisor = self.numerator, self.denominator
+        while divisor:
+            term, remainder = divmod(dividend, divisor)
+            result.append(term)
+            dividend, divisor = divisor, remainder
+        return result
+
+    def convergents(self, /) -> list[Self]:
+        result = []
+        previous_numerator, previous_denominator = _ZERO, _ONE
+        numerator, denominator = _ONE, _ZERO
+        for term in self.continued_fraction_terms():
+            previous_numerator, numerator = (
+                numerator,
+                term * numerator + previous_numerator,
+            )
+            previous_denominator, denominator = (
+                denominator,
+                term * denominator + previous_denominator,
+            )
+            result.append(type(self)(numerator, denominator, _normalize=False))
+        return result
+
+    def limit_denominator(self, max_denominator: _Int | int, /) -> Self:
+        max_denominator = _Int(max_denominator)
+        if max_denominator < 1:
+            raise ValueError('Maximum denominator should be positive.')
+        if self.denominator <= max_denominator:
+            return self
+        previous_numerator, previous_denominator = _ZERO, _ONE
+        numerator, denominator = _ONE, _ZERO
+        dividend, divisor = self.numerator, self.denominator
+        while True:
+            term, remainder = divmod(dividend, divisor)
+            next_denominator = previous_denominator + term * denominator
+            if next_denominator > max_denominator:
+                break
+            previous_numerator, numerator = (
+                numerator,
+                previous_numerator + term * numerator,
+            )
+            previous_denominator, denominator = denominator, next_denominator
+            dividend, divisor = divisor, remainder
+        scale = (max_denominator - previous_denominator) // denominator
+        bound_denominator = previous_denominator + scale * denominator
+        if bound_denominator <= self.denominator // 2 // divisor:
+            return type(self)(numerator, denominator, _normalize=False)
+        return type(self)(
+            previous_numerator + scale * numerator,
+            bound_denominator,
+            _normalize=False,
+        )
+
     def round(self, tie_breaking: _TieBreaking, /) -> _Int:
         quotient, remainder = divmod(self.numerator, self.denominator)
         double_remainder = remainder * 2
@@ -458,6 +514,23 @@ class Fraction:
             else quotient
         )
 
+    @classmethod
+    def from_continued_fraction_terms(
+        cls, terms: Iterable[_Int | int], /
+    ) -> Self:
+        previous_numerator, previous_denominator = _ZERO, _ONE
+        numerator, denominator = _ONE, _ZERO
+        for term in map(_Int, terms):
+            previous_numerator, numerator = (
+                numerator,
+                term * numerator + previous_numerator,
+            )
+            previous_denominator, denominator = (
+                denominator,
+                term * denominator + previous_denominator,
+            )
+        return cls(numerator, denominator)
+
     _denominator: _Int
     _numerator: _Int
 
@@ -561,7 +634,7 @@ class Fraction:
         )
 
     @overload
-    def __eq__(self, other: _Int | Self | int, /) -> bool: ...
+    def __eq__(self, other: _Int | Self | float | int, /) -> bool: ...
 
     @overload
     def __eq__(self, other: Any, /) -> Any: ...
@@ -574,7 +647,11 @@ class Fraction:
             else (
                 self.denominator == _ONE and self.numerator == other
                 if isinstance(other, (_Int, int))
-                else NotImplemented
+                else (
+                    _isfinite(other) and self == Fraction(other)
+                    if isinstance(other, float)
+                    else NotImplemented
+                )
             )
         )
 
@@ -596,7 +673,7 @@ class Fraction:
             )
         )
 
-    def __ge__(self, other: _Int | Self | int, /) -> bool:
+    def __ge__(self, other: _Int | Self | float | int, /) -> bool:
         return (
             self.numerator * other.denominator
             >= other.numerator * self.denominator
@@ -604,11 +681,19 @@ class Fraction:
             else (
                 self.numerator >= other * self.denominator
                 if isinstance(other, (_Int, int))
-                else NotImplemented
+                else (
+                    (
+                        self >= Fraction(other)
+                        if _isfinite(other)
+                        else other < 0
+                    )
+                    if isinstance(other, float)
+                    else NotImplemented
+                )
             )
         )
 
-    def __gt__(self, other: Self | _Int | int, /) -> bool:
+    def __gt__(self, other: Self | _Int | float | int, /) -> bool:
         return (
             self.numerator * other.denominator
             > other.numerator * self.denominator
@@ -616,7 +701,11 @@ class Fraction:
             else (
                 self.numerator > other * self.denominator
                 if isinstance(other, (_Int, int))
-                else NotImplemented
+                else (
+                    (self > Fraction(other) if _isfinite(other) else other < 0)
+                    if isinstance(other, float)
+                    else NotImplemented
+                )
             )
         )
 
@@ -632,7 +721,7 @@ class Fraction:
         result = result if self >= 0 else -result
         return -2 if result == -1 else int(result)
 
-    def __le__(self, other: _Int | Self | int, /) -> bool:
+    def __le__(self, other: _Int | Self | float | int, /) -> bool:
         return (
             self.numerator * other.denominator
             <= other.numerator * self.denominator
@@ -640,11 +729,19 @@ class Fraction:
             else (
                 self.numerator <= other * self.denominator
                 if isinstance(other, (_Int, int))
-                else NotImplemented
+                else (
+                    (
+                        self <= Fraction(other)
+                        if _isfinite(other)
+                        else other > 0
+                    )
+                    if isinstance(other, float)
+                    else NotImplemented
+                )
             )
         )
 
-    def __lt__(self, other: _Int | Self | int, /) -> bool:
+    def __lt__(self, other: _Int | Self | float | int, /) -> bool:
         return (
             self.numerator * other.denominator
             < other.numerator * self.denominator
@@ -652,7 +749,11 @@ class Fraction:
             else (
                 self.numerator < other * self.denominator
                 if isinstance(other, (_Int, int))
-                else NotImplemented
+                else (
+                    (self < Fraction(other) if _isfinite(other) else other > 0)
+                    if isinstance(other, float)
+                    else NotImplemented
+                )
             )
         )
 
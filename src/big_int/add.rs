@@ -1,7 +1,7 @@
 use std::ops::Add;
 
-use super::digits::SumComponents;
-use super::types::BigInt;
+use super::digits::{SumComponents, WithValueComponents};
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit: SumComponents, const DIGIT_BITNESS: usize> Add
     for BigInt<Digit, DIGIT_BITNESS>
@@ -66,3 +66,118 @@ impl<Digit: SumComponents, const DIGIT_BITNESS: usize> Add<&Self>
         Self::Output { sign, digits }
     }
 }
+
+impl<Digit: SumComponents, const DIGIT_BITNESS: usize>
+    Add<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn add(self, other: BigIntRef<'_, Digit, DIGIT_BITNESS>) -> Self::Output {
+        let (sign, digits) = Digit::sum_components::<DIGIT_BITNESS>(
+            self.sign,
+            self.digits,
+            other.sign,
+            other.digits,
+        );
+        Self::Output { sign, digits }
+    }
+}
+
+macro_rules! big_int_add_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<
+                Digit: SumComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Add<$integer> for BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = Self;
+
+            fn add(self, other: $integer) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(other, |value_sign, value_digits| {
+                    Digit::sum_components::<DIGIT_BITNESS>(
+                        self.sign,
+                        &self.digits,
+                        value_sign,
+                        value_digits,
+                    )
+                });
+                Self { sign, digits }
+            }
+        }
+
+        impl<
+                Digit: SumComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Add<$integer> for &BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn add(self, other: $integer) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(other, |value_sign, value_digits| {
+                    Digit::sum_components::<DIGIT_BITNESS>(
+                        self.sign,
+                        &self.digits,
+                        value_sign,
+                        value_digits,
+                    )
+                });
+                Self::Output { sign, digits }
+            }
+        }
+
+        impl<
+                Digit: SumComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Add<BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn add(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(self, |value_sign, value_digits| {
+                    Digit::sum_components::<DIGIT_BITNESS>(
+                        value_sign,
+                        value_digits,
+                        other.sign,
+                        &other.digits,
+                    )
+                });
+                Self::Output { sign, digits }
+            }
+        }
+
+        impl<
+                Digit: SumComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Add<&BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn add(self, other: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(self, |value_sign, value_digits| {
+                    Digit::sum_components::<DIGIT_BITNESS>(
+                        value_sign,
+                        value_digits,
+                        other.sign,
+                        &other.digits,
+                    )
+                });
+                Self::Output { sign, digits }
+            }
+        }
+    )*)
+}
+
+big_int_add_integer_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
@@ -0,0 +1,118 @@
+use std::mem::size_of;
+
+use traiter::numbers::{Endianness, FromBytes, ToBytes};
+
+use super::digits::trim_sign_extension_bytes;
+use super::types::{BigInt, FromAsn1IntegerError};
+
+const INDEFINITE_LENGTH: u8 = 0x80;
+const INTEGER_TAG: u8 = 0x02;
+const LONG_LENGTH_FLAG: u8 = 0x80;
+const RESERVED_LENGTH: u8 = 0xff;
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_der_integer(&self) -> Vec<u8> {
+        let content = self.to_bytes(Endianness::Big);
+        let mut result =
+            Vec::with_capacity(content.len() + 2 + size_of::<usize>());
+        result.push(INTEGER_TAG);
+        if content.len() < usize::from(LONG_LENGTH_FLAG) {
+            result.push(content.len() as u8);
+        } else {
+            let length_bytes = content.len().to_be_bytes();
+            let length_bytes = &length_bytes[length_bytes
+                .iter()
+                .position(|&byte| byte != 0u8)
+                .unwrap_or(length_bytes.len() - 1)..];
+            result.push(LONG_LENGTH_FLAG | (length_bytes.len() as u8));
+            result.extend_from_slice(length_bytes);
+        }
+        result.extend(content);
+        result
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: FromBytes,
+{
+    pub fn from_ber_integer(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromAsn1IntegerError> {
+        parse_integer(bytes, false)
+    }
+
+    pub fn from_der_integer(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromAsn1IntegerError> {
+        parse_integer(bytes, true)
+    }
+}
+
+fn parse_integer<Value: FromBytes>(
+    bytes: &[u8],
+    distinguished: bool,
+) -> Result<(Value, usize), FromAsn1IntegerError> {
+    let size = bytes.len();
+    let (&tag, bytes) =
+        bytes.split_first().ok_or(FromAsn1IntegerError::Truncated)?;
+    if tag != INTEGER_TAG {
+        return Err(FromAsn1IntegerError::InvalidTag(tag));
+    }
+    let (&length_octet, bytes) =
+        bytes.split_first().ok_or(FromAsn1IntegerError::Truncated)?;
+    let (length, bytes) = match length_octet {
+        INDEFINITE_LENGTH => {
+            return Err(FromAsn1IntegerError::IndefiniteLength);
+        }
+        RESERVED_LENGTH => return Err(FromAsn1IntegerError::ReservedLength),
+        _ if length_octet & LONG_LENGTH_FLAG == 0 => {
+            (usize::from(length_octet), bytes)
+        }
+        _ => {
+            let length_bytes_count =
+                usize::from(length_octet & !LONG_LENGTH_FLAG);
+            if bytes.len() < length_bytes_count {
+                return Err(FromAsn1IntegerError::Truncated);
+            }
+            let (length_bytes, bytes) = bytes.split_at(length_bytes_count);
+            if distinguished && length_bytes[0] == 0u8 {
+                return Err(FromAsn1IntegerError::NonMinimalLength);
+            }
+            let length_bytes = &length_bytes[length_bytes
+                .iter()
+                .position(|&byte| byte != 0u8)
+                .unwrap_or(length_bytes.len())..];
+            if length_bytes.len() > size_of::<usize>() {
+                return Err(FromAsn1IntegerError::TooLongLength);
+            }
+            let length = length_bytes.iter().fold(0usize, |length, &byte| {
+                (length << u8::BITS) | usize::from(byte)
+            });
+            if distinguished && length < usize::from(LONG_LENGTH_FLAG) {
+                return Err(FromAsn1IntegerError::NonMinimalLength);
+            }
+            (length, bytes)
+        }
+    };
+    if bytes.len() < length {
+        return Err(FromAsn1IntegerError::Truncated);
+    }
+    if length == 0 {
+        return Err(FromAsn1IntegerError::EmptyContent);
+    }
+    let mut content = bytes[..length].to_vec();
+    content.reverse();
+    let content_size = content.len();
+    trim_sign_extension_bytes(&mut content);
+    if distinguished && content.len() != content_size {
+        return Err(FromAsn1IntegerError::NonMinimalContent);
+    }
+    Ok((
+        Value::from_bytes(&content, Endianness::Little),
+        size - (bytes.len() - length),
+    ))
+}
@@ -2,10 +2,10 @@ use std::ops::{Add, Mul};
 
 use traiter::numbers::BitLength;
 
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit: BitLength<Output = usize> + Copy, const DIGIT_BITNESS: usize>
-    BitLength for &BigInt<Digit, DIGIT_BITNESS>
+    BitLength for BigIntRef<'_, Digit, DIGIT_BITNESS>
 where
     BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
         + From<usize>
@@ -28,3 +28,16 @@ where
         }
     }
 }
+
+impl<Digit, const DIGIT_BITNESS: usize> BitLength
+    for &BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> BigIntRef<'a, Digit, DIGIT_BITNESS>:
+        BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn bit_length(self) -> Self::Output {
+        BigIntRef::from(self).bit_length()
+    }
+}
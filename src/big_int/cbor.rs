@@ -0,0 +1,238 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use traiter::numbers::{Endianness, FromBytes, ToBytes};
+
+use super::digits::{trim_leading_zeros, trim_sign_extension_bytes};
+use super::types::BigInt;
+
+const BYTE_STRING_MAJOR_TYPE: u8 = 2;
+const INDEFINITE_LENGTH_INFORMATION: u8 = 31;
+const MAX_IMMEDIATE_ARGUMENT: u8 = 23;
+const NEGATIVE_BIGNUM_TAG: u64 = 3;
+const NEGATIVE_INTEGER_MAJOR_TYPE: u8 = 1;
+const POSITIVE_BIGNUM_TAG: u64 = 2;
+const TAG_MAJOR_TYPE: u8 = 6;
+const UNSIGNED_INTEGER_MAJOR_TYPE: u8 = 0;
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut modulus_bytes = self.to_bytes(Endianness::Little);
+        let is_negative = self.sign.is_negative();
+        if is_negative {
+            for byte in modulus_bytes.iter_mut() {
+                *byte = !*byte;
+            }
+        }
+        trim_leading_zeros::<u8>(&mut modulus_bytes);
+        let mut result = Vec::new();
+        if modulus_bytes.len() <= u64::BITS as usize / u8::BITS as usize {
+            let mut argument_bytes = [0u8; 8];
+            argument_bytes[..modulus_bytes.len()]
+                .copy_from_slice(&modulus_bytes);
+            write_header(
+                &mut result,
+                if is_negative {
+                    NEGATIVE_INTEGER_MAJOR_TYPE
+                } else {
+                    UNSIGNED_INTEGER_MAJOR_TYPE
+                },
+                u64::from_le_bytes(argument_bytes),
+            );
+        } else {
+            write_header(
+                &mut result,
+                TAG_MAJOR_TYPE,
+                if is_negative {
+                    NEGATIVE_BIGNUM_TAG
+                } else {
+                    POSITIVE_BIGNUM_TAG
+                },
+            );
+            write_header(
+                &mut result,
+                BYTE_STRING_MAJOR_TYPE,
+                modulus_bytes.len() as u64,
+            );
+            result.extend(modulus_bytes.iter().rev());
+        }
+        result
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: FromBytes,
+{
+    pub fn from_cbor(bytes: &[u8]) -> Result<(Self, usize), FromCborError> {
+        let (major_type, argument, header_size) = read_header(bytes)?;
+        let (mut modulus_bytes, is_negative, size) = match major_type {
+            UNSIGNED_INTEGER_MAJOR_TYPE | NEGATIVE_INTEGER_MAJOR_TYPE => (
+                argument.to_le_bytes().to_vec(),
+                major_type == NEGATIVE_INTEGER_MAJOR_TYPE,
+                header_size,
+            ),
+            TAG_MAJOR_TYPE
+                if argument == POSITIVE_BIGNUM_TAG
+                    || argument == NEGATIVE_BIGNUM_TAG =>
+            {
+                let (string_major_type, string_size, string_header_size) =
+                    read_header(&bytes[header_size..])?;
+                if string_major_type != BYTE_STRING_MAJOR_TYPE {
+                    return Err(FromCborError::UnexpectedMajorType(
+                        string_major_type,
+                    ));
+                }
+                let start = header_size + string_header_size;
+                let end = usize::try_from(string_size)
+                    .ok()
+                    .and_then(|string_size| start.checked_add(string_size))
+                    .filter(|&end| end <= bytes.len())
+                    .ok_or(FromCborError::Truncated)?;
+                let modulus_bytes = &bytes[start..end];
+                if modulus_bytes.len()
+                    <= u64::BITS as usize / u8::BITS as usize
+                    || modulus_bytes[0] == 0u8
+                {
+                    return Err(FromCborError::NonCanonical);
+                }
+                (
+                    modulus_bytes.iter().rev().copied().collect(),
+                    argument == NEGATIVE_BIGNUM_TAG,
+                    end,
+                )
+            }
+            TAG_MAJOR_TYPE => return Err(FromCborError::InvalidTag(argument)),
+            _ => return Err(FromCborError::UnexpectedMajorType(major_type)),
+        };
+        modulus_bytes.push(0u8);
+        if is_negative {
+            for byte in modulus_bytes.iter_mut() {
+                *byte = !*byte;
+            }
+        }
+        trim_sign_extension_bytes(&mut modulus_bytes);
+        Ok((Self::from_bytes(&modulus_bytes, Endianness::Little), size))
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromCborError {
+    IndefiniteLength,
+    InvalidTag(u64),
+    Malformed,
+    NonCanonical,
+    Truncated,
+    UnexpectedMajorType(u8),
+}
+
+impl FromCborError {
+    fn description(&self) -> String {
+        match self {
+            FromCborError::IndefiniteLength => {
+                String::from("Indefinite length items are not supported.")
+            }
+            FromCborError::InvalidTag(tag) => {
+                format!("Expected bignum tag 2 or 3, but found: {tag}.")
+            }
+            FromCborError::Malformed => {
+                String::from("Reserved additional information value.")
+            }
+            FromCborError::NonCanonical => {
+                String::from("Item is not in canonical form.")
+            }
+            FromCborError::Truncated => {
+                String::from("Unexpected end of input.")
+            }
+            FromCborError::UnexpectedMajorType(major_type) => {
+                format!("Unexpected major type: {major_type}.")
+            }
+        }
+    }
+}
+
+impl Debug for FromCborError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for FromCborError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+impl Error for FromCborError {}
+
+pub(crate) fn read_header(
+    bytes: &[u8],
+) -> Result<(u8, u64, usize), FromCborError> {
+    let (&initial_byte, bytes) =
+        bytes.split_first().ok_or(FromCborError::Truncated)?;
+    let major_type = initial_byte >> 5;
+    let additional_information = initial_byte & 0x1f;
+    if additional_information <= MAX_IMMEDIATE_ARGUMENT {
+        return Ok((major_type, u64::from(additional_information), 1));
+    } else if additional_information == INDEFINITE_LENGTH_INFORMATION {
+        return Err(FromCborError::IndefiniteLength);
+    }
+    let argument_size = match additional_information {
+        24 => 1usize,
+        25 => 2usize,
+        26 => 4usize,
+        27 => 8usize,
+        _ => return Err(FromCborError::Malformed),
+    };
+    if bytes.len() < argument_size {
+        return Err(FromCborError::Truncated);
+    }
+    let argument =
+        bytes[..argument_size].iter().fold(0u64, |argument, &byte| {
+            (argument << u8::BITS) | u64::from(byte)
+        });
+    if argument_size_of(argument) != argument_size {
+        return Err(FromCborError::NonCanonical);
+    }
+    Ok((major_type, argument, 1 + argument_size))
+}
+
+pub(crate) fn write_header(
+    result: &mut Vec<u8>,
+    major_type: u8,
+    argument: u64,
+) {
+    let argument_size = argument_size_of(argument);
+    result.push(
+        (major_type << 5)
+            | match argument_size {
+                0 => argument as u8,
+                1 => 24u8,
+                2 => 25u8,
+                4 => 26u8,
+                _ => 27u8,
+            },
+    );
+    result.extend_from_slice(
+        &argument.to_be_bytes()
+            [(u64::BITS / u8::BITS) as usize - argument_size..],
+    );
+}
+
+fn argument_size_of(argument: u64) -> usize {
+    if argument <= u64::from(MAX_IMMEDIATE_ARGUMENT) {
+        0
+    } else if argument <= u64::from(u8::MAX) {
+        1
+    } else if argument <= u64::from(u16::MAX) {
+        2
+    } else if argument <= u64::from(u32::MAX) {
+        4
+    } else {
+        8
+    }
+}
@@ -1,7 +1,7 @@
 use traiter::numbers::CheckedRem;
 
-use super::digits::CheckedRemComponents;
-use super::types::BigInt;
+use super::digits::{CheckedRemComponents, WithValueComponents};
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit: CheckedRemComponents, const DIGIT_BITNESS: usize> CheckedRem
     for BigInt<Digit, DIGIT_BITNESS>
@@ -70,3 +70,121 @@ impl<Digit: CheckedRemComponents, const DIGIT_BITNESS: usize> CheckedRem
         .map(|(sign, digits)| BigInt::<Digit, DIGIT_BITNESS> { sign, digits })
     }
 }
+
+impl<Digit: CheckedRemComponents, const DIGIT_BITNESS: usize>
+    CheckedRem<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn checked_rem(
+        self,
+        divisor: BigIntRef<'_, Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        Digit::checked_rem_components::<DIGIT_BITNESS>(
+            self.sign,
+            self.digits,
+            divisor.sign,
+            divisor.digits,
+        )
+        .map(|(sign, digits)| BigInt { sign, digits })
+    }
+}
+
+macro_rules! big_int_checked_rem_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<
+                Digit: CheckedRemComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > CheckedRem<$integer> for BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = Option<Self>;
+
+            fn checked_rem(self, divisor: $integer) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    divisor,
+                    |value_sign, value_digits| {
+                        Digit::checked_rem_components::<DIGIT_BITNESS>(
+                            self.sign,
+                            &self.digits,
+                            value_sign,
+                            value_digits,
+                        )
+                    },
+                )
+                .map(|(sign, digits)| BigInt { sign, digits })
+            }
+        }
+
+        impl<
+                Digit: CheckedRemComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > CheckedRem<$integer> for &BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+            fn checked_rem(self, divisor: $integer) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    divisor,
+                    |value_sign, value_digits| {
+                        Digit::checked_rem_components::<DIGIT_BITNESS>(
+                            self.sign,
+                            &self.digits,
+                            value_sign,
+                            value_digits,
+                        )
+                    },
+                )
+                .map(|(sign, digits)| BigInt { sign, digits })
+            }
+        }
+
+        impl<
+                Digit: CheckedRemComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > CheckedRem<BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+            fn checked_rem(self, divisor: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    self,
+                    |value_sign, value_digits| {
+                        Digit::checked_rem_components::<DIGIT_BITNESS>(
+                            value_sign,
+                            value_digits,
+                            divisor.sign,
+                            &divisor.digits,
+                        )
+                    },
+                )
+                .map(|(sign, digits)| BigInt { sign, digits })
+            }
+        }
+
+        impl<
+                Digit: CheckedRemComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > CheckedRem<&BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+            fn checked_rem(self, divisor: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    self,
+                    |value_sign, value_digits| {
+                        Digit::checked_rem_components::<DIGIT_BITNESS>(
+                            value_sign,
+                            value_digits,
+                            divisor.sign,
+                            &divisor.digits,
+                        )
+                    },
+                )
+                .map(|(sign, digits)| BigInt { sign, digits })
+            }
+        }
+    )*)
+}
+
+big_int_checked_rem_integer_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
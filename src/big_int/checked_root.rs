@@ -0,0 +1,154 @@
+use std::ops::{Add, Div, Mul, Neg, Shl, Sub};
+
+use traiter::numbers::{
+    Abs, BitLength, One, Parity, Pow, Signed, Zero, Zeroable,
+};
+
+use crate::traits::CheckedRoot;
+
+use super::types::BigInt;
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRoot<Self>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: CheckedRoot<&'a Self, Output = Option<Self>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_root(self, degree: Self) -> Self::Output {
+        (&self).checked_root(&degree)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRoot<&Self>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: CheckedRoot<&'a Self, Output = Option<Self>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_root(self, degree: &Self) -> Self::Output {
+        (&self).checked_root(degree)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRoot<usize>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: CheckedRoot<&'a Self, Output = Option<Self>>,
+    Self: From<usize>,
+{
+    type Output = Option<Self>;
+
+    fn checked_root(self, degree: usize) -> Self::Output {
+        (&self).checked_root(&Self::from(degree))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedRoot<BigInt<Digit, DIGIT_BITNESS>> for &BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Parity + Signed,
+    BigInt<Digit, DIGIT_BITNESS>: UncheckedRoot,
+{
+    type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn checked_root(
+        self,
+        degree: BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        self.checked_root(&degree)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedRoot<&BigInt<Digit, DIGIT_BITNESS>>
+    for &BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Parity + Signed,
+    BigInt<Digit, DIGIT_BITNESS>: UncheckedRoot,
+{
+    type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn checked_root(
+        self,
+        degree: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        if !degree.is_positive() || (self.is_negative() && degree.is_even()) {
+            None
+        } else {
+            self.unchecked_root(degree)
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRoot<usize>
+    for &BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> Self: CheckedRoot<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+    BigInt<Digit, DIGIT_BITNESS>: From<usize>,
+{
+    type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn checked_root(self, degree: usize) -> Self::Output {
+        self.checked_root(&BigInt::from(degree))
+    }
+}
+
+trait UncheckedRoot: Sized {
+    fn unchecked_root(&self, degree: &Self) -> Option<Self>;
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> UncheckedRoot
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: Abs<Output = Self>
+        + BitLength<Output = Self>
+        + Div<Output = Self>
+        + Mul<Output = Self>
+        + Pow<&'a Self, Output = Self>
+        + Signed
+        + Sub<Self, Output = Self>
+        + Zeroable,
+    for<'a> Self: Add<Output = Self>
+        + Add<&'a Self, Output = Self>
+        + Div<&'a Self, Output = Self>
+        + Neg<Output = Self>
+        + One
+        + Ord
+        + Shl<Output = Self>
+        + Zero,
+{
+    fn unchecked_root(&self, degree: &Self) -> Option<Self> {
+        debug_assert!(degree.is_positive());
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        let modulus = self.abs();
+        let bit_length = (&modulus).bit_length();
+        let root = if &bit_length <= degree {
+            Self::one()
+        } else {
+            let degree_decrement = degree - Self::one();
+            let mut candidate =
+                Self::one() << ((bit_length + &degree_decrement) / degree);
+            loop {
+                let next_candidate = (&degree_decrement * &candidate
+                    + &modulus / &(&candidate).pow(&degree_decrement))
+                    / degree;
+                if next_candidate >= candidate {
+                    break candidate;
+                }
+                candidate = next_candidate;
+            }
+        };
+        if (&root).pow(degree) == modulus {
+            Some(if self.is_negative() { -root } else { root })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+
+use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+use traiter::numbers::Zeroable;
+
+use crate::traits::HasSignBit;
+
+use super::contracts::is_valid_digit_bitness;
+use super::digits::{
+    to_digits_sign, trim_leading_zeros, BinaryBaseFromBinaryDigits,
+};
+use super::try_from_string::TryFromString;
+use super::types::{BigInt, Sign};
+
+impl<
+        'de,
+        Digit: BinaryBaseFromBinaryDigits<u8> + HasSignBit,
+        const DIGIT_BITNESS: usize,
+    > Deserialize<'de> for BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+    Self: From<i64> + From<i128> + From<u64> + From<u128> + TryFromString,
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BigIntStringVisitor(PhantomData))
+        } else {
+            deserializer
+                .deserialize_tuple(2, BigIntComponentsVisitor(PhantomData))
+        }
+    }
+}
+
+struct BigIntStringVisitor<Digit, const DIGIT_BITNESS: usize>(
+    PhantomData<BigInt<Digit, DIGIT_BITNESS>>,
+);
+
+impl<Digit, const DIGIT_BITNESS: usize> Visitor<'_>
+    for BigIntStringVisitor<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>:
+        From<i64> + From<i128> + From<u64> + From<u128> + TryFromString,
+{
+    type Value = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an integer or a string with an integer")
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Self::Value::from(value))
+    }
+
+    fn visit_i128<E: Error>(self, value: i128) -> Result<Self::Value, E> {
+        Ok(Self::Value::from(value))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Self::Value::from(value))
+    }
+
+    fn visit_u128<E: Error>(self, value: u128) -> Result<Self::Value, E> {
+        Ok(Self::Value::from(value))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        Self::Value::try_from_string(value, 0).map_err(E::custom)
+    }
+}
+
+struct BigIntComponentsVisitor<Digit, const DIGIT_BITNESS: usize>(
+    PhantomData<BigInt<Digit, DIGIT_BITNESS>>,
+);
+
+impl<
+        'de,
+        Digit: BinaryBaseFromBinaryDigits<u8> + HasSignBit,
+        const DIGIT_BITNESS: usize,
+    > Visitor<'de> for BigIntComponentsVisitor<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    type Value = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a pair of a sign and modulus bytes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut sequence: A,
+    ) -> Result<Self::Value, A::Error> {
+        debug_assert!(is_valid_digit_bitness::<Digit, DIGIT_BITNESS>());
+        let sign = sequence
+            .next_element::<Sign>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let ModulusBytesBuffer(mut modulus_bytes) = sequence
+            .next_element::<ModulusBytesBuffer>()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        if !(-1..=1).contains(&sign) {
+            return Err(A::Error::invalid_value(
+                Unexpected::Signed(i64::from(sign)),
+                &"a sign in range from -1 to 1",
+            ));
+        }
+        if modulus_bytes.is_empty() {
+            return Err(A::Error::invalid_length(
+                0,
+                &"non-empty modulus bytes",
+            ));
+        }
+        trim_leading_zeros::<u8>(&mut modulus_bytes);
+        if to_digits_sign::<u8>(&modulus_bytes) != sign.abs() {
+            return Err(A::Error::custom(
+                "Sign should be zero if and only if modulus is zero.",
+            ));
+        }
+        let mut digits = Digit::binary_base_from_binary_digits(
+            &modulus_bytes,
+            u8::BITS as usize,
+            DIGIT_BITNESS,
+        );
+        trim_leading_zeros(&mut digits);
+        Ok(Self::Value { sign, digits })
+    }
+}
+
+struct ModulusBytesBuffer(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ModulusBytesBuffer {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(ModulusBytesVisitor)
+    }
+}
+
+struct ModulusBytesVisitor;
+
+impl<'de> Visitor<'de> for ModulusBytesVisitor {
+    type Value = ModulusBytesBuffer;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("little-endian modulus bytes")
+    }
+
+    fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(ModulusBytesBuffer(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: Error>(
+        self,
+        value: Vec<u8>,
+    ) -> Result<Self::Value, E> {
+        Ok(ModulusBytesBuffer(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut sequence: A,
+    ) -> Result<Self::Value, A::Error> {
+        let mut result = Vec::with_capacity(sequence.size_hint().unwrap_or(0));
+        while let Some(byte) = sequence.next_element::<u8>()? {
+            result.push(byte);
+        }
+        Ok(ModulusBytesBuffer(result))
+    }
+}
@@ -11,12 +11,13 @@ use traiter::numbers::{
     RemEuclid, Signed, Unitary, Zero, Zeroable,
 };
 
-use crate::contracts::{is_signed, is_unsigned};
+use crate::contracts::is_signed;
 use crate::traits::{
     DoublePrecision, DoublePrecisionOf, HasSignBit, MantissaDigits, MaxExp,
-    MinExp, Oppose, OppositionOf, WrappingSub,
+    MinExp, Oppose, OppositionOf, UnsignedAbs, WrappingSub,
 };
 
+use super::constants::MIDDLE_BYTE;
 use super::types::{CheckedDivAsFloatError, ShlError, Sign};
 
 pub trait BaseFromBinaryDigits<Source>: Sized {
@@ -1516,6 +1517,50 @@ impl<Digit: One + SumComponents> InvertComponents for Digit {
     }
 }
 
+pub trait MultiplyAddDigitsInPlace: Sized {
+    fn multiply_add_digits_in_place<const DIGIT_BITNESS: usize>(
+        digits: &mut Vec<Self>,
+        multiplier: Self,
+        addend: Self,
+    );
+}
+
+impl<Digit: Copy + DoublePrecision + TryFrom<DoublePrecisionOf<Digit>>>
+    MultiplyAddDigitsInPlace for Digit
+where
+    for<'a> &'a DoublePrecisionOf<Digit>: Zeroable,
+    DoublePrecisionOf<Digit>: AddAssign
+        + BitAnd<Output = DoublePrecisionOf<Digit>>
+        + Copy
+        + DigitMask
+        + Mul<Output = DoublePrecisionOf<Digit>>
+        + ShrAssign<usize>,
+{
+    fn multiply_add_digits_in_place<const DIGIT_BITNESS: usize>(
+        digits: &mut Vec<Self>,
+        multiplier: Self,
+        addend: Self,
+    ) {
+        let mask = DoublePrecisionOf::<Self>::digit_mask(DIGIT_BITNESS);
+        let multiplier = DoublePrecisionOf::<Self>::from(multiplier);
+        let mut accumulator = DoublePrecisionOf::<Self>::from(addend);
+        for digit in digits.iter_mut() {
+            accumulator +=
+                DoublePrecisionOf::<Self>::from(*digit) * multiplier;
+            *digit = unsafe {
+                Self::try_from(accumulator & mask).unwrap_unchecked()
+            };
+            accumulator >>= DIGIT_BITNESS;
+        }
+        while !accumulator.is_zero() {
+            digits.push(unsafe {
+                Self::try_from(accumulator & mask).unwrap_unchecked()
+            });
+            accumulator >>= DIGIT_BITNESS;
+        }
+    }
+}
+
 pub(super) trait MultiplyDigits: Sized {
     fn multiply_digits<const DIGIT_BITNESS: usize>(
         first: &[Self],
@@ -1766,6 +1811,20 @@ pub(super) fn negate_bytes(digits: &mut [u8]) {
     }
 }
 
+pub(super) fn trim_sign_extension_bytes(bytes: &mut Vec<u8>) {
+    while bytes.len() > 1 {
+        let most_significant_byte = bytes[bytes.len() - 1];
+        let next_byte_is_negative = bytes[bytes.len() - 2] >= MIDDLE_BYTE;
+        if (most_significant_byte == u8::MIN && !next_byte_is_negative)
+            || (most_significant_byte == u8::MAX && next_byte_is_negative)
+        {
+            bytes.pop();
+        } else {
+            break;
+        }
+    }
+}
+
 pub(super) trait GreaterBinaryBaseFromNonBinaryDigits<Source>:
     Sized
 {
@@ -1981,6 +2040,9 @@ impl<
         Digit: Copy,
         Output: CheckedShl<u32, Output = Option<Output>>
             + BitOr<Output = Output>
+            + Copy
+            + PartialEq
+            + Shr<u32, Output = Output>
             + TryFrom<Digit>
             + Zero,
     > MaybeReduceDigits<Output> for Digit
@@ -1988,11 +2050,17 @@ impl<
     fn maybe_reduce_digits<const DIGIT_BITNESS: usize>(
         digits: &[Self],
     ) -> Option<Output> {
+        let shift = unsafe { u32::try_from(DIGIT_BITNESS).unwrap_unchecked() };
         let mut result = Output::zero();
         for &digit in digits.iter().rev() {
-            result = result.checked_shl(unsafe {
-                u32::try_from(DIGIT_BITNESS).unwrap_unchecked()
-            })? | Output::try_from(digit).ok()?;
+            if result != Output::zero() {
+                let shifted_result = result.checked_shl(shift)?;
+                if shifted_result >> shift != result {
+                    return None;
+                }
+                result = shifted_result;
+            }
+            result = result | Output::try_from(digit).ok()?;
         }
         Some(result)
     }
@@ -2243,14 +2311,13 @@ where
         let low_mask = Self::digit_mask(high_shift);
         let high_mask = Self::digit_mask(DIGIT_BITNESS) ^ low_mask;
         let mut result = vec![Self::zero(); result_digits_count];
-        let mut position = shift_quotient;
         for (index, result_digit) in result.iter_mut().enumerate() {
+            let position = shift_quotient + index;
             *result_digit = (digits[position] >> shift_remainder) & low_mask;
             if index + 1 < result_digits_count {
                 *result_digit |=
                     (digits[position + 1] << high_shift) & high_mask;
             }
-            position += 1;
         }
         trim_leading_zeros(&mut result);
         result
@@ -2913,88 +2980,127 @@ pub(super) trait DigitsFromNonZeroValue<Source>: Sized {
     fn digits_from_non_zero_value<const DIGIT_BITNESS: usize>(
         value: Source,
     ) -> Vec<Self>;
+
+    fn for_each_value_digit<const DIGIT_BITNESS: usize>(
+        value: Source,
+        sink: impl FnMut(Self),
+    );
 }
 
 impl<
         Digit: BitAnd<Output = Digit>
             + Copy
             + DigitMask
-            + HasSignBit
-            + Oppose
-            + ShrAssign<usize>
-            + TryFrom<Source>,
-        Source: BitAnd<Output = Source>
-            + Copy
-            + DigitMask
-            + HasSignBit
-            + Oppose
             + ShrAssign<usize>
-            + TryFrom<OppositionOf<Source>>,
+            + TryFrom<<Source as UnsignedAbs>::Output>,
+        Source: UnsignedAbs,
     > DigitsFromNonZeroValue<Source> for Digit
 where
     for<'a> &'a Digit: Zeroable,
-    for<'a> &'a Source: Zeroable,
-    OppositionOf<Source>: TryFrom<Source>,
+    for<'a> &'a <Source as UnsignedAbs>::Output: Zeroable,
+    <Source as UnsignedAbs>::Output: BitAnd<Output = <Source as UnsignedAbs>::Output>
+        + Copy
+        + DigitMask
+        + ShrAssign<usize>,
 {
     fn digits_from_non_zero_value<const DIGIT_BITNESS: usize>(
         value: Source,
     ) -> Vec<Self> {
-        if std::mem::size_of::<Source>() < std::mem::size_of::<Self>()
-            || (std::mem::size_of::<Source>() == std::mem::size_of::<Self>()
-                && is_signed::<Source>()
-                && is_unsigned::<Self>())
+        let mut digits = Vec::<Self>::new();
+        Self::for_each_value_digit::<DIGIT_BITNESS>(value, |digit| {
+            digits.push(digit)
+        });
+        digits
+    }
+
+    fn for_each_value_digit<const DIGIT_BITNESS: usize>(
+        value: Source,
+        mut sink: impl FnMut(Self),
+    ) {
+        let modulus = value.unsigned_abs();
+        if std::mem::size_of::<<Source as UnsignedAbs>::Output>()
+            <= std::mem::size_of::<Self>()
         {
-            let mut value = if is_signed::<Source>() {
-                let value = unsafe {
-                    OppositionOf::<Source>::try_from(value).unwrap_unchecked()
-                };
-                unsafe {
-                    Self::try_from(
-                        Source::try_from(if value.is_negative() {
-                            -value
-                        } else {
-                            value
-                        })
-                        .unwrap_unchecked(),
-                    )
-                    .unwrap_unchecked()
-                }
-            } else {
-                unsafe { Self::try_from(value).unwrap_unchecked() }
-            };
-            let mut digits = Vec::<Self>::new();
+            let mut modulus =
+                unsafe { Self::try_from(modulus).unwrap_unchecked() };
             let digit_mask = Self::digit_mask(DIGIT_BITNESS);
-            while !value.is_zero() {
-                digits.push(value & digit_mask);
-                value >>= DIGIT_BITNESS;
+            while !modulus.is_zero() {
+                sink(modulus & digit_mask);
+                modulus >>= DIGIT_BITNESS;
             }
-            digits
         } else {
-            let mut value = if is_signed::<Source>() {
-                let value = unsafe {
-                    OppositionOf::<Source>::try_from(value).unwrap_unchecked()
-                };
-                if value.is_negative() {
-                    unsafe { Source::try_from(-value).unwrap_unchecked() }
-                } else {
-                    unsafe { Source::try_from(value).unwrap_unchecked() }
-                }
-            } else {
-                value
-            };
-            let mut digits = Vec::<Self>::new();
-            let digit_mask = Source::digit_mask(DIGIT_BITNESS);
-            while !value.is_zero() {
-                digits.push(unsafe {
-                    Self::try_from(value & digit_mask).unwrap_unchecked()
+            let mut modulus = modulus;
+            let digit_mask =
+                <Source as UnsignedAbs>::Output::digit_mask(DIGIT_BITNESS);
+            while !modulus.is_zero() {
+                sink(unsafe {
+                    Self::try_from(modulus & digit_mask).unwrap_unchecked()
                 });
-                value >>= DIGIT_BITNESS;
+                modulus >>= DIGIT_BITNESS;
             }
-            digits
         }
     }
 }
 
+/// Provides a stack buffer able to hold digits of any value
+/// of the primitive integer type
+/// (since digit bitness is at least 1 its bitness bounds digits count).
+pub(super) trait ValueDigitsBuffer {
+    type Buffer<Digit: Copy>: AsRef<[Digit]> + AsMut<[Digit]>;
+
+    fn value_digits_buffer<Digit: Copy>(filler: Digit) -> Self::Buffer<Digit>;
+}
+
+macro_rules! value_digits_buffer_impl {
+    ($($integer:ty)*) => ($(
+        impl ValueDigitsBuffer for $integer {
+            type Buffer<Digit: Copy> = [Digit; <$integer>::BITS as usize];
+
+            #[inline]
+            fn value_digits_buffer<Digit: Copy>(
+                filler: Digit,
+            ) -> Self::Buffer<Digit> {
+                [filler; <$integer>::BITS as usize]
+            }
+        }
+    )*)
+}
+
+value_digits_buffer_impl!(
+    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+);
+
+pub(super) trait WithValueComponents<Source>: Sized {
+    fn with_value_components<const DIGIT_BITNESS: usize, Target>(
+        value: Source,
+        callback: impl FnOnce(Sign, &[Self]) -> Target,
+    ) -> Target;
+}
+
+impl<
+        Digit: DigitsFromNonZeroValue<Source> + Zero + Copy,
+        Source: Copy + HasSignBit + Oppose + ValueDigitsBuffer,
+    > WithValueComponents<Source> for Digit
+where
+    for<'a> &'a Source: Zeroable,
+    OppositionOf<Source>: TryFrom<Source>,
+{
+    fn with_value_components<const DIGIT_BITNESS: usize, Target>(
+        value: Source,
+        callback: impl FnOnce(Sign, &[Self]) -> Target,
+    ) -> Target {
+        let sign = value_to_sign(value);
+        let mut buffer = Source::value_digits_buffer(Self::zero());
+        let digits = buffer.as_mut();
+        let mut digits_count = 0usize;
+        Self::for_each_value_digit::<DIGIT_BITNESS>(value, |digit| {
+            digits[digits_count] = digit;
+            digits_count += 1;
+        });
+        callback(sign, &digits[..digits_count.max(1)])
+    }
+}
+
 #[inline]
 pub(super) fn value_to_sign<Source>(value: Source) -> Sign
 where
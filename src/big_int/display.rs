@@ -6,26 +6,46 @@ use traiter::numbers::{RemEuclid, Signed, Zeroable};
 
 use super::constants::MAX_REPRESENTABLE_BASE;
 use super::digits::BaseFromBinaryDigits;
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit, const DIGIT_BITNESS: usize> Display
     for BigInt<Digit, DIGIT_BITNESS>
 where
-    Self: ToBaseString,
+    for<'a> BigIntRef<'a, Digit, DIGIT_BITNESS>: WriteBaseDigits,
 {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
-        formatter.write_str(&self.to_base_string(10))
+        Display::fmt(&BigIntRef::from(self), formatter)
     }
 }
 
-trait ToBaseString {
-    const DIGIT_VALUES_ASCII_CODES: [char; MAX_REPRESENTABLE_BASE as usize] = [
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd',
-        'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r',
-        's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-    ];
+impl<Digit, const DIGIT_BITNESS: usize> Display
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+where
+    Self: WriteBaseDigits,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_base_digits(10, |characters| {
+            formatter.write_str(unsafe {
+                std::str::from_utf8_unchecked(characters)
+            })
+        })
+    }
+}
+
+pub(super) trait WriteBaseDigits {
+    const DIGIT_VALUES_ASCII_CODES: [u8; MAX_REPRESENTABLE_BASE as usize] =
+        *b"0123456789abcdefghijklmnopqrstuvwxyz";
+    const CHUNK_SIZE: usize = 4096;
 
-    fn to_base_string(&self, base: usize) -> String;
+    /// Passes characters to `write` in chunks of about `CHUNK_SIZE` bytes,
+    /// but the digits converted to a power of `base` are still collected
+    /// in full beforehand, since they are produced starting from the least
+    /// significant one.
+    fn write_base_digits<Error>(
+        &self,
+        base: usize,
+        write: impl FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error>;
 }
 
 impl<
@@ -36,12 +56,16 @@ impl<
             + RemEuclid<Output = Digit>
             + TryFrom<usize>,
         const DIGIT_BITNESS: usize,
-    > ToBaseString for BigInt<Digit, DIGIT_BITNESS>
+    > WriteBaseDigits for BigIntRef<'_, Digit, DIGIT_BITNESS>
 where
     for<'a> &'a Digit: Zeroable,
     usize: TryFrom<Digit>,
 {
-    fn to_base_string(&self, base: usize) -> String {
+    fn write_base_digits<Error>(
+        &self,
+        base: usize,
+        mut write: impl FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
         let shift = if (1usize << DIGIT_BITNESS)
             >= (MAX_REPRESENTABLE_BASE as usize)
             || base < (1usize << DIGIT_BITNESS)
@@ -51,54 +75,55 @@ where
             1usize
         };
         let digits = Digit::base_from_binary_digits(
-            &self.digits,
+            self.digits,
             DIGIT_BITNESS,
             power(base, shift),
         );
-        let characters_count = usize::from(self.is_negative())
-            + (digits.len() - 1) * shift
-            + floor_log(
-                unsafe {
-                    usize::try_from(digits[digits.len() - 1])
-                        .unwrap_unchecked()
-                },
-                base,
-            )
-            .unwrap_or(0usize)
-            + 1;
-        let mut characters: String = String::with_capacity(characters_count);
         let target_base = unsafe { Digit::try_from(base).unwrap_unchecked() };
-        for &(mut remainder) in digits.iter().take(digits.len() - 1) {
-            for _ in 0..shift {
-                characters.push(
-                    Self::DIGIT_VALUES_ASCII_CODES[unsafe {
-                        usize::try_from(remainder.rem_euclid(target_base))
-                            .unwrap_unchecked()
-                    }],
-                );
-                remainder /= target_base;
-            }
+        let mut characters =
+            Vec::<u8>::with_capacity(Self::CHUNK_SIZE + shift + 1);
+        if self.is_negative() {
+            characters.push(b'-');
         }
-        let mut remainder = digits[digits.len() - 1];
-        while !remainder.is_zero() {
-            characters.push(
-                Self::DIGIT_VALUES_ASCII_CODES[unsafe {
+        let most_significant_digit = digits[digits.len() - 1];
+        let most_significant_characters_count = floor_log(
+            unsafe {
+                usize::try_from(most_significant_digit).unwrap_unchecked()
+            },
+            base,
+        )
+        .unwrap_or(0usize)
+            + 1;
+        for (digit, characters_count) in std::iter::once((
+            most_significant_digit,
+            most_significant_characters_count,
+        ))
+        .chain(digits.iter().rev().skip(1).map(|&digit| (digit, shift)))
+        {
+            let end = characters.len() + characters_count;
+            characters.resize(end, b'0');
+            let mut remainder = digit;
+            for position in (end - characters_count..end).rev() {
+                characters[position] = Self::DIGIT_VALUES_ASCII_CODES[unsafe {
                     usize::try_from(remainder.rem_euclid(target_base))
                         .unwrap_unchecked()
-                }],
-            );
-            remainder /= target_base;
+                }];
+                remainder /= target_base;
+            }
+            if characters.len() >= Self::CHUNK_SIZE {
+                write(&characters)?;
+                characters.clear();
+            }
         }
-        if self.is_zero() {
-            characters.push('0');
-        } else if self.is_negative() {
-            characters.push('-');
+        if characters.is_empty() {
+            Ok(())
+        } else {
+            write(&characters)
         }
-        characters.chars().rev().collect()
     }
 }
 
-const fn floor_log(value: usize, base: usize) -> Option<usize> {
+pub(super) const fn floor_log(value: usize, base: usize) -> Option<usize> {
     if value == 0usize {
         None
     } else if value < base {
@@ -111,7 +136,7 @@ const fn floor_log(value: usize, base: usize) -> Option<usize> {
     }
 }
 
-const fn power(base: usize, exponent: usize) -> usize {
+pub(super) const fn power(base: usize, exponent: usize) -> usize {
     match exponent {
         0 => 1,
         _ => base * power(base, exponent - 1),
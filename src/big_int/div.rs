@@ -4,7 +4,7 @@ use traiter::numbers::CheckedDiv;
 
 use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
 
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit, const DIGIT_BITNESS: usize> Div for BigInt<Digit, DIGIT_BITNESS>
 where
@@ -58,3 +58,81 @@ where
             .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
     }
 }
+
+impl<'a, 'b, Digit, const DIGIT_BITNESS: usize>
+    Div<BigIntRef<'b, Digit, DIGIT_BITNESS>>
+    for BigIntRef<'a, Digit, DIGIT_BITNESS>
+where
+    Self: CheckedDiv<
+        BigIntRef<'b, Digit, DIGIT_BITNESS>,
+        Output = Option<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn div(
+        self,
+        divisor: BigIntRef<'b, Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        self.checked_div(divisor)
+            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+    }
+}
+
+macro_rules! big_int_div_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> Div<$integer>
+            for BigInt<Digit, DIGIT_BITNESS>
+        where
+            Self: CheckedDiv<$integer, Output = Option<Self>>,
+        {
+            type Output = Self;
+
+            fn div(self, divisor: $integer) -> Self::Output {
+                self.checked_div(divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> Div<$integer>
+            for &BigInt<Digit, DIGIT_BITNESS>
+        where
+            Self: CheckedDiv<$integer, Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn div(self, divisor: $integer) -> Self::Output {
+                self.checked_div(divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> Div<BigInt<Digit, DIGIT_BITNESS>>
+            for $integer
+        where
+            Self: CheckedDiv<BigInt<Digit, DIGIT_BITNESS>, Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn div(self, divisor: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                CheckedDiv::checked_div(self, divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> Div<&BigInt<Digit, DIGIT_BITNESS>>
+            for $integer
+        where
+            for<'a> Self: CheckedDiv<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn div(self, divisor: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                CheckedDiv::checked_div(self, divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+    )*)
+}
+
+big_int_div_integer_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
@@ -1,7 +1,7 @@
 use traiter::numbers::{One, Zero, Zeroable};
 
 use super::digits::{non_zero_value_to_sign, DigitsFromNonZeroValue};
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit, const DIGIT_BITNESS: usize> From<bool>
     for BigInt<Digit, DIGIT_BITNESS>
@@ -17,6 +17,30 @@ where
     }
 }
 
+impl<'a, Digit, const DIGIT_BITNESS: usize>
+    From<&'a BigInt<Digit, DIGIT_BITNESS>>
+    for BigIntRef<'a, Digit, DIGIT_BITNESS>
+{
+    fn from(value: &'a BigInt<Digit, DIGIT_BITNESS>) -> Self {
+        Self {
+            sign: value.sign,
+            digits: &value.digits,
+        }
+    }
+}
+
+impl<Digit: Clone, const DIGIT_BITNESS: usize>
+    From<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn from(value: BigIntRef<'_, Digit, DIGIT_BITNESS>) -> Self {
+        Self {
+            sign: value.sign,
+            digits: value.digits.to_vec(),
+        }
+    }
+}
+
 macro_rules! from_integer_impl {
     ($($integer:ty)*) => ($(
         impl<
@@ -0,0 +1,24 @@
+use std::hash::{Hash, Hasher};
+
+use crate::traits::NumericHash;
+
+use super::types::{BigInt, BigIntRef};
+
+impl<Digit, const DIGIT_BITNESS: usize> Hash for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: NumericHash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_isize(self.numeric_hash());
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Hash
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+where
+    Self: NumericHash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_isize(self.numeric_hash());
+    }
+}
@@ -1,13 +1,34 @@
+#[cfg(feature = "cbor")]
+pub use self::cbor::FromCborError;
+#[cfg(feature = "cbor")]
+pub(crate) use self::cbor::{read_header, write_header};
 #[cfg(feature = "python_binding")]
 pub(crate) use self::constants::{
     MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE,
 };
 pub use self::contracts::is_valid_digit_bitness;
-pub use self::types::{BigInt, ShlError, ShrError, TryFromFloatError};
+pub(crate) use self::numeric_hash::to_hash_residue;
+pub(crate) use self::order_preserving::{
+    read_order_preserving_bytes, write_order_preserving_bytes,
+};
+pub(crate) use self::pg_numeric::{
+    read_pg_numeric, write_pg_numeric, NUMERIC_MAX_DISPLAY_SCALE,
+};
+pub(crate) use self::product::balanced_product;
+pub(crate) use self::try_to_float::div_moduli_as_float_parts;
+pub use self::types::{
+    BigInt, BigIntRef, CheckedDivAsFloatError, CheckedPowRemEuclidError,
+    FloatOverflow, FloatRounding, FromAsn1IntegerError,
+    FromOrderPreservingBytesError, FromPgNumericError, FromSignAndDigitsError,
+    FromVarintError, ShlError, ShrError, TryFromFloatError,
+    TryFromStringError, TryIntoFloatError, TryIntoSignedIntegerError,
+    TryIntoUnsignedIntegerError,
+};
 
 mod abs;
 mod add;
 mod add_assign;
+mod asn1;
 mod bit_and;
 mod bit_and_assign;
 mod bit_length;
@@ -15,6 +36,8 @@ mod bit_or;
 mod bit_or_assign;
 mod bit_xor;
 mod bit_xor_assign;
+#[cfg(feature = "cbor")]
+mod cbor;
 mod checked_div;
 mod checked_div_euclid;
 mod checked_div_rem;
@@ -24,10 +47,13 @@ mod checked_pow_rem_euclid;
 mod checked_rem;
 mod checked_rem_euclid;
 mod checked_rem_euclid_inv;
+mod checked_root;
 mod checked_shl;
 mod checked_shr;
 mod constants;
 mod contracts;
+#[cfg(feature = "serde")]
+mod deserialize;
 mod digits;
 mod display;
 mod div;
@@ -39,32 +65,46 @@ mod from;
 mod from_bytes;
 mod from_str_radix;
 mod gcd;
+mod hash;
 mod is_power_of_two;
 mod mul;
 mod mul_assign;
 mod neg;
 mod not;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod numeric_hash;
 mod one;
 mod ord;
+mod order_preserving;
 mod parity;
 mod partial_eq;
 mod partial_ord;
+mod pg_numeric;
 mod pow;
+mod product;
+mod read_radix;
 mod rem;
 mod rem_assign;
 mod rem_euclid;
+#[cfg(feature = "serde")]
+mod serialize;
 mod shl;
 mod shr;
 mod signed;
 mod sub;
 mod sub_assign;
+mod sum;
 mod to_bytes;
 mod try_div_as_float;
 mod try_from;
 mod try_from_string;
 mod try_into;
+mod try_to_float;
 mod types;
 mod unchecked_to_int;
 mod unitary;
+mod varint;
+mod write_radix;
 mod zero;
 mod zeroable;
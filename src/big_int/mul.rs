@@ -1,7 +1,7 @@
 use std::ops::Mul;
 
-use super::digits::MultiplyDigits;
-use super::types::BigInt;
+use super::digits::{MultiplyDigits, WithValueComponents};
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit: MultiplyDigits, const DIGIT_BITNESS: usize> Mul
     for BigInt<Digit, DIGIT_BITNESS>
@@ -66,3 +66,110 @@ impl<Digit: MultiplyDigits, const DIGIT_BITNESS: usize> Mul
         }
     }
 }
+
+impl<Digit: MultiplyDigits, const DIGIT_BITNESS: usize>
+    Mul<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn mul(self, other: BigIntRef<'_, Digit, DIGIT_BITNESS>) -> Self::Output {
+        Self::Output {
+            sign: self.sign * other.sign,
+            digits: Digit::multiply_digits::<DIGIT_BITNESS>(
+                self.digits,
+                other.digits,
+            ),
+        }
+    }
+}
+
+macro_rules! big_int_mul_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<
+                Digit: MultiplyDigits + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Mul<$integer> for BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = Self;
+
+            fn mul(self, other: $integer) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    other,
+                    |value_sign, value_digits| Self::Output {
+                        sign: self.sign * value_sign,
+                        digits: Digit::multiply_digits::<DIGIT_BITNESS>(
+                            &self.digits,
+                            value_digits,
+                        ),
+                    },
+                )
+            }
+        }
+
+        impl<
+                Digit: MultiplyDigits + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Mul<$integer> for &BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn mul(self, other: $integer) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    other,
+                    |value_sign, value_digits| Self::Output {
+                        sign: self.sign * value_sign,
+                        digits: Digit::multiply_digits::<DIGIT_BITNESS>(
+                            &self.digits,
+                            value_digits,
+                        ),
+                    },
+                )
+            }
+        }
+
+        impl<
+                Digit: MultiplyDigits + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Mul<BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn mul(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    self,
+                    |value_sign, value_digits| Self::Output {
+                        sign: value_sign * other.sign,
+                        digits: Digit::multiply_digits::<DIGIT_BITNESS>(
+                            value_digits,
+                            &other.digits,
+                        ),
+                    },
+                )
+            }
+        }
+
+        impl<
+                Digit: MultiplyDigits + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Mul<&BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn mul(self, other: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                Digit::with_value_components::<DIGIT_BITNESS, _>(
+                    self,
+                    |value_sign, value_digits| Self::Output {
+                        sign: value_sign * other.sign,
+                        digits: Digit::multiply_digits::<DIGIT_BITNESS>(
+                            value_digits,
+                            &other.digits,
+                        ),
+                    },
+                )
+            }
+        }
+    )*)
+}
+
+big_int_mul_integer_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
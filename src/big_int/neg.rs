@@ -1,6 +1,6 @@
 use std::ops::Neg;
 
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit: Clone, const DIGIT_BITNESS: usize> Neg
     for &BigInt<Digit, DIGIT_BITNESS>
@@ -25,3 +25,16 @@ impl<Digit, const DIGIT_BITNESS: usize> Neg for BigInt<Digit, DIGIT_BITNESS> {
         }
     }
 }
+
+impl<Digit: Clone, const DIGIT_BITNESS: usize> Neg
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            sign: -self.sign,
+            digits: self.digits.to_vec(),
+        }
+    }
+}
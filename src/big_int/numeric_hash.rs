@@ -0,0 +1,39 @@
+use crate::traits::{reduce_modulo_hash_modulus, to_signed_hash, NumericHash};
+
+use super::types::{BigInt, BigIntRef};
+
+impl<Digit: Copy, const DIGIT_BITNESS: usize> NumericHash
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    u128: From<Digit>,
+{
+    fn numeric_hash(&self) -> isize {
+        BigIntRef::from(self).numeric_hash()
+    }
+}
+
+impl<Digit: Copy, const DIGIT_BITNESS: usize> NumericHash
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+where
+    u128: From<Digit>,
+{
+    fn numeric_hash(&self) -> isize {
+        to_signed_hash(
+            to_hash_residue::<Digit, DIGIT_BITNESS>(self.digits),
+            self.sign.is_negative(),
+        )
+    }
+}
+
+pub(crate) fn to_hash_residue<Digit: Copy, const DIGIT_BITNESS: usize>(
+    digits: &[Digit],
+) -> usize
+where
+    u128: From<Digit>,
+{
+    digits.iter().rev().fold(0usize, |result, &digit| {
+        reduce_modulo_hash_modulus(
+            ((result as u128) << DIGIT_BITNESS) | u128::from(digit),
+        )
+    })
+}
@@ -0,0 +1,142 @@
+use traiter::numbers::{Endianness, FromBytes, Sign, Signed, ToBytes};
+
+use super::digits::{
+    negate_bytes, trim_leading_zeros, trim_sign_extension_bytes,
+};
+use super::types::{BigInt, FromOrderPreservingBytesError};
+
+const NEGATIVE_HEADER: u8 = 0x7f;
+const POSITIVE_HEADER: u8 = 0x81;
+const ZERO_HEADER: u8 = 0x80;
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_order_preserving_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        write_order_preserving_bytes(self, false, &mut result);
+        result
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: FromBytes,
+{
+    pub fn from_order_preserving_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromOrderPreservingBytesError> {
+        read_order_preserving_bytes(bytes, false)
+    }
+}
+
+pub(crate) fn write_order_preserving_bytes<Digit, const DIGIT_BITNESS: usize>(
+    value: &BigInt<Digit, DIGIT_BITNESS>,
+    inverted: bool,
+    result: &mut Vec<u8>,
+) where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: ToBytes<Output = Vec<u8>>,
+{
+    let start = result.len();
+    match value.sign() {
+        Sign::Zero => result.push(ZERO_HEADER),
+        sign => {
+            let is_negative = sign == Sign::Negative;
+            let mut modulus_bytes = value.to_bytes(Endianness::Little);
+            if is_negative {
+                negate_bytes(&mut modulus_bytes);
+            }
+            trim_leading_zeros::<u8>(&mut modulus_bytes);
+            let length_bytes = modulus_bytes.len().to_be_bytes();
+            let length_bytes = &length_bytes[length_bytes
+                .iter()
+                .position(|&byte| byte != 0u8)
+                .unwrap_or(length_bytes.len() - 1)..];
+            result.push(if is_negative {
+                NEGATIVE_HEADER
+            } else {
+                POSITIVE_HEADER
+            });
+            let body_start = result.len();
+            result.push(length_bytes.len() as u8);
+            result.extend(length_bytes);
+            result.extend(modulus_bytes.iter().rev());
+            if is_negative {
+                result[body_start..]
+                    .iter_mut()
+                    .for_each(|byte| *byte = !*byte);
+            }
+        }
+    }
+    if inverted {
+        result[start..].iter_mut().for_each(|byte| *byte = !*byte);
+    }
+}
+
+pub(crate) fn read_order_preserving_bytes<Digit, const DIGIT_BITNESS: usize>(
+    bytes: &[u8],
+    inverted: bool,
+) -> Result<(BigInt<Digit, DIGIT_BITNESS>, usize), FromOrderPreservingBytesError>
+where
+    BigInt<Digit, DIGIT_BITNESS>: FromBytes,
+{
+    let header = read_byte(bytes, 0, inverted)?;
+    match header {
+        ZERO_HEADER => {
+            Ok((BigInt::from_bytes(&[0u8], Endianness::Little), 1usize))
+        }
+        NEGATIVE_HEADER | POSITIVE_HEADER => {
+            let is_negative = header == NEGATIVE_HEADER;
+            let body_inverted = inverted ^ is_negative;
+            let length_size =
+                read_byte(bytes, 1usize, body_inverted)? as usize;
+            if length_size == 0usize {
+                return Err(FromOrderPreservingBytesError::NonCanonical);
+            } else if length_size > std::mem::size_of::<usize>() {
+                return Err(FromOrderPreservingBytesError::TooLongLength);
+            }
+            let modulus_start = 2usize + length_size;
+            let mut length = 0usize;
+            for index in 2usize..modulus_start {
+                let byte = read_byte(bytes, index, body_inverted)?;
+                if index == 2usize && byte == 0u8 {
+                    return Err(FromOrderPreservingBytesError::NonCanonical);
+                }
+                length = (length << u8::BITS) | (byte as usize);
+            }
+            let size = modulus_start
+                .checked_add(length)
+                .ok_or(FromOrderPreservingBytesError::TooLongLength)?;
+            if bytes.len() < size {
+                return Err(FromOrderPreservingBytesError::Truncated);
+            }
+            let mut modulus_bytes = bytes[modulus_start..size]
+                .iter()
+                .rev()
+                .map(|&byte| if body_inverted { !byte } else { byte })
+                .collect::<Vec<_>>();
+            if modulus_bytes[length - 1] == 0u8 {
+                return Err(FromOrderPreservingBytesError::NonCanonical);
+            }
+            modulus_bytes.push(0u8);
+            if is_negative {
+                negate_bytes(&mut modulus_bytes);
+            }
+            trim_sign_extension_bytes(&mut modulus_bytes);
+            Ok((BigInt::from_bytes(&modulus_bytes, Endianness::Little), size))
+        }
+        _ => Err(FromOrderPreservingBytesError::InvalidHeader(header)),
+    }
+}
+
+fn read_byte(
+    bytes: &[u8],
+    index: usize,
+    inverted: bool,
+) -> Result<u8, FromOrderPreservingBytesError> {
+    bytes
+        .get(index)
+        .map(|&byte| if inverted { !byte } else { byte })
+        .ok_or(FromOrderPreservingBytesError::Truncated)
+}
@@ -1,7 +1,9 @@
+use std::convert::TryFrom;
+
 use traiter::numbers::Zeroable;
 
 use super::digits::{value_to_sign, DigitsFromNonZeroValue};
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 macro_rules! integer_partial_eq_big_int_impl {
     ($($integer:ty)*) => ($(
@@ -40,3 +42,62 @@ macro_rules! integer_partial_eq_big_int_impl {
 integer_partial_eq_big_int_impl!(
     i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
 );
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq<f64>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: PartialEq + TryFrom<f64>,
+{
+    fn eq(&self, other: &f64) -> bool {
+        other.fract() == 0.0
+            && Self::try_from(*other).is_ok_and(|other| self.eq(&other))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq<BigInt<Digit, DIGIT_BITNESS>>
+    for f64
+where
+    BigInt<Digit, DIGIT_BITNESS>: PartialEq<f64>,
+{
+    fn eq(&self, other: &BigInt<Digit, DIGIT_BITNESS>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq<f32>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: PartialEq<f64>,
+{
+    fn eq(&self, other: &f32) -> bool {
+        self.eq(&f64::from(*other))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq<BigInt<Digit, DIGIT_BITNESS>>
+    for f32
+where
+    BigInt<Digit, DIGIT_BITNESS>: PartialEq<f32>,
+{
+    fn eq(&self, other: &BigInt<Digit, DIGIT_BITNESS>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<Digit: PartialEq, const DIGIT_BITNESS: usize>
+    PartialEq<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn eq(&self, other: &BigIntRef<'_, Digit, DIGIT_BITNESS>) -> bool {
+        self.sign == other.sign && self.digits == other.digits
+    }
+}
+
+impl<Digit: PartialEq, const DIGIT_BITNESS: usize>
+    PartialEq<BigInt<Digit, DIGIT_BITNESS>>
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    fn eq(&self, other: &BigInt<Digit, DIGIT_BITNESS>) -> bool {
+        self.sign == other.sign && self.digits == other.digits
+    }
+}
@@ -1,9 +1,10 @@
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 
 use traiter::numbers::{Sign, Signed, Zeroable};
 
 use super::digits::{compare_digits, DigitsFromNonZeroValue};
-use super::types::{BigInt, Sign as BigIntSign};
+use super::types::{BigInt, BigIntRef, Sign as BigIntSign};
 
 impl<Digit: Ord, const DIGIT_BITNESS: usize> PartialOrd
     for BigInt<Digit, DIGIT_BITNESS>
@@ -169,3 +170,97 @@ macro_rules! unsigned_integer_partial_ord_big_int_impl {
 }
 
 unsigned_integer_partial_ord_big_int_impl!(u8 u16 u32 u64 u128 usize);
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialOrd<f64>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: Ord + PartialEq<f64> + TryFrom<f64>,
+{
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        if other.is_nan() {
+            None
+        } else if other.is_infinite() {
+            Some(if other.is_sign_positive() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            })
+        } else {
+            let other_integer_part = other.trunc();
+            match self.cmp(&Self::try_from(other_integer_part).ok()?) {
+                Ordering::Equal => other_integer_part.partial_cmp(other),
+                value => Some(value),
+            }
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    PartialOrd<BigInt<Digit, DIGIT_BITNESS>> for f64
+where
+    BigInt<Digit, DIGIT_BITNESS>: PartialOrd<f64>,
+    Self: PartialEq<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn partial_cmp(
+        &self,
+        other: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialOrd<f32>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: PartialEq<f32> + PartialOrd<f64>,
+{
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        self.partial_cmp(&f64::from(*other))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    PartialOrd<BigInt<Digit, DIGIT_BITNESS>> for f32
+where
+    BigInt<Digit, DIGIT_BITNESS>: PartialOrd<f32>,
+    Self: PartialEq<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn partial_cmp(
+        &self,
+        other: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl<Digit: Ord, const DIGIT_BITNESS: usize> PartialOrd
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Digit: Ord, const DIGIT_BITNESS: usize>
+    PartialOrd<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn partial_cmp(
+        &self,
+        other: &BigIntRef<'_, Digit, DIGIT_BITNESS>,
+    ) -> Option<Ordering> {
+        Some(BigIntRef::from(self).cmp(other))
+    }
+}
+
+impl<Digit: Ord, const DIGIT_BITNESS: usize>
+    PartialOrd<BigInt<Digit, DIGIT_BITNESS>>
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    fn partial_cmp(
+        &self,
+        other: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Option<Ordering> {
+        Some(self.cmp(&BigIntRef::from(other)))
+    }
+}
@@ -0,0 +1,216 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::{Endianness, FromBytes, Signed, ToBytes};
+
+use super::digits::{
+    negate_bytes, trim_leading_zeros, trim_sign_extension_bytes,
+    BaseFromBinaryDigits, MultiplyAddDigitsInPlace,
+};
+use super::types::{BigInt, FromPgNumericError};
+
+const NUMERIC_BASE: u16 = 10000;
+const NUMERIC_BASE_DECIMAL_DIGITS_COUNT: usize = 4;
+const NUMERIC_HEADER_SIZE: usize = 8;
+pub(crate) const NUMERIC_MAX_DISPLAY_SCALE: usize = 0x3fff;
+const NUMERIC_NAN: u16 = 0xc000;
+const NUMERIC_NEGATIVE: u16 = 0x4000;
+const NUMERIC_NEGATIVE_INFINITY: u16 = 0xf000;
+const NUMERIC_POSITIVE: u16 = 0x0000;
+const NUMERIC_POSITIVE_INFINITY: u16 = 0xd000;
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_pg_numeric(&self) -> Option<Vec<u8>> {
+        write_pg_numeric(self, 0usize)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: FromBytes,
+{
+    pub fn from_pg_numeric(bytes: &[u8]) -> Result<Self, FromPgNumericError> {
+        let (is_negative, digits, fractional_digits_count) =
+            parse_pg_numeric(bytes)?;
+        if fractional_digits_count > 0usize {
+            Err(FromPgNumericError::NonIntegral)
+        } else {
+            Ok(numeric_digits_to_big_int(&digits, is_negative))
+        }
+    }
+}
+
+pub(crate) fn write_pg_numeric<Digit, const DIGIT_BITNESS: usize>(
+    scaled_value: &BigInt<Digit, DIGIT_BITNESS>,
+    scale: usize,
+) -> Option<Vec<u8>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: ToBytes<Output = Vec<u8>>,
+{
+    if scale > NUMERIC_MAX_DISPLAY_SCALE {
+        return None;
+    }
+    let is_negative = scaled_value.is_negative();
+    let mut modulus_bytes = scaled_value.to_bytes(Endianness::Little);
+    if is_negative {
+        negate_bytes(&mut modulus_bytes);
+    }
+    trim_leading_zeros::<u8>(&mut modulus_bytes);
+    let mut digits = u16::base_from_binary_digits(
+        &modulus_bytes,
+        u8::BITS as usize,
+        NUMERIC_BASE as usize,
+    );
+    let padding = (NUMERIC_BASE_DECIMAL_DIGITS_COUNT
+        - scale % NUMERIC_BASE_DECIMAL_DIGITS_COUNT)
+        % NUMERIC_BASE_DECIMAL_DIGITS_COUNT;
+    let multiplier = 10u32.pow(padding as u32);
+    let mut carry = 0u32;
+    for digit in digits.iter_mut() {
+        let step = (*digit as u32) * multiplier + carry;
+        *digit = (step % (NUMERIC_BASE as u32)) as u16;
+        carry = step / (NUMERIC_BASE as u32);
+    }
+    if carry != 0u32 {
+        digits.push(carry as u16);
+    }
+    trim_leading_zeros::<u16>(&mut digits);
+    let fractional_digits_count =
+        (scale + padding) / NUMERIC_BASE_DECIMAL_DIGITS_COUNT;
+    let (weight, digits) = if digits.iter().all(|&digit| digit == 0u16) {
+        (0isize, &digits[..0])
+    } else {
+        let lowest_non_zero_position = unsafe {
+            digits
+                .iter()
+                .position(|&digit| digit != 0u16)
+                .unwrap_unchecked()
+        };
+        (
+            (digits.len() as isize) - 1 - (fractional_digits_count as isize),
+            &digits[lowest_non_zero_position..],
+        )
+    };
+    let digits_count = i16::try_from(digits.len()).ok()?;
+    let weight = i16::try_from(weight).ok()?;
+    let mut result =
+        Vec::with_capacity(NUMERIC_HEADER_SIZE + 2usize * digits.len());
+    result.extend(digits_count.to_be_bytes());
+    result.extend(weight.to_be_bytes());
+    result.extend(
+        if is_negative {
+            NUMERIC_NEGATIVE
+        } else {
+            NUMERIC_POSITIVE
+        }
+        .to_be_bytes(),
+    );
+    result.extend((scale as u16).to_be_bytes());
+    for digit in digits.iter().rev() {
+        result.extend(digit.to_be_bytes());
+    }
+    Some(result)
+}
+
+pub(crate) fn read_pg_numeric<Digit, const DIGIT_BITNESS: usize>(
+    bytes: &[u8],
+) -> Result<
+    (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
+    FromPgNumericError,
+>
+where
+    BigInt<Digit, DIGIT_BITNESS>: FromBytes,
+{
+    let (is_negative, digits, fractional_digits_count) =
+        parse_pg_numeric(bytes)?;
+    let mut denominator_digits = vec![0u16; fractional_digits_count + 1];
+    denominator_digits[fractional_digits_count] = 1u16;
+    Ok((
+        numeric_digits_to_big_int(&digits, is_negative),
+        numeric_digits_to_big_int(&denominator_digits, false),
+    ))
+}
+
+fn numeric_digits_to_big_int<Digit, const DIGIT_BITNESS: usize>(
+    digits: &[u16],
+    is_negative: bool,
+) -> BigInt<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: FromBytes,
+{
+    let mut limbs = vec![0u32];
+    for &digit in digits.iter().rev() {
+        u32::multiply_add_digits_in_place::<{ u32::BITS as usize }>(
+            &mut limbs,
+            NUMERIC_BASE as u32,
+            digit as u32,
+        );
+    }
+    let mut bytes = limbs
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect::<Vec<_>>();
+    bytes.push(0u8);
+    if is_negative {
+        negate_bytes(&mut bytes);
+    }
+    trim_sign_extension_bytes(&mut bytes);
+    BigInt::from_bytes(&bytes, Endianness::Little)
+}
+
+fn parse_pg_numeric(
+    bytes: &[u8],
+) -> Result<(bool, Vec<u16>, usize), FromPgNumericError> {
+    if bytes.len() < NUMERIC_HEADER_SIZE {
+        return Err(FromPgNumericError::InvalidLength);
+    }
+    let read_field =
+        |index: usize| u16::from_be_bytes([bytes[index], bytes[index + 1]]);
+    let digits_count = read_field(0usize) as i16;
+    let weight = read_field(2usize) as i16;
+    let is_negative = match read_field(4usize) {
+        NUMERIC_NAN => return Err(FromPgNumericError::NaN),
+        NUMERIC_NEGATIVE => true,
+        NUMERIC_NEGATIVE_INFINITY | NUMERIC_POSITIVE_INFINITY => {
+            return Err(FromPgNumericError::Infinity)
+        }
+        NUMERIC_POSITIVE => false,
+        sign => return Err(FromPgNumericError::InvalidSign(sign)),
+    };
+    if digits_count < 0i16
+        || bytes.len()
+            != NUMERIC_HEADER_SIZE + 2usize * (digits_count as usize)
+    {
+        return Err(FromPgNumericError::InvalidLength);
+    }
+    let mut digits = Vec::with_capacity(digits_count as usize);
+    for index in (NUMERIC_HEADER_SIZE..bytes.len()).step_by(2usize).rev() {
+        let digit = read_field(index);
+        if digit >= NUMERIC_BASE {
+            return Err(FromPgNumericError::InvalidDigit(digit));
+        }
+        digits.push(digit);
+    }
+    let lowest_non_zero_position =
+        match digits.iter().position(|&digit| digit != 0u16) {
+            Some(position) => position,
+            None => return Ok((false, vec![0u16], 0usize)),
+        };
+    digits.drain(..lowest_non_zero_position);
+    trim_leading_zeros::<u16>(&mut digits);
+    let fractional_digits_count = (digits_count as isize)
+        - 1
+        - (weight as isize)
+        - (lowest_non_zero_position as isize);
+    if fractional_digits_count < 0isize {
+        digits.splice(
+            ..0,
+            std::iter::repeat(0u16).take((-fractional_digits_count) as usize),
+        );
+        Ok((is_negative, digits, 0usize))
+    } else {
+        Ok((is_negative, digits, fractional_digits_count as usize))
+    }
+}
@@ -0,0 +1,52 @@
+use std::iter::Product;
+use std::ops::Mul;
+
+use traiter::numbers::One;
+
+use super::types::BigInt;
+
+impl<Digit, const DIGIT_BITNESS: usize> Product
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: Mul<Output = Self> + One,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        balanced_product(iter)
+    }
+}
+
+impl<'a, Digit: 'a, const DIGIT_BITNESS: usize> Product<&'a Self>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: Clone + Mul<Output = Self> + One,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        balanced_product(iter.cloned())
+    }
+}
+
+pub(crate) fn balanced_product<Value: Mul<Output = Value> + One>(
+    values: impl Iterator<Item = Value>,
+) -> Value {
+    let mut partial_products = Vec::<(Value, usize)>::new();
+    for mut value in values {
+        let mut level = 0usize;
+        while let Some((last_value, last_level)) = partial_products.pop() {
+            if last_level != level {
+                partial_products.push((last_value, last_level));
+                break;
+            }
+            value = last_value * value;
+            level += 1;
+        }
+        partial_products.push((value, level));
+    }
+    let mut result = match partial_products.pop() {
+        Some((value, _)) => value,
+        None => return Value::one(),
+    };
+    while let Some((value, _)) = partial_products.pop() {
+        result = value * result;
+    }
+    result
+}
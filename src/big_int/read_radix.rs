@@ -0,0 +1,169 @@
+use std::convert::TryFrom;
+use std::io::{BufRead, Error, ErrorKind, Result};
+
+use traiter::numbers::{One, Zero, Zeroable};
+
+use crate::traits::HasSignBit;
+
+use super::constants::{MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE};
+use super::contracts::is_valid_digit_bitness;
+use super::digits::{to_digits_sign, MultiplyAddDigitsInPlace};
+use super::display::{floor_log, power};
+use super::try_from_string::ASCII_CODES_DIGIT_VALUES;
+use super::types::{BigInt, Sign, TryFromStringError};
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Digit:
+        Copy + HasSignBit + MultiplyAddDigitsInPlace + TryFrom<usize> + Zero,
+    for<'a> &'a Digit: Zeroable,
+{
+    /// Stops at the first byte that can't continue the number
+    /// and leaves it unread in `reader`.
+    pub fn read_radix<R: BufRead>(reader: R, radix: u32) -> Result<Self> {
+        debug_assert!(is_valid_digit_bitness::<Digit, DIGIT_BITNESS>());
+        if radix != 0
+            && (radix < u32::from(MIN_REPRESENTABLE_BASE)
+                || radix > u32::from(MAX_REPRESENTABLE_BASE))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                TryFromStringError::BaseOutOfBounds(radix),
+            ));
+        }
+        let mut bytes = PeekableBytes { reader };
+        while bytes.peek()?.is_some_and(|byte| byte.is_ascii_whitespace()) {
+            bytes.next()?;
+        }
+        let sign = match bytes.peek()? {
+            Some(b'-') => {
+                bytes.next()?;
+                -Sign::one()
+            }
+            Some(b'+') => {
+                bytes.next()?;
+                Sign::one()
+            }
+            _ => Sign::one(),
+        };
+        let mut base = radix as usize;
+        let mut has_digits = false;
+        let mut is_separator_allowed = false;
+        if bytes.peek()? == Some(b'0') {
+            bytes.next()?;
+            is_separator_allowed = true;
+            match bytes.peek()? {
+                Some(b'b' | b'B') if base == 0 || base == 2 => {
+                    base = 2;
+                }
+                Some(b'o' | b'O') if base == 0 || base == 8 => {
+                    base = 8;
+                }
+                Some(b'x' | b'X') if base == 0 || base == 16 => {
+                    base = 16;
+                }
+                _ => {
+                    has_digits = true;
+                }
+            }
+            if !has_digits {
+                bytes.next()?;
+            }
+        }
+        if base == 0 {
+            base = 10;
+        }
+        let group_size =
+            floor_log(1 << DIGIT_BITNESS, base).unwrap_or(0usize).max(1);
+        let group_multiplier = unsafe {
+            Digit::try_from(power(base, group_size)).unwrap_unchecked()
+        };
+        let mut digits = vec![Digit::zero()];
+        let mut group = 0usize;
+        let mut group_digits_count = 0usize;
+        while let Some(byte) = bytes.peek()? {
+            if byte == b'_' && is_separator_allowed {
+                bytes.next()?;
+                is_separator_allowed = false;
+                if !bytes
+                    .peek()?
+                    .is_some_and(|byte| to_digit_value(byte) < base)
+                {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        TryFromStringError::InvalidDigit('_', base as u8),
+                    ));
+                }
+                continue;
+            }
+            let digit = to_digit_value(byte);
+            if digit >= base {
+                break;
+            }
+            bytes.next()?;
+            has_digits = true;
+            is_separator_allowed = true;
+            group = group * base + digit;
+            group_digits_count += 1;
+            if group_digits_count == group_size {
+                Digit::multiply_add_digits_in_place::<DIGIT_BITNESS>(
+                    &mut digits,
+                    group_multiplier,
+                    unsafe { Digit::try_from(group).unwrap_unchecked() },
+                );
+                group = 0;
+                group_digits_count = 0;
+            }
+        }
+        if group_digits_count > 0 {
+            Digit::multiply_add_digits_in_place::<DIGIT_BITNESS>(
+                &mut digits,
+                unsafe {
+                    Digit::try_from(power(base, group_digits_count))
+                        .unwrap_unchecked()
+                },
+                unsafe { Digit::try_from(group).unwrap_unchecked() },
+            );
+        }
+        if has_digits {
+            Ok(Self {
+                sign: sign * to_digits_sign(&digits),
+                digits,
+            })
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                TryFromStringError::NoDigits,
+            ))
+        }
+    }
+}
+
+#[inline]
+fn to_digit_value(byte: u8) -> usize {
+    ASCII_CODES_DIGIT_VALUES[byte as usize] as usize
+}
+
+struct PeekableBytes<R> {
+    reader: R,
+}
+
+impl<R: BufRead> PeekableBytes<R> {
+    fn next(&mut self) -> Result<Option<u8>> {
+        let result = self.peek()?;
+        if result.is_some() {
+            self.reader.consume(1);
+        }
+        Ok(result)
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>> {
+        loop {
+            match self.reader.fill_buf() {
+                Ok(buffer) => return Ok(buffer.first().copied()),
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@ use traiter::numbers::CheckedRem;
 
 use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
 
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit, const DIGIT_BITNESS: usize> Rem for BigInt<Digit, DIGIT_BITNESS>
 where
@@ -58,3 +58,81 @@ where
             .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
     }
 }
+
+impl<'a, 'b, Digit, const DIGIT_BITNESS: usize>
+    Rem<BigIntRef<'b, Digit, DIGIT_BITNESS>>
+    for BigIntRef<'a, Digit, DIGIT_BITNESS>
+where
+    Self: CheckedRem<
+        BigIntRef<'b, Digit, DIGIT_BITNESS>,
+        Output = Option<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn rem(
+        self,
+        divisor: BigIntRef<'b, Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        self.checked_rem(divisor)
+            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+    }
+}
+
+macro_rules! big_int_rem_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> Rem<$integer>
+            for BigInt<Digit, DIGIT_BITNESS>
+        where
+            Self: CheckedRem<$integer, Output = Option<Self>>,
+        {
+            type Output = Self;
+
+            fn rem(self, divisor: $integer) -> Self::Output {
+                self.checked_rem(divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> Rem<$integer>
+            for &BigInt<Digit, DIGIT_BITNESS>
+        where
+            Self: CheckedRem<$integer, Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn rem(self, divisor: $integer) -> Self::Output {
+                self.checked_rem(divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> Rem<BigInt<Digit, DIGIT_BITNESS>>
+            for $integer
+        where
+            Self: CheckedRem<BigInt<Digit, DIGIT_BITNESS>, Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn rem(self, divisor: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                CheckedRem::checked_rem(self, divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> Rem<&BigInt<Digit, DIGIT_BITNESS>>
+            for $integer
+        where
+            for<'a> Self: CheckedRem<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn rem(self, divisor: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                CheckedRem::checked_rem(self, divisor)
+                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+            }
+        }
+    )*)
+}
+
+big_int_rem_integer_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
@@ -0,0 +1,50 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use serde::ser::SerializeTuple;
+use serde::{Serialize, Serializer};
+
+use super::digits::BinaryBaseFromBinaryDigits;
+use super::types::BigInt;
+
+impl<
+        Digit: BinaryBaseFromBinaryDigits<Digit> + Copy,
+        const DIGIT_BITNESS: usize,
+    > Serialize for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: Display,
+    u8: TryFrom<Digit>,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let modulus_bytes = Digit::binary_base_from_binary_digits(
+                &self.digits,
+                DIGIT_BITNESS,
+                u8::BITS as usize,
+            )
+            .into_iter()
+            .map(|byte| unsafe { u8::try_from(byte).unwrap_unchecked() })
+            .collect::<Vec<u8>>();
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.sign)?;
+            tuple.serialize_element(&ModulusBytes(&modulus_bytes))?;
+            tuple.end()
+        }
+    }
+}
+
+struct ModulusBytes<'a>(&'a [u8]);
+
+impl Serialize for ModulusBytes<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
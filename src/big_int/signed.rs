@@ -1,6 +1,6 @@
 use traiter::numbers::{Sign, Signed};
 
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit, const DIGIT_BITNESS: usize> Signed
     for &BigInt<Digit, DIGIT_BITNESS>
@@ -33,3 +33,19 @@ impl<Digit, const DIGIT_BITNESS: usize> Signed
         self.sign.sign()
     }
 }
+
+impl<Digit, const DIGIT_BITNESS: usize> Signed
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    fn is_negative(self) -> bool {
+        self.sign.is_negative()
+    }
+
+    fn is_positive(self) -> bool {
+        self.sign.is_positive()
+    }
+
+    fn sign(self) -> Sign {
+        self.sign.sign()
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::Sub;
 
-use super::digits::SubtractComponents;
-use super::types::BigInt;
+use super::digits::{SubtractComponents, WithValueComponents};
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit: SubtractComponents, const DIGIT_BITNESS: usize> Sub
     for BigInt<Digit, DIGIT_BITNESS>
@@ -66,3 +66,121 @@ impl<Digit: SubtractComponents, const DIGIT_BITNESS: usize> Sub
         Self::Output { sign, digits }
     }
 }
+
+impl<Digit: SubtractComponents, const DIGIT_BITNESS: usize>
+    Sub<BigIntRef<'_, Digit, DIGIT_BITNESS>>
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn sub(
+        self,
+        subtrahend: BigIntRef<'_, Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        let (sign, digits) = Digit::subtract_components::<DIGIT_BITNESS>(
+            self.sign,
+            self.digits,
+            subtrahend.sign,
+            subtrahend.digits,
+        );
+        Self::Output { sign, digits }
+    }
+}
+
+macro_rules! big_int_sub_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<
+                Digit: SubtractComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Sub<$integer> for BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = Self;
+
+            fn sub(self, subtrahend: $integer) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(subtrahend, |value_sign, value_digits| {
+                    Digit::subtract_components::<DIGIT_BITNESS>(
+                        self.sign,
+                        &self.digits,
+                        value_sign,
+                        value_digits,
+                    )
+                });
+                Self { sign, digits }
+            }
+        }
+
+        impl<
+                Digit: SubtractComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Sub<$integer> for &BigInt<Digit, DIGIT_BITNESS>
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn sub(self, subtrahend: $integer) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(subtrahend, |value_sign, value_digits| {
+                    Digit::subtract_components::<DIGIT_BITNESS>(
+                        self.sign,
+                        &self.digits,
+                        value_sign,
+                        value_digits,
+                    )
+                });
+                Self::Output { sign, digits }
+            }
+        }
+
+        impl<
+                Digit: SubtractComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Sub<BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn sub(self, subtrahend: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(self, |value_sign, value_digits| {
+                    Digit::subtract_components::<DIGIT_BITNESS>(
+                        value_sign,
+                        value_digits,
+                        subtrahend.sign,
+                        &subtrahend.digits,
+                    )
+                });
+                Self::Output { sign, digits }
+            }
+        }
+
+        impl<
+                Digit: SubtractComponents + WithValueComponents<$integer>,
+                const DIGIT_BITNESS: usize,
+            > Sub<&BigInt<Digit, DIGIT_BITNESS>> for $integer
+        {
+            type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+            fn sub(self, subtrahend: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+                let (sign, digits) = Digit::with_value_components::<
+                    DIGIT_BITNESS,
+                    _,
+                >(self, |value_sign, value_digits| {
+                    Digit::subtract_components::<DIGIT_BITNESS>(
+                        value_sign,
+                        value_digits,
+                        subtrahend.sign,
+                        &subtrahend.digits,
+                    )
+                });
+                Self::Output { sign, digits }
+            }
+        }
+    )*)
+}
+
+big_int_sub_integer_impl!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
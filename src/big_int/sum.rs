@@ -0,0 +1,25 @@
+use std::iter::Sum;
+use std::ops::Add;
+
+use traiter::numbers::Zero;
+
+use super::types::BigInt;
+
+impl<Digit, const DIGIT_BITNESS: usize> Sum for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: Add<Output = Self> + Zero,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |result, value| result + value)
+    }
+}
+
+impl<'a, Digit: 'a, const DIGIT_BITNESS: usize> Sum<&'a Self>
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: Add<&'a Self, Output = Self> + Zero,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |result, value| result + value)
+    }
+}
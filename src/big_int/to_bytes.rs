@@ -6,12 +6,12 @@ use crate::traits::Oppose;
 
 use super::constants::MIDDLE_BYTE;
 use super::digits::{negate_bytes, BinaryBaseFromBinaryDigits};
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<
         Digit: BinaryBaseFromBinaryDigits<Digit> + Copy + From<u8> + Oppose,
         const DIGIT_BITNESS: usize,
-    > ToBytes for &BigInt<Digit, DIGIT_BITNESS>
+    > ToBytes for BigIntRef<'_, Digit, DIGIT_BITNESS>
 where
     for<'a> &'a Digit: Zeroable,
     u8: TryFrom<Digit>,
@@ -20,7 +20,7 @@ where
 
     fn to_bytes(self, endianness: Endianness) -> Self::Output {
         let mut result = Digit::binary_base_from_binary_digits(
-            &self.digits,
+            self.digits,
             DIGIT_BITNESS,
             u8::BITS as usize,
         )
@@ -45,3 +45,15 @@ where
         result
     }
 }
+
+impl<Digit, const DIGIT_BITNESS: usize> ToBytes
+    for &BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> BigIntRef<'a, Digit, DIGIT_BITNESS>: ToBytes<Output = Vec<u8>>,
+{
+    type Output = Vec<u8>;
+
+    fn to_bytes(self, endianness: Endianness) -> Self::Output {
+        BigIntRef::from(self).to_bytes(endianness)
+    }
+}
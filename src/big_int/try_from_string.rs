@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::iter::Peekable;
 use std::str::Chars;
 
@@ -19,7 +20,7 @@ pub trait TryFromString: Sized {
     ) -> Result<Self, TryFromStringError>;
 }
 
-const ASCII_CODES_DIGIT_VALUES: [u8; 256] = [
+pub(super) const ASCII_CODES_DIGIT_VALUES: [u8; 256] = [
     37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
     37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
     37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 0, 1, 2, 3, 4, 5, 6, 7, 8,
@@ -98,7 +99,8 @@ fn parse_digits(
         Vec::<u8>::with_capacity(characters_count)
     };
     for character in characters {
-        let digit = ASCII_CODES_DIGIT_VALUES[character as usize];
+        let digit = u8::try_from(character)
+            .map_or(base, |code| ASCII_CODES_DIGIT_VALUES[code as usize]);
         if digit >= base {
             return Err(TryFromStringError::InvalidDigit(character, base));
         }
@@ -129,20 +131,14 @@ fn parse_sign(characters: &mut Peekable<Chars<'_>>) -> i8 {
 fn skip_prefix(characters: &mut Peekable<Chars<'_>>, base: u8) {
     if characters.peek() == Some(&'0') {
         match characters.clone().nth(1) {
-            Some('b' | 'B') => {
-                if base == 2 {
-                    characters.nth(1);
-                }
+            Some('b' | 'B') if base == 2 => {
+                characters.nth(1);
             }
-            Some('o' | 'O') => {
-                if base == 8 {
-                    characters.nth(1);
-                }
+            Some('o' | 'O') if base == 8 => {
+                characters.nth(1);
             }
-            Some('x' | 'X') => {
-                if base == 16 {
-                    characters.nth(1);
-                }
+            Some('x' | 'X') if base == 16 => {
+                characters.nth(1);
             }
             _ => {}
         };
@@ -55,9 +55,9 @@ macro_rules! float_try_from_big_int_impl {
 float_try_from_big_int_impl!(f32 f64);
 
 macro_rules! signed_integer_try_from_big_int_impl {
-    ($($integer:ty)*) => ($(
+    ($($integer:ty => $unsigned:ty)*) => ($(
         impl<
-                Digit: MaybeReduceDigits<$integer>,
+                Digit: MaybeReduceDigits<$unsigned>,
                 const DIGIT_BITNESS: usize,
             > TryFrom<BigInt<Digit, DIGIT_BITNESS>> for $integer
         where
@@ -68,20 +68,25 @@ macro_rules! signed_integer_try_from_big_int_impl {
             fn try_from(
                 value: BigInt<Digit, DIGIT_BITNESS>,
             ) -> Result<Self, Self::Error> {
-                let result =
+                let modulus: $unsigned =
                     Digit::maybe_reduce_digits::<DIGIT_BITNESS>(&value.digits)
-                        .ok_or(TryIntoSignedIntegerError::TooLarge);
+                        .ok_or(TryIntoSignedIntegerError::TooLarge)?;
                 if value.is_negative() {
-                    result.map(|value| -value)
+                    if modulus > <$integer>::MIN.unsigned_abs() {
+                        Err(TryIntoSignedIntegerError::TooLarge)
+                    } else {
+                        Ok((modulus as $integer).wrapping_neg())
+                    }
                 } else {
-                    result
+                    <$integer>::try_from(modulus)
+                        .map_err(|_| TryIntoSignedIntegerError::TooLarge)
                 }
             }
         }
 
         impl<
                 'a,
-                Digit: MaybeReduceDigits<$integer>,
+                Digit: MaybeReduceDigits<$unsigned>,
                 const DIGIT_BITNESS: usize,
             > TryFrom<&'a BigInt<Digit, DIGIT_BITNESS>> for $integer
         where
@@ -92,20 +97,27 @@ macro_rules! signed_integer_try_from_big_int_impl {
             fn try_from(
                 value: &'a BigInt<Digit, DIGIT_BITNESS>,
             ) -> Result<Self, Self::Error> {
-                let result =
+                let modulus: $unsigned =
                     Digit::maybe_reduce_digits::<DIGIT_BITNESS>(&value.digits)
-                        .ok_or(TryIntoSignedIntegerError::TooLarge);
+                        .ok_or(TryIntoSignedIntegerError::TooLarge)?;
                 if value.is_negative() {
-                    result.map(|value| -value)
+                    if modulus > <$integer>::MIN.unsigned_abs() {
+                        Err(TryIntoSignedIntegerError::TooLarge)
+                    } else {
+                        Ok((modulus as $integer).wrapping_neg())
+                    }
                 } else {
-                    result
+                    <$integer>::try_from(modulus)
+                        .map_err(|_| TryIntoSignedIntegerError::TooLarge)
                 }
             }
         }
     )*)
 }
 
-signed_integer_try_from_big_int_impl!(i8 i16 i32 i64 i128 isize);
+signed_integer_try_from_big_int_impl!(
+    i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize
+);
 
 macro_rules! unsigned_integer_try_from_big_int_impl {
     ($($integer:ty)*) => ($(
@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::Shl;
+
+use traiter::numbers::{
+    Abs, BitLength, CheckedDivRemEuclid, LoadExp, One, Signed, Zeroable,
+};
+
+use super::types::{BigInt, FloatOverflow, FloatRounding, TryIntoFloatError};
+
+macro_rules! big_int_try_to_float_impl {
+    ($($float:ty => $method:ident)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+        where
+            for<'a> &'a Self: Abs<Output = Self>
+                + BitLength<Output = Self>
+                + CheckedDivRemEuclid<Output = Option<(Self, Self)>>
+                + Signed
+                + Zeroable,
+            Self: Clone + One + Ord + Shl<usize, Output = Self>,
+            u64: TryFrom<Self>,
+            usize: TryFrom<Self>,
+        {
+            pub fn $method(
+                &self,
+                rounding: FloatRounding,
+                overflow: FloatOverflow,
+            ) -> Result<$float, TryIntoFloatError> {
+                let is_negative = self.is_negative();
+                let sign = if is_negative { -1.0 } else { 1.0 };
+                div_moduli_as_float_parts(
+                    &self.abs(),
+                    &Self::one(),
+                    is_negative,
+                    <$float>::MANTISSA_DIGITS,
+                    <$float>::MIN_EXP,
+                    <$float>::MAX_EXP,
+                    rounding,
+                    overflow,
+                )
+                .map(|parts| match parts {
+                    Some((mantissa, exponent)) => {
+                        sign * (mantissa as $float).load_exp(exponent)
+                    }
+                    None => sign * <$float>::INFINITY,
+                })
+            }
+        }
+    )*)
+}
+
+big_int_try_to_float_impl!(
+    f32 => try_to_f32_rounded
+    f64 => try_to_f64_rounded
+);
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn div_moduli_as_float_parts<Digit, const DIGIT_BITNESS: usize>(
+    dividend: &BigInt<Digit, DIGIT_BITNESS>,
+    divisor: &BigInt<Digit, DIGIT_BITNESS>,
+    is_negative: bool,
+    mantissa_digits: u32,
+    min_exponent: i32,
+    max_exponent: i32,
+    rounding: FloatRounding,
+    overflow: FloatOverflow,
+) -> Result<Option<(u64, i32)>, TryIntoFloatError>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>:
+        Clone + Ord + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+    u64: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    if dividend.is_zero() {
+        return Ok(Some((0u64, 0i32)));
+    }
+    let dividend_bit_length =
+        unsafe { usize::try_from(dividend.bit_length()).unwrap_unchecked() }
+            as isize;
+    let divisor_bit_length =
+        unsafe { usize::try_from(divisor.bit_length()).unwrap_unchecked() }
+            as isize;
+    if dividend_bit_length - divisor_bit_length > (max_exponent as isize) {
+        return on_overflow(
+            is_negative,
+            mantissa_digits,
+            max_exponent,
+            rounding,
+            overflow,
+        );
+    }
+    let mut exponent = (dividend_bit_length
+        - divisor_bit_length
+        - (mantissa_digits as isize))
+        .max((min_exponent as isize) - (mantissa_digits as isize));
+    let (scaled_dividend, scaled_divisor) = if exponent < 0 {
+        (dividend.clone() << ((-exponent) as usize), divisor.clone())
+    } else {
+        (dividend.clone(), divisor.clone() << (exponent as usize))
+    };
+    let (quotient, remainder) = unsafe {
+        scaled_dividend
+            .checked_div_rem_euclid(&scaled_divisor)
+            .unwrap_unchecked()
+    };
+    let mut mantissa = unsafe { u64::try_from(quotient).unwrap_unchecked() };
+    let is_remainder_zero = (&remainder).is_zero();
+    let (mut half_ordering, mut is_exact) = if is_remainder_zero {
+        (Ordering::Less, true)
+    } else {
+        ((remainder << 1usize).cmp(&scaled_divisor), false)
+    };
+    if mantissa >> mantissa_digits != 0u64 {
+        (half_ordering, is_exact) = if mantissa & 1u64 == 0u64 {
+            (Ordering::Less, is_exact)
+        } else if is_remainder_zero {
+            (Ordering::Equal, false)
+        } else {
+            (Ordering::Greater, false)
+        };
+        mantissa >>= 1;
+        exponent += 1;
+    }
+    let round_up = match rounding {
+        FloatRounding::NearestEven => {
+            half_ordering == Ordering::Greater
+                || (half_ordering == Ordering::Equal
+                    && mantissa & 1u64 == 1u64)
+        }
+        FloatRounding::TowardNegative => !is_exact && is_negative,
+        FloatRounding::TowardPositive => !is_exact && !is_negative,
+        FloatRounding::TowardZero => false,
+    };
+    if round_up {
+        mantissa += 1u64;
+        if mantissa >> mantissa_digits != 0u64 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+    }
+    if ((u64::BITS - mantissa.leading_zeros()) as isize) + exponent
+        > (max_exponent as isize)
+    {
+        on_overflow(
+            is_negative,
+            mantissa_digits,
+            max_exponent,
+            rounding,
+            overflow,
+        )
+    } else {
+        Ok(Some((mantissa, exponent as i32)))
+    }
+}
+
+fn on_overflow(
+    is_negative: bool,
+    mantissa_digits: u32,
+    max_exponent: i32,
+    rounding: FloatRounding,
+    overflow: FloatOverflow,
+) -> Result<Option<(u64, i32)>, TryIntoFloatError> {
+    let is_infinite = match rounding {
+        FloatRounding::NearestEven => true,
+        FloatRounding::TowardNegative => is_negative,
+        FloatRounding::TowardPositive => !is_negative,
+        FloatRounding::TowardZero => false,
+    };
+    if !is_infinite {
+        Ok(Some((
+            (1u64 << mantissa_digits) - 1u64,
+            max_exponent - (mantissa_digits as i32),
+        )))
+    } else {
+        match overflow {
+            FloatOverflow::Error => Err(TryIntoFloatError::TooLarge),
+            FloatOverflow::Infinity => Ok(None),
+        }
+    }
+}
@@ -1,21 +1,104 @@
+use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::Shr;
+
+use traiter::numbers::{One, Signed, Zero, Zeroable};
 
 use super::constants::{MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE};
 
 pub(super) type Sign = i8;
 pub(super) type WindowDigit = u8;
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BigInt<Digit, const DIGIT_BITNESS: usize> {
     pub(super) sign: Sign,
     pub(super) digits: Vec<Digit>,
 }
 
-#[cfg(feature = "python_binding")]
 impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS> {
-    pub(crate) fn digits(&self) -> &[Digit] {
+    pub fn as_digits(&self) -> &[Digit] {
         &self.digits
     }
+
+    pub fn into_sign_and_digits(self) -> (traiter::numbers::Sign, Vec<Digit>) {
+        (self.sign.sign(), self.digits)
+    }
+}
+
+impl<Digit: Copy + Shr<usize, Output = Digit>, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    pub fn from_sign_and_digits(
+        sign: traiter::numbers::Sign,
+        digits: &[Digit],
+    ) -> Result<Self, FromSignAndDigitsError> {
+        BigIntRef::from_sign_and_digits(sign, digits).map(Self::from)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct BigIntRef<'a, Digit, const DIGIT_BITNESS: usize> {
+    pub(super) sign: Sign,
+    pub(super) digits: &'a [Digit],
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Clone
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Copy
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+}
+
+impl<'a, Digit, const DIGIT_BITNESS: usize>
+    BigIntRef<'a, Digit, DIGIT_BITNESS>
+{
+    pub fn as_digits(&self) -> &'a [Digit] {
+        self.digits
+    }
+}
+
+impl<
+        'a,
+        Digit: Copy + Shr<usize, Output = Digit>,
+        const DIGIT_BITNESS: usize,
+    > BigIntRef<'a, Digit, DIGIT_BITNESS>
+where
+    for<'b> &'b Digit: Zeroable,
+{
+    pub fn from_sign_and_digits(
+        sign: traiter::numbers::Sign,
+        digits: &'a [Digit],
+    ) -> Result<Self, FromSignAndDigitsError> {
+        if digits.is_empty() {
+            return Err(FromSignAndDigitsError::NoDigits);
+        }
+        if digits
+            .iter()
+            .any(|&digit| !(digit >> DIGIT_BITNESS).is_zero())
+        {
+            return Err(FromSignAndDigitsError::TooLargeDigit);
+        }
+        let digits = &digits[..digits
+            .iter()
+            .rposition(|digit| !digit.is_zero())
+            .map_or(1, |position| position + 1)];
+        let is_zero = digits.len() == 1 && digits[0].is_zero();
+        let sign = match sign {
+            traiter::numbers::Sign::Negative if !is_zero => -Sign::one(),
+            traiter::numbers::Sign::Positive if !is_zero => Sign::one(),
+            traiter::numbers::Sign::Zero if is_zero => Sign::zero(),
+            _ => return Err(FromSignAndDigitsError::InconsistentSign),
+        };
+        Ok(Self { sign, digits })
+    }
 }
 
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -49,6 +132,8 @@ impl Display for CheckedDivAsFloatError {
     }
 }
 
+impl Error for CheckedDivAsFloatError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum CheckedPowRemEuclidError {
     ZeroDivisor,
@@ -56,7 +141,7 @@ pub enum CheckedPowRemEuclidError {
 }
 
 impl CheckedPowRemEuclidError {
-    fn description(self) -> &'static str {
+    fn description(&self) -> &'static str {
         match self {
             CheckedPowRemEuclidError::ZeroDivisor => {
                 "Divisor should not be zero."
@@ -80,6 +165,233 @@ impl Display for CheckedPowRemEuclidError {
     }
 }
 
+impl Error for CheckedPowRemEuclidError {}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FloatOverflow {
+    Error,
+    Infinity,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FloatRounding {
+    NearestEven,
+    TowardNegative,
+    TowardPositive,
+    TowardZero,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromAsn1IntegerError {
+    EmptyContent,
+    IndefiniteLength,
+    InvalidTag(u8),
+    NonMinimalContent,
+    NonMinimalLength,
+    ReservedLength,
+    TooLongLength,
+    Truncated,
+}
+
+impl FromAsn1IntegerError {
+    fn description(&self) -> String {
+        match self {
+            FromAsn1IntegerError::EmptyContent => {
+                String::from("Integer content should not be empty.")
+            }
+            FromAsn1IntegerError::IndefiniteLength => String::from(
+                "Integer should not be encoded with indefinite length.",
+            ),
+            FromAsn1IntegerError::InvalidTag(tag) => {
+                format!("Expected integer tag 0x02, but found: {tag:#04x}.")
+            }
+            FromAsn1IntegerError::NonMinimalContent => String::from(
+                "Integer content should be encoded with minimal octets count.",
+            ),
+            FromAsn1IntegerError::NonMinimalLength => String::from(
+                "Length should be encoded with minimal octets count.",
+            ),
+            FromAsn1IntegerError::ReservedLength => {
+                String::from("Length octet 0xff is reserved.")
+            }
+            FromAsn1IntegerError::TooLongLength => {
+                String::from("Length is too large to be addressed.")
+            }
+            FromAsn1IntegerError::Truncated => {
+                String::from("Unexpected end of input.")
+            }
+        }
+    }
+}
+
+impl Debug for FromAsn1IntegerError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for FromAsn1IntegerError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+impl Error for FromAsn1IntegerError {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromOrderPreservingBytesError {
+    InvalidHeader(u8),
+    NonCanonical,
+    TooLongLength,
+    Truncated,
+}
+
+impl FromOrderPreservingBytesError {
+    fn description(&self) -> String {
+        match self {
+            FromOrderPreservingBytesError::InvalidHeader(header) => {
+                format!("Unexpected header byte: {header:#04x}.")
+            }
+            FromOrderPreservingBytesError::NonCanonical => {
+                String::from("Value should be encoded canonically.")
+            }
+            FromOrderPreservingBytesError::TooLongLength => {
+                String::from("Length is too large to be addressed.")
+            }
+            FromOrderPreservingBytesError::Truncated => {
+                String::from("Unexpected end of input.")
+            }
+        }
+    }
+}
+
+impl Debug for FromOrderPreservingBytesError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for FromOrderPreservingBytesError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+impl Error for FromOrderPreservingBytesError {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromPgNumericError {
+    Infinity,
+    InvalidDigit(u16),
+    InvalidLength,
+    InvalidSign(u16),
+    NaN,
+    NonIntegral,
+}
+
+impl FromPgNumericError {
+    fn description(&self) -> String {
+        match self {
+            FromPgNumericError::Infinity => {
+                String::from("Conversion of infinity is undefined.")
+            }
+            FromPgNumericError::InvalidDigit(digit) => format!(
+                "Digits should be less than 10000, but found: {digit}."
+            ),
+            FromPgNumericError::InvalidLength => String::from(
+                "Input length should agree with the digits count.",
+            ),
+            FromPgNumericError::InvalidSign(sign) => {
+                format!("Unexpected sign field: {sign:#06x}.")
+            }
+            FromPgNumericError::NaN => {
+                String::from("Conversion of NaN is undefined.")
+            }
+            FromPgNumericError::NonIntegral => {
+                String::from("Value should be integral.")
+            }
+        }
+    }
+}
+
+impl Debug for FromPgNumericError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for FromPgNumericError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+impl Error for FromPgNumericError {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromSignAndDigitsError {
+    InconsistentSign,
+    NoDigits,
+    TooLargeDigit,
+}
+
+impl FromSignAndDigitsError {
+    fn description(&self) -> &'static str {
+        match self {
+            FromSignAndDigitsError::InconsistentSign => {
+                "Sign should be zero if and only if digits are zero."
+            }
+            FromSignAndDigitsError::NoDigits => "No digits found.",
+            FromSignAndDigitsError::TooLargeDigit => {
+                "Digits should be less than two to the digit bitness."
+            }
+        }
+    }
+}
+
+impl Debug for FromSignAndDigitsError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
+impl Display for FromSignAndDigitsError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+impl Error for FromSignAndDigitsError {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromVarintError {
+    Truncated,
+}
+
+impl FromVarintError {
+    fn description(&self) -> &'static str {
+        match self {
+            FromVarintError::Truncated => {
+                "Unexpected end of input before the last varint byte."
+            }
+        }
+    }
+}
+
+impl Debug for FromVarintError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
+impl Display for FromVarintError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+impl Error for FromVarintError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum ShlError {
     NegativeShift,
@@ -88,7 +400,7 @@ pub enum ShlError {
 }
 
 impl ShlError {
-    fn description(self) -> &'static str {
+    fn description(&self) -> &'static str {
         match self {
             ShlError::NegativeShift => "Shift by negative step is undefined.",
             ShlError::OutOfMemory => "Not enough memory for shift result.",
@@ -109,13 +421,15 @@ impl Display for ShlError {
     }
 }
 
+impl Error for ShlError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum ShrError {
     NegativeShift,
 }
 
 impl ShrError {
-    fn description(self) -> &'static str {
+    fn description(&self) -> &'static str {
         match self {
             ShrError::NegativeShift => "Shift by negative step is undefined.",
         }
@@ -134,6 +448,8 @@ impl Display for ShrError {
     }
 }
 
+impl Error for ShrError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryFromFloatError {
     Infinity,
@@ -141,7 +457,7 @@ pub enum TryFromFloatError {
 }
 
 impl TryFromFloatError {
-    fn description(self) -> &'static str {
+    fn description(&self) -> &'static str {
         match self {
             TryFromFloatError::Infinity => {
                 "Conversion of infinity is undefined."
@@ -163,6 +479,8 @@ impl Display for TryFromFloatError {
     }
 }
 
+impl Error for TryFromFloatError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryFromStringError {
     BaseOutOfBounds(u32),
@@ -171,7 +489,7 @@ pub enum TryFromStringError {
 }
 
 impl TryFromStringError {
-    fn description(self) -> String {
+    fn description(&self) -> String {
         match self {
             TryFromStringError::BaseOutOfBounds(base) => {
                 format!(
@@ -199,6 +517,8 @@ impl Display for TryFromStringError {
     }
 }
 
+impl Error for TryFromStringError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryIntoFloatError {
     TooLarge,
@@ -226,6 +546,8 @@ impl Display for TryIntoFloatError {
     }
 }
 
+impl Error for TryIntoFloatError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryIntoSignedIntegerError {
     TooLarge,
@@ -253,6 +575,8 @@ impl Display for TryIntoSignedIntegerError {
     }
 }
 
+impl Error for TryIntoSignedIntegerError {}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryIntoUnsignedIntegerError {
     TooLarge,
@@ -283,3 +607,5 @@ impl Display for TryIntoUnsignedIntegerError {
         Display::fmt(&self.description(), formatter)
     }
 }
+
+impl Error for TryIntoUnsignedIntegerError {}
@@ -0,0 +1,164 @@
+use traiter::numbers::{Endianness, FromBytes, Signed, ToBytes};
+
+use super::digits::{
+    trim_leading_zeros, trim_sign_extension_bytes, BinaryBaseFromBinaryDigits,
+};
+use super::types::{BigInt, FromVarintError};
+
+const CONTINUATION_FLAG: u8 = 1u8 << VARINT_GROUP_BITNESS;
+const SIGN_FLAG: u8 = 1u8 << (VARINT_GROUP_BITNESS - 1);
+const VARINT_GROUP_BITNESS: usize = 7;
+const VARINT_GROUP_MASK: u8 = CONTINUATION_FLAG - 1;
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_unsigned_leb128(&self) -> Option<Vec<u8>> {
+        if self.is_negative() {
+            None
+        } else {
+            let mut bytes = self.to_bytes(Endianness::Little);
+            trim_leading_zeros::<u8>(&mut bytes);
+            Some(groups_to_varint(u8::binary_base_from_binary_digits(
+                &bytes,
+                u8::BITS as usize,
+                VARINT_GROUP_BITNESS,
+            )))
+        }
+    }
+
+    pub fn to_signed_leb128(&self) -> Vec<u8> {
+        let bytes = self.to_bytes(Endianness::Little);
+        let mut groups = u8::binary_base_from_binary_digits(
+            &bytes,
+            u8::BITS as usize,
+            VARINT_GROUP_BITNESS,
+        );
+        if self.is_negative() {
+            let most_significant_group_bitness = bytes.len()
+                * (u8::BITS as usize)
+                - (groups.len() - 1) * VARINT_GROUP_BITNESS;
+            let last_index = groups.len() - 1;
+            groups[last_index] |= (VARINT_GROUP_MASK
+                << most_significant_group_bitness)
+                & VARINT_GROUP_MASK;
+            while groups.len() > 1
+                && groups[groups.len() - 1] == VARINT_GROUP_MASK
+                && groups[groups.len() - 2] & SIGN_FLAG != 0
+            {
+                groups.pop();
+            }
+        } else if groups[groups.len() - 1] & SIGN_FLAG != 0 {
+            groups.push(0u8);
+        }
+        groups_to_varint(groups)
+    }
+
+    pub fn to_zigzag_varint(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes(Endianness::Little);
+        let sign_extension = if self.is_negative() { u8::MAX } else { 0u8 };
+        bytes.push(sign_extension);
+        let mut carry = 0u8;
+        for byte in bytes.iter_mut() {
+            let shifted = (*byte << 1) | carry;
+            carry = *byte >> (u8::BITS - 1);
+            *byte = shifted ^ sign_extension;
+        }
+        trim_leading_zeros::<u8>(&mut bytes);
+        groups_to_varint(u8::binary_base_from_binary_digits(
+            &bytes,
+            u8::BITS as usize,
+            VARINT_GROUP_BITNESS,
+        ))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: FromBytes,
+{
+    pub fn from_unsigned_leb128(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromVarintError> {
+        let (groups, size) = varint_to_groups(bytes)?;
+        let mut bytes = u8::binary_base_from_binary_digits(
+            &groups,
+            VARINT_GROUP_BITNESS,
+            u8::BITS as usize,
+        );
+        bytes.push(0u8);
+        trim_sign_extension_bytes(&mut bytes);
+        Ok((Self::from_bytes(&bytes, Endianness::Little), size))
+    }
+
+    pub fn from_signed_leb128(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromVarintError> {
+        let (groups, size) = varint_to_groups(bytes)?;
+        let is_negative = groups[groups.len() - 1] & SIGN_FLAG != 0;
+        let bitness = groups.len() * VARINT_GROUP_BITNESS;
+        let mut bytes = u8::binary_base_from_binary_digits(
+            &groups,
+            VARINT_GROUP_BITNESS,
+            u8::BITS as usize,
+        );
+        bytes.resize(bitness.div_ceil(u8::BITS as usize), 0u8);
+        if is_negative {
+            let most_significant_byte_bitness =
+                bitness - (bytes.len() - 1) * (u8::BITS as usize);
+            let last_index = bytes.len() - 1;
+            bytes[last_index] |= u8::MAX
+                .checked_shl(most_significant_byte_bitness as u32)
+                .unwrap_or(0u8);
+        }
+        trim_sign_extension_bytes(&mut bytes);
+        Ok((Self::from_bytes(&bytes, Endianness::Little), size))
+    }
+
+    pub fn from_zigzag_varint(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromVarintError> {
+        let (groups, size) = varint_to_groups(bytes)?;
+        let mut bytes = u8::binary_base_from_binary_digits(
+            &groups,
+            VARINT_GROUP_BITNESS,
+            u8::BITS as usize,
+        );
+        bytes.push(0u8);
+        let sign_extension = if bytes[0] & 1 != 0 { u8::MAX } else { 0u8 };
+        for index in 0..bytes.len() {
+            let next_byte = bytes.get(index + 1).copied().unwrap_or(0u8);
+            bytes[index] = ((bytes[index] >> 1)
+                | (next_byte << (u8::BITS - 1)))
+                ^ sign_extension;
+        }
+        trim_sign_extension_bytes(&mut bytes);
+        Ok((Self::from_bytes(&bytes, Endianness::Little), size))
+    }
+}
+
+fn groups_to_varint(mut groups: Vec<u8>) -> Vec<u8> {
+    let last_index = groups.len() - 1;
+    for group in &mut groups[..last_index] {
+        *group |= CONTINUATION_FLAG;
+    }
+    groups
+}
+
+fn varint_to_groups(
+    bytes: &[u8],
+) -> Result<(Vec<u8>, usize), FromVarintError> {
+    let size = bytes
+        .iter()
+        .position(|&byte| byte & CONTINUATION_FLAG == 0)
+        .ok_or(FromVarintError::Truncated)?
+        + 1;
+    Ok((
+        bytes[..size]
+            .iter()
+            .map(|&byte| byte & VARINT_GROUP_MASK)
+            .collect(),
+        size,
+    ))
+}
@@ -0,0 +1,63 @@
+use std::convert::TryFrom;
+use std::io::{Error, ErrorKind, Result, Write};
+use std::ops::{Div, DivAssign};
+
+use traiter::numbers::{RemEuclid, Zeroable};
+
+use super::constants::{MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE};
+use super::digits::BaseFromBinaryDigits;
+use super::display::WriteBaseDigits;
+use super::types::{BigInt, BigIntRef};
+
+impl<
+        Digit: Copy
+            + Div<Output = Digit>
+            + DivAssign
+            + BaseFromBinaryDigits<Digit>
+            + RemEuclid<Output = Digit>
+            + TryFrom<usize>,
+        const DIGIT_BITNESS: usize,
+    > BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+    usize: TryFrom<Digit>,
+{
+    pub fn write_radix<W: Write>(&self, writer: W, radix: u32) -> Result<()> {
+        BigIntRef::from(self).write_radix(writer, radix)
+    }
+}
+
+impl<
+        Digit: Copy
+            + Div<Output = Digit>
+            + DivAssign
+            + BaseFromBinaryDigits<Digit>
+            + RemEuclid<Output = Digit>
+            + TryFrom<usize>,
+        const DIGIT_BITNESS: usize,
+    > BigIntRef<'_, Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+    usize: TryFrom<Digit>,
+{
+    pub fn write_radix<W: Write>(
+        &self,
+        mut writer: W,
+        radix: u32,
+    ) -> Result<()> {
+        if radix < u32::from(MIN_REPRESENTABLE_BASE)
+            || radix > u32::from(MAX_REPRESENTABLE_BASE)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Radix should be in range from {MIN_REPRESENTABLE_BASE} \
+                     to {MAX_REPRESENTABLE_BASE}, but found: {radix}."
+                ),
+            ));
+        }
+        self.write_base_digits(radix as usize, |characters| {
+            writer.write_all(characters)
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use traiter::numbers::Zeroable;
 
-use super::types::BigInt;
+use super::types::{BigInt, BigIntRef};
 
 impl<Digit, const DIGIT_BITNESS: usize> Zeroable
     for &BigInt<Digit, DIGIT_BITNESS>
@@ -17,3 +17,11 @@ impl<Digit, const DIGIT_BITNESS: usize> Zeroable
         self.sign.is_zero()
     }
 }
+
+impl<Digit, const DIGIT_BITNESS: usize> Zeroable
+    for BigIntRef<'_, Digit, DIGIT_BITNESS>
+{
+    fn is_zero(self) -> bool {
+        self.sign.is_zero()
+    }
+}
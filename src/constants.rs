@@ -1,2 +1,8 @@
 pub(crate) const UNDEFINED_DIVISION_ERROR_MESSAGE: &str =
     "Division by zero is undefined.";
+#[cfg(target_pointer_width = "32")]
+pub(crate) const HASH_BITS: usize = 31;
+#[cfg(target_pointer_width = "64")]
+pub(crate) const HASH_BITS: usize = 61;
+pub(crate) const HASH_INF: isize = 314_159isize;
+pub(crate) const HASH_MODULUS: usize = (1usize << HASH_BITS) - 1usize;
@@ -0,0 +1,177 @@
+use std::error;
+use std::fmt::{Debug, Display, Formatter};
+use std::num::ParseIntError;
+
+#[cfg(feature = "cbor")]
+use crate::big_int::FromCborError;
+use crate::big_int::{
+    CheckedDivAsFloatError, CheckedPowRemEuclidError, FromAsn1IntegerError,
+    FromOrderPreservingBytesError, FromPgNumericError, FromSignAndDigitsError,
+    FromVarintError, ShlError, ShrError, TryFromFloatError,
+    TryFromStringError, TryIntoFloatError, TryIntoSignedIntegerError,
+    TryIntoUnsignedIntegerError,
+};
+#[cfg(feature = "cbor")]
+use crate::fraction::FromCborError as FractionFromCborError;
+use crate::fraction::{
+    FromFloatConstructionError, FromVarintPairError,
+    TryFromStringError as FractionTryFromStringError,
+};
+
+#[derive(Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    CheckedDivAsFloat(CheckedDivAsFloatError),
+    CheckedPowRemEuclid(CheckedPowRemEuclidError),
+    #[cfg(feature = "cbor")]
+    FractionFromCbor(FractionFromCborError),
+    FromAsn1Integer(FromAsn1IntegerError),
+    #[cfg(feature = "cbor")]
+    FromCbor(FromCborError),
+    FromFloatConstruction(FromFloatConstructionError),
+    FromOrderPreservingBytes(FromOrderPreservingBytesError),
+    FromPgNumeric(FromPgNumericError),
+    FromSignAndDigits(FromSignAndDigitsError),
+    FromVarint(FromVarintError),
+    ParseInt(ParseIntError),
+    Shl(ShlError),
+    Shr(ShrError),
+    TryFromFloat(TryFromFloatError),
+    TryFromString(TryFromStringError),
+    TryIntoFloat(TryIntoFloatError),
+    TryIntoSignedInteger(TryIntoSignedIntegerError),
+    TryIntoUnsignedInteger(TryIntoUnsignedIntegerError),
+    ZeroDenominator,
+}
+
+impl Debug for Error {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, formatter)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::CheckedDivAsFloat(error) => Display::fmt(error, formatter),
+            Error::CheckedPowRemEuclid(error) => {
+                Display::fmt(error, formatter)
+            }
+            #[cfg(feature = "cbor")]
+            Error::FractionFromCbor(error) => Display::fmt(error, formatter),
+            Error::FromAsn1Integer(error) => Display::fmt(error, formatter),
+            #[cfg(feature = "cbor")]
+            Error::FromCbor(error) => Display::fmt(error, formatter),
+            Error::FromFloatConstruction(error) => {
+                Display::fmt(error, formatter)
+            }
+            Error::FromOrderPreservingBytes(error) => {
+                Display::fmt(error, formatter)
+            }
+            Error::FromPgNumeric(error) => Display::fmt(error, formatter),
+            Error::FromSignAndDigits(error) => Display::fmt(error, formatter),
+            Error::FromVarint(error) => Display::fmt(error, formatter),
+            Error::ParseInt(error) => Display::fmt(error, formatter),
+            Error::Shl(error) => Display::fmt(error, formatter),
+            Error::Shr(error) => Display::fmt(error, formatter),
+            Error::TryFromFloat(error) => Display::fmt(error, formatter),
+            Error::TryFromString(error) => Display::fmt(error, formatter),
+            Error::TryIntoFloat(error) => Display::fmt(error, formatter),
+            Error::TryIntoSignedInteger(error) => {
+                Display::fmt(error, formatter)
+            }
+            Error::TryIntoUnsignedInteger(error) => {
+                Display::fmt(error, formatter)
+            }
+            Error::ZeroDenominator => {
+                formatter.write_str("Denominator should not be zero.")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::CheckedDivAsFloat(error) => Some(error),
+            Error::CheckedPowRemEuclid(error) => Some(error),
+            #[cfg(feature = "cbor")]
+            Error::FractionFromCbor(error) => Some(error),
+            Error::FromAsn1Integer(error) => Some(error),
+            #[cfg(feature = "cbor")]
+            Error::FromCbor(error) => Some(error),
+            Error::FromFloatConstruction(error) => Some(error),
+            Error::FromOrderPreservingBytes(error) => Some(error),
+            Error::FromPgNumeric(error) => Some(error),
+            Error::FromSignAndDigits(error) => Some(error),
+            Error::FromVarint(error) => Some(error),
+            Error::ParseInt(error) => Some(error),
+            Error::Shl(error) => Some(error),
+            Error::Shr(error) => Some(error),
+            Error::TryFromFloat(error) => Some(error),
+            Error::TryFromString(error) => Some(error),
+            Error::TryIntoFloat(error) => Some(error),
+            Error::TryIntoSignedInteger(error) => Some(error),
+            Error::TryIntoUnsignedInteger(error) => Some(error),
+            Error::ZeroDenominator => None,
+        }
+    }
+}
+
+macro_rules! error_from_impl {
+    ($($variant:ident($error:ty))*) => ($(
+        impl From<$error> for Error {
+            #[inline]
+            fn from(error: $error) -> Self {
+                Error::$variant(error)
+            }
+        }
+    )*)
+}
+
+error_from_impl!(
+    CheckedDivAsFloat(CheckedDivAsFloatError)
+    CheckedPowRemEuclid(CheckedPowRemEuclidError)
+    FromAsn1Integer(FromAsn1IntegerError)
+    FromFloatConstruction(FromFloatConstructionError)
+    FromOrderPreservingBytes(FromOrderPreservingBytesError)
+    FromPgNumeric(FromPgNumericError)
+    FromSignAndDigits(FromSignAndDigitsError)
+    FromVarint(FromVarintError)
+    ParseInt(ParseIntError)
+    Shl(ShlError)
+    Shr(ShrError)
+    TryFromFloat(TryFromFloatError)
+    TryFromString(TryFromStringError)
+    TryIntoFloat(TryIntoFloatError)
+    TryIntoSignedInteger(TryIntoSignedIntegerError)
+    TryIntoUnsignedInteger(TryIntoUnsignedIntegerError)
+);
+
+#[cfg(feature = "cbor")]
+error_from_impl!(
+    FractionFromCbor(FractionFromCborError)
+    FromCbor(FromCborError)
+);
+
+impl<ComponentError: Into<Error>>
+    From<FractionTryFromStringError<ComponentError>> for Error
+{
+    fn from(error: FractionTryFromStringError<ComponentError>) -> Self {
+        match error {
+            FractionTryFromStringError::Component(error) => error.into(),
+            FractionTryFromStringError::ZeroDenominator => {
+                Error::ZeroDenominator
+            }
+        }
+    }
+}
+
+impl From<FromVarintPairError> for Error {
+    fn from(error: FromVarintPairError) -> Self {
+        match error {
+            FromVarintPairError::Component(error) => error.into(),
+            FromVarintPairError::ZeroDenominator => Error::ZeroDenominator,
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use traiter::numbers::{BitLength, One, Zero};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, FractionAccumulator, NormalizeModuli};
+
+const DEFAULT_NORMALIZATION_BITNESS: usize = 1024;
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    pub fn with_normalization_bitness(
+        value: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+        normalization_bitness: usize,
+    ) -> Self {
+        Self {
+            numerator: value.numerator,
+            denominator: value.denominator,
+            normalization_bitness,
+        }
+    }
+
+    pub fn normalize(&mut self) {
+        (self.numerator, self.denominator) =
+            (&self.numerator).normalize_moduli(&self.denominator);
+        self.normalization_bitness =
+            self.normalization_bitness.max(2 * self.bitness());
+    }
+
+    pub fn to_fraction(&self) -> Fraction<BigInt<Digit, DIGIT_BITNESS>> {
+        let (numerator, denominator) =
+            (&self.numerator).normalize_moduli(&self.denominator);
+        Fraction {
+            numerator,
+            denominator,
+        }
+    }
+
+    fn bitness(&self) -> usize {
+        unsafe {
+            usize::try_from((&self.numerator).bit_length()).unwrap_unchecked()
+        }
+        .max(unsafe {
+            usize::try_from((&self.denominator).bit_length())
+                .unwrap_unchecked()
+        })
+    }
+
+    fn normalize_if_oversized(&mut self) {
+        if self.bitness() > self.normalization_bitness {
+            self.normalize();
+        }
+    }
+}
+
+impl<Component> From<Fraction<Component>> for FractionAccumulator<Component> {
+    fn from(value: Fraction<Component>) -> Self {
+        Self {
+            numerator: value.numerator,
+            denominator: value.denominator,
+            normalization_bitness: DEFAULT_NORMALIZATION_BITNESS,
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    From<FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
+        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
+    >,
+{
+    fn from(value: FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>) -> Self {
+        let (numerator, denominator) =
+            value.numerator.normalize_moduli(value.denominator);
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl<Component: One + Zero> Zero for FractionAccumulator<Component> {
+    fn zero() -> Self {
+        Self {
+            numerator: Component::zero(),
+            denominator: Component::one(),
+            normalization_bitness: DEFAULT_NORMALIZATION_BITNESS,
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    AddAssign<&Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+    BigInt<Digit, DIGIT_BITNESS>:
+        Add<Output = BigInt<Digit, DIGIT_BITNESS>> + PartialEq,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn add_assign(&mut self, other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>) {
+        if self.denominator == other.denominator {
+            self.numerator = &self.numerator + &other.numerator;
+        } else {
+            self.numerator = &self.numerator * &other.denominator
+                + &self.denominator * &other.numerator;
+            self.denominator = &self.denominator * &other.denominator;
+        }
+        self.normalize_if_oversized();
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    AddAssign<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: AddAssign<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    fn add_assign(&mut self, other: Fraction<BigInt<Digit, DIGIT_BITNESS>>) {
+        *self += &other;
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    AddAssign<&BigInt<Digit, DIGIT_BITNESS>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        > + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn add_assign(&mut self, other: &BigInt<Digit, DIGIT_BITNESS>) {
+        self.numerator = &self.numerator + &self.denominator * other;
+        self.normalize_if_oversized();
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> AddAssign<BigInt<Digit, DIGIT_BITNESS>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: AddAssign<&'a BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn add_assign(&mut self, other: BigInt<Digit, DIGIT_BITNESS>) {
+        *self += &other;
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    SubAssign<&Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>:
+        PartialEq + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn sub_assign(&mut self, other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>) {
+        if self.denominator == other.denominator {
+            self.numerator = &self.numerator - &other.numerator;
+        } else {
+            self.numerator = &self.numerator * &other.denominator
+                - &self.denominator * &other.numerator;
+            self.denominator = &self.denominator * &other.denominator;
+        }
+        self.normalize_if_oversized();
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    SubAssign<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: SubAssign<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    fn sub_assign(&mut self, other: Fraction<BigInt<Digit, DIGIT_BITNESS>>) {
+        *self -= &other;
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    SubAssign<&BigInt<Digit, DIGIT_BITNESS>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + Sub<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn sub_assign(&mut self, other: &BigInt<Digit, DIGIT_BITNESS>) {
+        self.numerator = &self.numerator - &self.denominator * other;
+        self.normalize_if_oversized();
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> SubAssign<BigInt<Digit, DIGIT_BITNESS>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: SubAssign<&'a BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn sub_assign(&mut self, other: BigInt<Digit, DIGIT_BITNESS>) {
+        *self -= &other;
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    MulAssign<&Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn mul_assign(&mut self, other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>) {
+        self.numerator = &self.numerator * &other.numerator;
+        self.denominator = &self.denominator * &other.denominator;
+        self.normalize_if_oversized();
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    MulAssign<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: MulAssign<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    fn mul_assign(&mut self, other: Fraction<BigInt<Digit, DIGIT_BITNESS>>) {
+        *self *= &other;
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    MulAssign<&BigInt<Digit, DIGIT_BITNESS>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn mul_assign(&mut self, other: &BigInt<Digit, DIGIT_BITNESS>) {
+        self.numerator = &self.numerator * other;
+        self.normalize_if_oversized();
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> MulAssign<BigInt<Digit, DIGIT_BITNESS>>
+    for FractionAccumulator<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: MulAssign<&'a BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn mul_assign(&mut self, other: BigInt<Digit, DIGIT_BITNESS>) {
+        *self *= &other;
+    }
+}
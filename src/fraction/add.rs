@@ -1,4 +1,6 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Mul};
+
+use traiter::numbers::Gcd;
 
 use crate::big_int::BigInt;
 
@@ -7,16 +9,12 @@ use super::types::{Fraction, NormalizeModuli};
 impl<Digit, const DIGIT_BITNESS: usize> Add
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<
-        BigInt<Digit, DIGIT_BITNESS>,
-        Output = BigInt<Digit, DIGIT_BITNESS>,
-    >,
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
         + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            &'a BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        > + NormalizeModuli<
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
@@ -26,12 +24,15 @@ where
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
-        let (numerator, denominator) = (self.numerator * &other.denominator
-            + &self.denominator * other.numerator)
-            .normalize_moduli(self.denominator * other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            + &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -39,29 +40,30 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Add<&Self>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
         + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            &'a BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        > + NormalizeModuli<
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
         >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
-        Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Self;
 
     fn add(self, other: &Self) -> Self::Output {
-        let (numerator, denominator) = (self.numerator * &other.denominator
-            + &self.denominator * &other.numerator)
-            .normalize_moduli(self.denominator * &other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            + &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -70,12 +72,11 @@ impl<Digit, const DIGIT_BITNESS: usize>
     Add<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        >,
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
     BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
         + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
@@ -89,12 +90,15 @@ where
         self,
         other: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
     ) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator * &other.denominator
-            + &self.denominator * other.numerator)
-            .normalize_moduli(&self.denominator * other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            + &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -102,25 +106,30 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Add
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
     BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
         + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
         >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
-        Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
     fn add(self, other: Self) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator * &other.denominator
-            + &self.denominator * &other.numerator)
-            .normalize_moduli(&self.denominator * &other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            + &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -132,23 +141,14 @@ where
         BigInt<Digit, DIGIT_BITNESS>,
         Output = BigInt<Digit, DIGIT_BITNESS>,
     >,
-    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + NormalizeModuli<
-            Output = (
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            ),
-        >,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Self;
 
     fn add(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (self.numerator
-            + &self.denominator * other)
-            .normalize_moduli(self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self.numerator + &self.denominator * other,
+            denominator: self.denominator,
         }
     }
 }
@@ -156,25 +156,16 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Add<&BigInt<Digit, DIGIT_BITNESS>>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + NormalizeModuli<
-            Output = (
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            ),
-        >,
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
         Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Self;
 
     fn add(self, other: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (self.numerator
-            + &self.denominator * other)
-            .normalize_moduli(self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self.numerator + &self.denominator * other,
+            denominator: self.denominator,
         }
     }
 }
@@ -182,10 +173,6 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Add<BigInt<Digit, DIGIT_BITNESS>>
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
@@ -193,16 +180,14 @@ where
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
         >,
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
     fn add(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator
-            + &self.denominator * other)
-            .normalize_moduli(&self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: &self.numerator + &self.denominator * other,
+            denominator: self.denominator.clone(),
         }
     }
 }
@@ -210,24 +195,18 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Add<&BigInt<Digit, DIGIT_BITNESS>>
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
         > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
     fn add(self, other: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator
-            + &self.denominator * other)
-            .normalize_moduli(&self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: &self.numerator + &self.denominator * other,
+            denominator: self.denominator.clone(),
         }
     }
 }
@@ -302,13 +281,15 @@ macro_rules! integer_fraction_add_impl {
             type Output = Self;
 
             fn add(self, other: Self) -> Self::Output {
-                let (numerator, denominator) = (self.numerator
-                    * other.denominator
-                    + self.denominator * other.numerator)
-                    .normalize_moduli(self.denominator * other.denominator);
+                let gcd = self.denominator.gcd(other.denominator);
+                let (denominator, other_denominator) =
+                    (self.denominator / gcd, other.denominator / gcd);
+                let (numerator, gcd) = (self.numerator * other_denominator
+                    + other.numerator * denominator)
+                    .normalize_moduli(gcd);
                 Self::Output {
                     numerator,
-                    denominator,
+                    denominator: denominator * other_denominator * gcd,
                 }
             }
         }
@@ -317,12 +298,9 @@ macro_rules! integer_fraction_add_impl {
             type Output = Self;
 
             fn add(self, other: $integer) -> Self::Output {
-                let (numerator, denominator) = (self.numerator
-                    + self.denominator * other)
-                    .normalize_moduli(self.denominator);
                 Self::Output {
-                    numerator,
-                    denominator,
+                    numerator: self.numerator + self.denominator * other,
+                    denominator: self.denominator,
                 }
             }
         }
@@ -1,4 +1,6 @@
-use std::ops::{Add, AddAssign, Mul};
+use std::ops::{Add, AddAssign, Div, Mul};
+
+use traiter::numbers::Gcd;
 
 use crate::big_int::BigInt;
 
@@ -7,55 +9,60 @@ use super::types::{Fraction, NormalizeModuli};
 impl<Digit, const DIGIT_BITNESS: usize> AddAssign
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
         + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
         >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        >,
 {
     fn add_assign(&mut self, other: Self) {
-        (self.numerator, self.denominator) = (&self.numerator
-            * &other.denominator
-            + &self.denominator * other.numerator)
-            .normalize_moduli(&self.denominator * other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            + &other.numerator * &denominator)
+            .normalize_moduli(gcd);
+        (self.numerator, self.denominator) =
+            (numerator, denominator * other_denominator * gcd);
     }
 }
 
 impl<Digit, const DIGIT_BITNESS: usize> AddAssign<&Self>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
         + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
         >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
-        Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     fn add_assign(&mut self, other: &Self) {
-        (self.numerator, self.denominator) = (&self.numerator
-            * &other.denominator
-            + &self.denominator * &other.numerator)
-            .normalize_moduli(&self.denominator * &other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            + &other.numerator * &denominator)
+            .normalize_moduli(gcd);
+        (self.numerator, self.denominator) =
+            (numerator, denominator * other_denominator * gcd);
     }
 }
 
 impl<Digit, const DIGIT_BITNESS: usize> AddAssign<BigInt<Digit, DIGIT_BITNESS>>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
@@ -65,9 +72,7 @@ where
         >,
 {
     fn add_assign(&mut self, other: BigInt<Digit, DIGIT_BITNESS>) {
-        (self.numerator, self.denominator) = (&self.numerator
-            + &self.denominator * other)
-            .normalize_moduli(&self.denominator);
+        self.numerator = &self.numerator + &self.denominator * other;
     }
 }
 
@@ -75,19 +80,13 @@ impl<Digit, const DIGIT_BITNESS: usize>
     AddAssign<&BigInt<Digit, DIGIT_BITNESS>>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
         > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     fn add_assign(&mut self, other: &BigInt<Digit, DIGIT_BITNESS>) {
-        (self.numerator, self.denominator) = (&self.numerator
-            + &self.denominator * other)
-            .normalize_moduli(&self.denominator);
+        self.numerator = &self.numerator + &self.denominator * other;
     }
 }
 
@@ -95,18 +94,20 @@ macro_rules! integer_fraction_add_assign_impl {
     ($($integer:ty)*) => ($(
         impl AddAssign for Fraction<$integer> {
             fn add_assign(&mut self, other: Self) {
-                (self.numerator, self.denominator) = (self.numerator
-                    * other.denominator
-                    + self.denominator * other.numerator)
-                    .normalize_moduli(self.denominator * other.denominator);
+                let gcd = self.denominator.gcd(other.denominator);
+                let (denominator, other_denominator) =
+                    (self.denominator / gcd, other.denominator / gcd);
+                let (numerator, gcd) = (self.numerator * other_denominator
+                    + other.numerator * denominator)
+                    .normalize_moduli(gcd);
+                (self.numerator, self.denominator) =
+                    (numerator, denominator * other_denominator * gcd);
             }
         }
 
         impl AddAssign<$integer> for Fraction<$integer> {
             fn add_assign(&mut self, other: $integer) {
-                (self.numerator, self.denominator) = (self.numerator
-                    + self.denominator * other)
-                    .normalize_moduli(self.denominator);
+                self.numerator = self.numerator + self.denominator * other;
             }
         }
     )*)
@@ -0,0 +1,513 @@
+use std::ops::{Add, Div, Mul, Neg, Shl, Shr, Sub};
+
+use traiter::numbers::{
+    Abs, BitLength, DivEuclid, DivRemEuclid, One, Signed, Zero, Zeroable,
+};
+
+use crate::big_int::BigInt;
+use crate::traits::CheckedRoot;
+
+use super::types::{Fraction, NormalizeModuli, NormalizeSign};
+
+impl<Digit: Clone, const DIGIT_BITNESS: usize>
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Abs<Output = Self>
+        + Add<&'a Self, Output = Self>
+        + CheckedRoot<usize, Output = Option<Self>>
+        + Div<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Self>
+        + Signed
+        + Sub<&'a Self, Output = Self>,
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zeroable,
+    for<'a> BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Add<
+            &'a BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        > + DivEuclid<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + DivRemEuclid<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + From<usize>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + NormalizeSign<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Ord
+        + Shl<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Shr<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zero,
+    for<'a> Self: Add<Output = Self>
+        + Add<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Self>
+        + Mul<Output = Self>
+        + Mul<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Self>
+        + Sub<Output = Self>,
+{
+    pub fn approximate_e(tolerance: &Self) -> Option<Self> {
+        Self::from(BigInt::one()).approximate_exp(tolerance)
+    }
+
+    pub fn approximate_pi(tolerance: &Self) -> Option<Self> {
+        if !tolerance.is_positive() {
+            return None;
+        }
+        let half_tolerance = tolerance / &BigInt::from(2usize);
+        let quarter_tolerance = &half_tolerance / &BigInt::from(2usize);
+        let pi = Self::arctangent(
+            BigInt::one(),
+            BigInt::from(5usize),
+            &(&quarter_tolerance / &BigInt::from(16usize)),
+        ) * &BigInt::from(16usize)
+            - Self::arctangent(
+                BigInt::one(),
+                BigInt::from(239usize),
+                &(&quarter_tolerance / &BigInt::from(4usize)),
+            ) * &BigInt::from(4usize);
+        Some(pi.simplest_around(&half_tolerance))
+    }
+
+    pub fn approximate_cos(&self, tolerance: &Self) -> Option<Self> {
+        if !tolerance.is_positive() {
+            return None;
+        }
+        let half_tolerance = tolerance / &BigInt::from(2usize);
+        let quarter_tolerance = &half_tolerance / &BigInt::from(2usize);
+        let angle = self.reduce_angle(&quarter_tolerance)?;
+        let squared_numerator = &angle.numerator * &angle.numerator;
+        let squared_denominator = &angle.denominator * &angle.denominator;
+        let cosine = Self::sum_series(
+            |index| {
+                (
+                    -squared_numerator.clone(),
+                    squared_denominator.clone()
+                        * BigInt::from((2 * index - 1) * (2 * index)),
+                )
+            },
+            &quarter_tolerance,
+        ) + &BigInt::one();
+        Some(cosine.simplest_around(&half_tolerance))
+    }
+
+    pub fn approximate_exp(&self, tolerance: &Self) -> Option<Self> {
+        if !tolerance.is_positive() {
+            return None;
+        }
+        if self.is_negative() && self.is_exp_within(tolerance) {
+            return Some(Self::from(BigInt::zero()));
+        }
+        let half_tolerance = tolerance / &BigInt::from(2usize);
+        let two = BigInt::from(2usize);
+        // exp(x) = exp(x / 2 ** k) ** (2 ** k) with |x / 2 ** k| < 1 / 2,
+        // squarings are done on values truncated to `precision` bits
+        // which makes relative error grow at most 3 times per squaring
+        let halvings_count = ((&self.numerator).bit_length() + two.clone()
+            - (&self.denominator).bit_length())
+        .max(BigInt::zero());
+        // exp(x) < 2 ** result_exponent since log2(e) < 13 / 9
+        let result_exponent = if self.is_positive() {
+            (self.numerator.clone() * BigInt::from(13usize))
+                .div_euclid(self.denominator.clone() * BigInt::from(9usize))
+                + BigInt::one()
+        } else {
+            BigInt::zero()
+        };
+        let tolerance_exponent = (&half_tolerance.numerator).bit_length()
+            - (&half_tolerance.denominator).bit_length()
+            - BigInt::one();
+        let guard_bits_count =
+            halvings_count.clone() * two.clone() + BigInt::from(4usize);
+        let precision = (result_exponent + guard_bits_count.clone()
+            - two
+            - tolerance_exponent)
+            .max(guard_bits_count);
+        let scaled_denominator =
+            self.denominator.clone() << halvings_count.clone();
+        let ratio = |index| {
+            (
+                self.numerator.clone(),
+                scaled_denominator.clone() * BigInt::from(index),
+            )
+        };
+        let terms_count = Self::series_terms_count(
+            &ratio,
+            &Self {
+                numerator: BigInt::one(),
+                denominator: BigInt::one()
+                    << (precision.clone() + BigInt::one()),
+            },
+        );
+        let (_, denominator, numerator) = if terms_count == 0 {
+            (BigInt::one(), BigInt::one(), BigInt::zero())
+        } else {
+            Self::split_series(&ratio, 1, terms_count + 1)
+        };
+        let mut scale_exponent = precision.clone() + BigInt::one();
+        let mut mantissa = ((numerator + denominator.clone())
+            << scale_exponent.clone())
+        .div_euclid(denominator);
+        let mut squarings_count = halvings_count;
+        while !(&squarings_count).is_zero() {
+            mantissa = &mantissa * &mantissa;
+            scale_exponent = scale_exponent.clone() + scale_exponent;
+            let excess =
+                (&mantissa).bit_length() - precision.clone() - BigInt::one();
+            if (&excess).is_positive() {
+                mantissa = mantissa >> excess.clone();
+                scale_exponent = scale_exponent - excess;
+            }
+            squarings_count = squarings_count - BigInt::one();
+        }
+        Some(Self::simplest_around_dyadic(
+            mantissa,
+            scale_exponent,
+            &half_tolerance,
+        ))
+    }
+
+    pub fn approximate_ln(&self, tolerance: &Self) -> Option<Self> {
+        if !tolerance.is_positive() || !self.is_positive() {
+            return None;
+        }
+        let half_tolerance = tolerance / &BigInt::from(2usize);
+        let quarter_tolerance = &half_tolerance / &BigInt::from(2usize);
+        let binary_exponent =
+            (&self.numerator).bit_length() - (&self.denominator).bit_length();
+        let (numerator, denominator) = if (&binary_exponent).is_negative() {
+            (
+                self.numerator.clone() << -binary_exponent.clone(),
+                self.denominator.clone(),
+            )
+        } else {
+            (
+                self.numerator.clone(),
+                self.denominator.clone() << binary_exponent.clone(),
+            )
+        };
+        let mut logarithm = Self::hyperbolic_arctangent(
+            numerator.clone() - denominator.clone(),
+            numerator + denominator,
+            &(&quarter_tolerance / &BigInt::from(2usize)),
+        ) * &BigInt::from(2usize);
+        if !(&binary_exponent).is_zero() {
+            logarithm = logarithm
+                + Self::hyperbolic_arctangent(
+                    BigInt::one(),
+                    BigInt::from(3usize),
+                    &(&quarter_tolerance
+                        / &(binary_exponent.clone() * BigInt::from(2usize))
+                            .abs()),
+                ) * &(binary_exponent * BigInt::from(2usize));
+        }
+        Some(logarithm.simplest_around(&half_tolerance))
+    }
+
+    pub fn approximate_sin(&self, tolerance: &Self) -> Option<Self> {
+        if !tolerance.is_positive() {
+            return None;
+        }
+        let half_tolerance = tolerance / &BigInt::from(2usize);
+        let quarter_tolerance = &half_tolerance / &BigInt::from(2usize);
+        let angle = self.reduce_angle(&quarter_tolerance)?;
+        let squared_numerator = &angle.numerator * &angle.numerator;
+        let squared_denominator = &angle.denominator * &angle.denominator;
+        let sine = (Self::sum_series(
+            |index| {
+                (
+                    -squared_numerator.clone(),
+                    squared_denominator.clone()
+                        * BigInt::from((2 * index) * (2 * index + 1)),
+                )
+            },
+            &(&quarter_tolerance
+                / &((&angle.numerator).abs() + angle.denominator.clone())
+                * &angle.denominator),
+        ) + &BigInt::one())
+            * angle;
+        Some(sine.simplest_around(&half_tolerance))
+    }
+
+    pub fn approximate_sqrt(&self, tolerance: &Self) -> Option<Self> {
+        if !tolerance.is_positive() || self.is_negative() {
+            return None;
+        }
+        if let Some(root) = self.checked_root(2usize) {
+            return Some(root);
+        }
+        let scale = tolerance
+            .denominator
+            .clone()
+            .div_euclid(tolerance.numerator.clone())
+            + BigInt::one();
+        let root = Self::floor_sqrt(
+            (&self.numerator * &(&scale * &scale))
+                .div_euclid(self.denominator.clone()),
+        );
+        let lower_bound = Self {
+            numerator: root.clone(),
+            denominator: scale.clone(),
+        }
+        .normalized();
+        let upper_bound = Self {
+            numerator: root + BigInt::one(),
+            denominator: scale,
+        }
+        .normalized();
+        lower_bound.simplest_between(&upper_bound)
+    }
+
+    fn arctangent(
+        numerator: BigInt<Digit, DIGIT_BITNESS>,
+        denominator: BigInt<Digit, DIGIT_BITNESS>,
+        tolerance: &Self,
+    ) -> Self {
+        let squared_numerator = &numerator * &numerator;
+        let squared_denominator = &denominator * &denominator;
+        (Self::sum_series(
+            |index| {
+                (
+                    -(squared_numerator.clone() * BigInt::from(2 * index - 1)),
+                    squared_denominator.clone() * BigInt::from(2 * index + 1),
+                )
+            },
+            tolerance,
+        ) + &BigInt::one())
+            * Self {
+                numerator,
+                denominator,
+            }
+            .normalized()
+    }
+
+    fn hyperbolic_arctangent(
+        numerator: BigInt<Digit, DIGIT_BITNESS>,
+        denominator: BigInt<Digit, DIGIT_BITNESS>,
+        tolerance: &Self,
+    ) -> Self {
+        let squared_numerator = &numerator * &numerator;
+        let squared_denominator = &denominator * &denominator;
+        (Self::sum_series(
+            |index| {
+                (
+                    squared_numerator.clone() * BigInt::from(2 * index - 1),
+                    squared_denominator.clone() * BigInt::from(2 * index + 1),
+                )
+            },
+            tolerance,
+        ) + &BigInt::one())
+            * Self {
+                numerator,
+                denominator,
+            }
+            .normalized()
+    }
+
+    fn floor_sqrt(
+        value: BigInt<Digit, DIGIT_BITNESS>,
+    ) -> BigInt<Digit, DIGIT_BITNESS> {
+        if (&value).is_zero() {
+            return value;
+        }
+        let two = BigInt::from(2usize);
+        let mut candidate = BigInt::one()
+            << ((&value).bit_length() + BigInt::one()).div_euclid(two.clone());
+        loop {
+            let next_candidate = (candidate.clone()
+                + value.clone().div_euclid(candidate.clone()))
+            .div_euclid(two.clone());
+            if next_candidate >= candidate {
+                break candidate;
+            }
+            candidate = next_candidate;
+        }
+    }
+
+    /// Checks if `exp(-|x|)` is within `tolerance`
+    /// using its upper bound `2 ** -floor(|x| * 9 / 13)`
+    /// without building the power itself.
+    fn is_exp_within(&self, tolerance: &Self) -> bool {
+        let exponent = ((&self.numerator).abs() * BigInt::from(9usize))
+            .div_euclid(self.denominator.clone() * BigInt::from(13usize));
+        // tolerance >= 2 ** (bit_length(numerator) - bit_length(denominator) - 1)
+        if exponent
+            > (&tolerance.denominator).bit_length()
+                - (&tolerance.numerator).bit_length()
+        {
+            true
+        } else {
+            tolerance.numerator.clone() << exponent
+                >= tolerance.denominator.clone()
+        }
+    }
+
+    fn normalized(self) -> Self {
+        let (numerator, denominator) =
+            self.numerator.normalize_moduli(self.denominator);
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    fn reduce_angle(&self, tolerance: &Self) -> Option<Self> {
+        let modulus = (&self.numerator).abs();
+        if modulus <= self.denominator.clone() * BigInt::from(3usize) {
+            return Some(self.clone());
+        }
+        let pi = Self::approximate_pi(
+            &(tolerance
+                / &((modulus + &self.denominator) * BigInt::from(2usize))
+                * &self.denominator),
+        )?;
+        let turns = (self.numerator.clone() * pi.denominator.clone()
+            + self.denominator.clone() * pi.numerator.clone())
+        .div_euclid(
+            self.denominator.clone()
+                * pi.numerator.clone()
+                * BigInt::from(2usize),
+        );
+        Some(self.clone() - pi * &(turns * BigInt::from(2usize)))
+    }
+
+    fn simplest_around(&self, radius: &Self) -> Self {
+        (self - radius)
+            .simplest_between(&(self + radius))
+            .unwrap_or_else(|| self.clone())
+    }
+
+    /// Finds the simplest fraction within `radius`
+    /// from `mantissa / 2 ** scale_exponent`
+    /// without normalizing the power of two denominator.
+    fn simplest_around_dyadic(
+        mantissa: BigInt<Digit, DIGIT_BITNESS>,
+        scale_exponent: BigInt<Digit, DIGIT_BITNESS>,
+        radius: &Self,
+    ) -> Self {
+        if !(&scale_exponent).is_positive() {
+            return Self::from(mantissa << -scale_exponent)
+                .simplest_around(radius);
+        }
+        let center_numerator = mantissa * radius.denominator.clone();
+        let scaled_radius_numerator =
+            radius.numerator.clone() << scale_exponent.clone();
+        let denominator = radius.denominator.clone() << scale_exponent;
+        let lower_bound = Self {
+            numerator: center_numerator.clone()
+                - scaled_radius_numerator.clone(),
+            denominator: denominator.clone(),
+        };
+        let upper_bound = Self {
+            numerator: center_numerator + scaled_radius_numerator,
+            denominator,
+        };
+        lower_bound
+            .simplest_between(&upper_bound)
+            .unwrap_or(lower_bound)
+    }
+
+    fn split_series(
+        ratio: &impl Fn(
+            usize,
+        ) -> (
+            BigInt<Digit, DIGIT_BITNESS>,
+            BigInt<Digit, DIGIT_BITNESS>,
+        ),
+        start: usize,
+        stop: usize,
+    ) -> (
+        BigInt<Digit, DIGIT_BITNESS>,
+        BigInt<Digit, DIGIT_BITNESS>,
+        BigInt<Digit, DIGIT_BITNESS>,
+    ) {
+        if stop - start == 1 {
+            let (numerator, denominator) = ratio(start);
+            (numerator.clone(), denominator, numerator)
+        } else {
+            let middle = start + (stop - start) / 2;
+            let (left_product, left_denominator, left_sum) =
+                Self::split_series(ratio, start, middle);
+            let (right_product, right_denominator, right_sum) =
+                Self::split_series(ratio, middle, stop);
+            (
+                &left_product * &right_product,
+                &left_denominator * &right_denominator,
+                left_sum * right_denominator + left_product * right_sum,
+            )
+        }
+    }
+
+    /// Sums `r(1) + r(1) * r(2) + r(1) * r(2) * r(3) + ...`
+    /// for ratios `r` with moduli which do not increase after dropping
+    /// to a half.
+    fn sum_series(
+        ratio: impl Fn(
+            usize,
+        ) -> (
+            BigInt<Digit, DIGIT_BITNESS>,
+            BigInt<Digit, DIGIT_BITNESS>,
+        ),
+        tolerance: &Self,
+    ) -> Self {
+        let terms_count = Self::series_terms_count(&ratio, tolerance);
+        if terms_count == 0 {
+            Self::from(BigInt::zero())
+        } else {
+            let (_, denominator, numerator) =
+                Self::split_series(&ratio, 1, terms_count + 1);
+            let (numerator, denominator) =
+                numerator.normalize_sign(denominator);
+            Self {
+                numerator,
+                denominator,
+            }
+            .normalized()
+        }
+    }
+
+    /// Estimates the number of series terms to sum from bit lengths
+    /// of ratios components only,
+    /// so that the tail is within `tolerance`
+    /// since its first term is within a half of it
+    /// and each following one is at most a half of the previous.
+    fn series_terms_count(
+        ratio: &impl Fn(
+            usize,
+        ) -> (
+            BigInt<Digit, DIGIT_BITNESS>,
+            BigInt<Digit, DIGIT_BITNESS>,
+        ),
+        tolerance: &Self,
+    ) -> usize {
+        let tolerance_exponent = (&tolerance.numerator).bit_length()
+            - (&tolerance.denominator).bit_length()
+            - BigInt::from(2usize);
+        let mut term_exponent = BigInt::zero();
+        let mut terms_count = 0usize;
+        loop {
+            let (numerator, denominator) = ratio(terms_count + 1);
+            let numerator = numerator.abs();
+            term_exponent = term_exponent + (&numerator).bit_length()
+                - (&denominator).bit_length()
+                + BigInt::one();
+            if numerator * BigInt::from(2usize) <= denominator
+                && term_exponent <= tolerance_exponent
+            {
+                break terms_count;
+            }
+            terms_count += 1;
+        }
+    }
+}
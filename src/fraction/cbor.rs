@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::fmt;
+
+use traiter::numbers::{FromBytes, Gcd, Signed, ToBytes, Unitary, Zeroable};
+
+use crate::big_int::{
+    read_header, write_header, BigInt, FromCborError as BigIntFromCborError,
+};
+
+use super::types::Fraction;
+
+const ARRAY_MAJOR_TYPE: u8 = 4;
+const RATIONAL_TAG: u64 = 30;
+const TAG_MAJOR_TYPE: u8 = 6;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        write_header(&mut result, TAG_MAJOR_TYPE, RATIONAL_TAG);
+        write_header(&mut result, ARRAY_MAJOR_TYPE, 2);
+        result.extend(self.numerator.to_cbor());
+        result.extend(self.denominator.to_cbor());
+        result
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
+        Gcd<Output = BigInt<Digit, DIGIT_BITNESS>> + Signed,
+    BigInt<Digit, DIGIT_BITNESS>: FromBytes + Unitary,
+{
+    pub fn from_cbor(bytes: &[u8]) -> Result<(Self, usize), FromCborError> {
+        let (major_type, tag, tag_size) =
+            read_header(bytes).map_err(FromCborError::Component)?;
+        if major_type != TAG_MAJOR_TYPE {
+            return Err(FromCborError::UnexpectedMajorType(major_type));
+        } else if tag != RATIONAL_TAG {
+            return Err(FromCborError::InvalidTag(tag));
+        }
+        let (major_type, length, array_header_size) =
+            read_header(&bytes[tag_size..])
+                .map_err(FromCborError::Component)?;
+        if major_type != ARRAY_MAJOR_TYPE {
+            return Err(FromCborError::UnexpectedMajorType(major_type));
+        } else if length != 2 {
+            return Err(FromCborError::InvalidArrayLength(length));
+        }
+        let numerator_start = tag_size + array_header_size;
+        let (numerator, numerator_size) =
+            BigInt::from_cbor(&bytes[numerator_start..])
+                .map_err(FromCborError::Component)?;
+        let denominator_start = numerator_start + numerator_size;
+        let (denominator, denominator_size) =
+            BigInt::from_cbor(&bytes[denominator_start..])
+                .map_err(FromCborError::Component)?;
+        if (&denominator).is_zero() {
+            Err(FromCborError::ZeroDenominator)
+        } else if !(&denominator).is_positive()
+            || !(&numerator).gcd(&denominator).is_one()
+        {
+            Err(FromCborError::NonNormalized)
+        } else {
+            Ok((
+                Self {
+                    numerator,
+                    denominator,
+                },
+                denominator_start + denominator_size,
+            ))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromCborError {
+    Component(BigIntFromCborError),
+    InvalidArrayLength(u64),
+    InvalidTag(u64),
+    NonNormalized,
+    UnexpectedMajorType(u8),
+    ZeroDenominator,
+}
+
+impl fmt::Debug for FromCborError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl fmt::Display for FromCborError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromCborError::Component(error) => {
+                fmt::Display::fmt(error, formatter)
+            }
+            FromCborError::InvalidArrayLength(length) => write!(
+                formatter,
+                "Expected array of 2 items, but found: {length}."
+            ),
+            FromCborError::InvalidTag(tag) => write!(
+                formatter,
+                "Expected rational number tag {RATIONAL_TAG}, \
+                 but found: {tag}."
+            ),
+            FromCborError::NonNormalized => formatter.write_str(
+                "Denominator should be positive and coprime \
+                 with numerator.",
+            ),
+            FromCborError::UnexpectedMajorType(major_type) => {
+                write!(formatter, "Unexpected major type: {major_type}.")
+            }
+            FromCborError::ZeroDenominator => {
+                formatter.write_str("Denominator should not be zero.")
+            }
+        }
+    }
+}
+
+impl Error for FromCborError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FromCborError::Component(error) => Some(error),
+            _ => None,
+        }
+    }
+}
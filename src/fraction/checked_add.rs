@@ -0,0 +1,97 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::{CheckedAdd, Gcd};
+
+use super::types::{Fraction, NormalizeModuli, PromotedFraction};
+
+macro_rules! integer_fraction_checked_add_impl {
+    ($($integer:ty => $wide:ty)*) => ($(
+        impl Fraction<$integer> {
+            fn widened_checked_add(&self, other: &Self) -> Option<Self> {
+                let gcd = self.denominator.gcd(other.denominator);
+                let (denominator, other_denominator) =
+                    (self.denominator / gcd, other.denominator / gcd);
+                let numerator = (self.numerator as $wide)
+                    .checked_mul(other_denominator as $wide)?
+                    .checked_add(
+                        (other.numerator as $wide)
+                            .checked_mul(denominator as $wide)?,
+                    )?;
+                let (numerator, gcd) =
+                    numerator.normalize_moduli(gcd as $wide);
+                Some(Self {
+                    numerator: <$integer>::try_from(numerator).ok()?,
+                    denominator: denominator.checked_mul(
+                        other_denominator * (gcd as $integer),
+                    )?,
+                })
+            }
+
+            fn widened_checked_add_integer(
+                &self,
+                other: $integer,
+            ) -> Option<Self> {
+                Some(Self {
+                    numerator: <$integer>::try_from(
+                        (self.numerator as $wide).checked_add(
+                            (self.denominator as $wide)
+                                .checked_mul(other as $wide)?,
+                        )?,
+                    )
+                    .ok()?,
+                    denominator: self.denominator,
+                })
+            }
+        }
+
+        impl CheckedAdd for Fraction<$integer> {
+            type Output = Option<Self>;
+
+            fn checked_add(self, other: Self) -> Self::Output {
+                self.widened_checked_add(&other).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Self::try_from(
+                            PromotedFraction::from(self)
+                                + PromotedFraction::from(other),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+
+        impl CheckedAdd<$integer> for Fraction<$integer> {
+            type Output = Option<Self>;
+
+            fn checked_add(self, other: $integer) -> Self::Output {
+                self.widened_checked_add_integer(other).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Self::try_from(
+                            PromotedFraction::from(self)
+                                + PromotedFraction::from(Self::from(other)),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+
+        impl CheckedAdd<Fraction<Self>> for $integer {
+            type Output = Option<Fraction<Self>>;
+
+            #[inline]
+            fn checked_add(self, other: Fraction<Self>) -> Self::Output {
+                other.checked_add(self)
+            }
+        }
+    )*)
+}
+
+integer_fraction_checked_add_impl!(
+    i8 => i128 i16 => i128 i32 => i128 i64 => i128 i128 => i128 isize => i128
+    u8 => u128 u16 => u128 u32 => u128 u64 => u128 u128 => u128 usize => u128
+);
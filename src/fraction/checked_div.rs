@@ -1,10 +1,13 @@
+use std::convert::TryFrom;
 use std::ops::Mul;
 
 use traiter::numbers::{CheckedDiv, Zeroable};
 
 use crate::big_int::BigInt;
 
-use super::types::{Fraction, NormalizeModuli, NormalizeSign};
+use super::types::{
+    Fraction, NormalizeModuli, NormalizeSign, PromotedFraction,
+};
 
 impl<Digit, const DIGIT_BITNESS: usize> CheckedDiv
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
@@ -446,7 +449,60 @@ where
 }
 
 macro_rules! integer_fraction_checked_div_impl {
-    ($($integer:ty)*) => ($(
+    ($($integer:ty => $wide:ty)*) => ($(
+        impl Fraction<$integer> {
+            fn widened_checked_div(&self, divisor: &Self) -> Option<Self> {
+                let (dividend_numerator, divisor_numerator) =
+                    (self.numerator as $wide)
+                        .normalize_moduli(divisor.numerator as $wide);
+                let (dividend_denominator, divisor_denominator) =
+                    self.denominator.normalize_moduli(divisor.denominator);
+                let (numerator, denominator) = dividend_numerator
+                    .checked_mul(divisor_denominator as $wide)?
+                    .checked_normalize_sign(
+                        (dividend_denominator as $wide)
+                            .checked_mul(divisor_numerator)?,
+                    )?;
+                Some(Self {
+                    numerator: <$integer>::try_from(numerator).ok()?,
+                    denominator: <$integer>::try_from(denominator).ok()?,
+                })
+            }
+
+            fn widened_checked_div_integer(
+                &self,
+                divisor: $integer,
+            ) -> Option<Self> {
+                let (dividend_numerator, divisor_numerator) =
+                    (self.numerator as $wide)
+                        .normalize_moduli(divisor as $wide);
+                let (numerator, denominator) = dividend_numerator
+                    .checked_normalize_sign(
+                        (self.denominator as $wide)
+                            .checked_mul(divisor_numerator)?,
+                    )?;
+                Some(Self {
+                    numerator: <$integer>::try_from(numerator).ok()?,
+                    denominator: <$integer>::try_from(denominator).ok()?,
+                })
+            }
+
+            fn widened_checked_div_integer_by(
+                &self,
+                dividend: $integer,
+            ) -> Option<Self> {
+                let (dividend, divisor_numerator) = (dividend as $wide)
+                    .normalize_moduli(self.numerator as $wide);
+                let (numerator, denominator) = dividend
+                    .checked_mul(self.denominator as $wide)?
+                    .checked_normalize_sign(divisor_numerator)?;
+                Some(Self {
+                    numerator: <$integer>::try_from(numerator).ok()?,
+                    denominator: <$integer>::try_from(denominator).ok()?,
+                })
+            }
+        }
+
         impl CheckedDiv for Fraction<$integer> {
             type Output = Option<Self>;
 
@@ -454,18 +510,16 @@ macro_rules! integer_fraction_checked_div_impl {
                 if divisor == 0 {
                     None
                 } else {
-                    let (dividend_numerator, divisor_numerator) =
-                        self.numerator.normalize_moduli(divisor.numerator);
-                    let (dividend_denominator, divisor_denominator) =
-                        self.denominator.normalize_moduli(divisor.denominator);
-                    let (numerator, denominator) = (dividend_numerator
-                        * divisor_denominator)
-                        .normalize_sign(
-                            dividend_denominator * divisor_numerator,
-                        );
-                    Some(Self {
-                        numerator,
-                        denominator,
+                    self.widened_checked_div(&divisor).or_else(|| {
+                        if <$integer>::BITS < <$wide>::BITS {
+                            None
+                        } else {
+                            Self::try_from(
+                                PromotedFraction::from(self)
+                                    / PromotedFraction::from(divisor),
+                            )
+                            .ok()
+                        }
                     })
                 }
             }
@@ -478,13 +532,18 @@ macro_rules! integer_fraction_checked_div_impl {
                 if divisor == 0 {
                     None
                 } else {
-                    let (dividend_numerator, divisor_numerator) =
-                        self.numerator.normalize_moduli(divisor);
-                    let (numerator, denominator) = dividend_numerator
-                        .normalize_sign(self.denominator * divisor_numerator);
-                    Some(Self {
-                        numerator,
-                        denominator,
+                    self.widened_checked_div_integer(divisor).or_else(|| {
+                        if <$integer>::BITS < <$wide>::BITS {
+                            None
+                        } else {
+                            Self::try_from(
+                                PromotedFraction::from(self)
+                                    / PromotedFraction::from(Self::from(
+                                        divisor,
+                                    )),
+                            )
+                            .ok()
+                        }
                     })
                 }
             }
@@ -497,14 +556,16 @@ macro_rules! integer_fraction_checked_div_impl {
                 if divisor == 0 {
                     None
                 } else {
-                    let (dividend, divisor_numerator) =
-                        self.normalize_moduli(divisor.numerator);
-                    let (numerator, denominator) = (dividend
-                        * divisor.denominator)
-                        .normalize_sign(divisor_numerator);
-                    Some(Fraction::<Self> {
-                        numerator,
-                        denominator,
+                    divisor.widened_checked_div_integer_by(self).or_else(|| {
+                        if <$integer>::BITS < <$wide>::BITS {
+                            None
+                        } else {
+                            Fraction::<Self>::try_from(
+                                PromotedFraction::from(Fraction::from(self))
+                                    / PromotedFraction::from(divisor),
+                            )
+                            .ok()
+                        }
                     })
                 }
             }
@@ -513,5 +574,28 @@ macro_rules! integer_fraction_checked_div_impl {
 }
 
 integer_fraction_checked_div_impl!(
-    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+    i8 => i128 i16 => i128 i32 => i128 i64 => i128 i128 => i128 isize => i128
+    u8 => u128 u16 => u128 u32 => u128 u64 => u128 u128 => u128 usize => u128
 );
+
+trait CheckedNormalizeSign: Sized {
+    fn checked_normalize_sign(self, other: Self) -> Option<(Self, Self)>;
+}
+
+impl CheckedNormalizeSign for i128 {
+    #[inline]
+    fn checked_normalize_sign(self, other: Self) -> Option<(Self, Self)> {
+        if other < 0 {
+            Some((self.checked_neg()?, other.checked_neg()?))
+        } else {
+            Some((self, other))
+        }
+    }
+}
+
+impl CheckedNormalizeSign for u128 {
+    #[inline(always)]
+    fn checked_normalize_sign(self, other: Self) -> Option<(Self, Self)> {
+        Some((self, other))
+    }
+}
@@ -0,0 +1,93 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::CheckedMul;
+
+use super::types::{Fraction, NormalizeModuli, PromotedFraction};
+
+macro_rules! integer_fraction_checked_mul_impl {
+    ($($integer:ty => $wide:ty)*) => ($(
+        impl Fraction<$integer> {
+            fn widened_checked_mul(&self, other: &Self) -> Option<Self> {
+                let (numerator, other_denominator) = (self.numerator as $wide)
+                    .normalize_moduli(other.denominator as $wide);
+                let (other_numerator, denominator) = (other.numerator as $wide)
+                    .normalize_moduli(self.denominator as $wide);
+                Some(Self {
+                    numerator: <$integer>::try_from(
+                        numerator.checked_mul(other_numerator)?,
+                    )
+                    .ok()?,
+                    denominator: <$integer>::try_from(
+                        denominator.checked_mul(other_denominator)?,
+                    )
+                    .ok()?,
+                })
+            }
+
+            fn widened_checked_mul_integer(
+                &self,
+                other: $integer,
+            ) -> Option<Self> {
+                let (other, denominator) = (other as $wide)
+                    .normalize_moduli(self.denominator as $wide);
+                Some(Self {
+                    numerator: <$integer>::try_from(
+                        (self.numerator as $wide).checked_mul(other)?,
+                    )
+                    .ok()?,
+                    denominator: <$integer>::try_from(denominator).ok()?,
+                })
+            }
+        }
+
+        impl CheckedMul for Fraction<$integer> {
+            type Output = Option<Self>;
+
+            fn checked_mul(self, other: Self) -> Self::Output {
+                self.widened_checked_mul(&other).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Self::try_from(
+                            PromotedFraction::from(self)
+                                * PromotedFraction::from(other),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+
+        impl CheckedMul<$integer> for Fraction<$integer> {
+            type Output = Option<Self>;
+
+            fn checked_mul(self, other: $integer) -> Self::Output {
+                self.widened_checked_mul_integer(other).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Self::try_from(
+                            PromotedFraction::from(self)
+                                * PromotedFraction::from(Self::from(other)),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+
+        impl CheckedMul<Fraction<Self>> for $integer {
+            type Output = Option<Fraction<Self>>;
+
+            #[inline]
+            fn checked_mul(self, other: Fraction<Self>) -> Self::Output {
+                other.checked_mul(self)
+            }
+        }
+    )*)
+}
+
+integer_fraction_checked_mul_impl!(
+    i8 => i128 i16 => i128 i32 => i128 i64 => i128 i128 => i128 isize => i128
+    u8 => u128 u16 => u128 u32 => u128 u64 => u128 u128 => u128 usize => u128
+);
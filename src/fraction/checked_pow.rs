@@ -3,6 +3,7 @@ use std::ops::Neg;
 use traiter::numbers::{CheckedPow, Signed, Zeroable};
 
 use crate::big_int::BigInt;
+use crate::traits::CheckedRoot;
 
 use super::types::{Fraction, NormalizeSign};
 
@@ -195,6 +196,69 @@ where
     }
 }
 
+impl<Digit, const DIGIT_BITNESS: usize> CheckedPow<Self>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: CheckedPow<&'a Self, Output = Option<Self>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_pow(self, exponent: Self) -> Self::Output {
+        (&self).checked_pow(&exponent)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedPow<&Self>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: CheckedPow<&'a Self, Output = Option<Self>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_pow(self, exponent: &Self) -> Self::Output {
+        (&self).checked_pow(exponent)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedPow<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedPow<
+        &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+{
+    type Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>;
+
+    fn checked_pow(
+        self,
+        exponent: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+    ) -> Self::Output {
+        self.checked_pow(&exponent)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedPow<Self>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedRoot<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+    for<'a> Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedPow<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+{
+    type Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>;
+
+    fn checked_pow(self, exponent: Self) -> Self::Output {
+        self.checked_root(&exponent.denominator)?
+            .checked_pow(&exponent.numerator)
+    }
+}
+
 macro_rules! integer_fraction_checked_pow_impl {
     ($($integer:ty)*) => ($(
         impl CheckedPow<u32> for Fraction<$integer> {
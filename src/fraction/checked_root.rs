@@ -0,0 +1,109 @@
+use crate::big_int::BigInt;
+use crate::traits::CheckedRoot;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedRoot<BigInt<Digit, DIGIT_BITNESS>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self:
+        CheckedRoot<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Option<Self>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_root(
+        self,
+        degree: BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        (&self).checked_root(&degree)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedRoot<&BigInt<Digit, DIGIT_BITNESS>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self:
+        CheckedRoot<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Option<Self>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_root(
+        self,
+        degree: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        (&self).checked_root(degree)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRoot<usize>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self:
+        CheckedRoot<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Option<Self>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<usize>,
+{
+    type Output = Option<Self>;
+
+    fn checked_root(self, degree: usize) -> Self::Output {
+        (&self).checked_root(&BigInt::from(degree))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedRoot<BigInt<Digit, DIGIT_BITNESS>>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: CheckedRoot<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+{
+    type Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>;
+
+    fn checked_root(
+        self,
+        degree: BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        self.checked_root(&degree)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedRoot<&BigInt<Digit, DIGIT_BITNESS>>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: CheckedRoot<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+{
+    type Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>;
+
+    fn checked_root(
+        self,
+        degree: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        Some(Fraction::<BigInt<Digit, DIGIT_BITNESS>> {
+            numerator: (&self.numerator).checked_root(degree)?,
+            denominator: (&self.denominator).checked_root(degree)?,
+        })
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRoot<usize>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: CheckedRoot<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+    BigInt<Digit, DIGIT_BITNESS>: From<usize>,
+{
+    type Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>;
+
+    fn checked_root(self, degree: usize) -> Self::Output {
+        self.checked_root(&BigInt::from(degree))
+    }
+}
@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::{CheckedSub, Gcd};
+
+use super::types::{Fraction, NormalizeModuli, PromotedFraction};
+
+macro_rules! integer_fraction_checked_sub_impl {
+    ($($integer:ty => $wide:ty)*) => ($(
+        impl Fraction<$integer> {
+            fn widened_checked_sub(&self, subtrahend: &Self) -> Option<Self> {
+                let gcd = self.denominator.gcd(subtrahend.denominator);
+                let (denominator, subtrahend_denominator) =
+                    (self.denominator / gcd, subtrahend.denominator / gcd);
+                let numerator = (self.numerator as $wide)
+                    .checked_mul(subtrahend_denominator as $wide)?
+                    .checked_sub(
+                        (subtrahend.numerator as $wide)
+                            .checked_mul(denominator as $wide)?,
+                    )?;
+                let (numerator, gcd) =
+                    numerator.normalize_moduli(gcd as $wide);
+                Some(Self {
+                    numerator: <$integer>::try_from(numerator).ok()?,
+                    denominator: denominator.checked_mul(
+                        subtrahend_denominator * (gcd as $integer),
+                    )?,
+                })
+            }
+
+            fn widened_checked_sub_integer(
+                &self,
+                subtrahend: $integer,
+            ) -> Option<Self> {
+                Some(Self {
+                    numerator: <$integer>::try_from(
+                        (self.numerator as $wide).checked_sub(
+                            (self.denominator as $wide)
+                                .checked_mul(subtrahend as $wide)?,
+                        )?,
+                    )
+                    .ok()?,
+                    denominator: self.denominator,
+                })
+            }
+
+            fn widened_checked_sub_from_integer(
+                &self,
+                minuend: $integer,
+            ) -> Option<Self> {
+                Some(Self {
+                    numerator: <$integer>::try_from(
+                        (self.denominator as $wide)
+                            .checked_mul(minuend as $wide)?
+                            .checked_sub(self.numerator as $wide)?,
+                    )
+                    .ok()?,
+                    denominator: self.denominator,
+                })
+            }
+        }
+
+        impl CheckedSub for Fraction<$integer> {
+            type Output = Option<Self>;
+
+            fn checked_sub(self, subtrahend: Self) -> Self::Output {
+                self.widened_checked_sub(&subtrahend).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Self::try_from(
+                            PromotedFraction::from(self)
+                                - PromotedFraction::from(subtrahend),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+
+        impl CheckedSub<$integer> for Fraction<$integer> {
+            type Output = Option<Self>;
+
+            fn checked_sub(self, subtrahend: $integer) -> Self::Output {
+                self.widened_checked_sub_integer(subtrahend).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Self::try_from(
+                            PromotedFraction::from(self)
+                                - PromotedFraction::from(Self::from(
+                                    subtrahend,
+                                )),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+
+        impl CheckedSub<Fraction<Self>> for $integer {
+            type Output = Option<Fraction<Self>>;
+
+            fn checked_sub(self, subtrahend: Fraction<Self>) -> Self::Output {
+                subtrahend.widened_checked_sub_from_integer(self).or_else(|| {
+                    if <$integer>::BITS < <$wide>::BITS {
+                        None
+                    } else {
+                        Fraction::<Self>::try_from(
+                            PromotedFraction::from(Fraction::from(self))
+                                - PromotedFraction::from(subtrahend),
+                        )
+                        .ok()
+                    }
+                })
+            }
+        }
+    )*)
+}
+
+integer_fraction_checked_sub_impl!(
+    i8 => i128 i16 => i128 i32 => i128 i64 => i128 i128 => i128 isize => i128
+    u8 => u128 u16 => u128 u32 => u128 u64 => u128 u128 => u128 usize => u128
+);
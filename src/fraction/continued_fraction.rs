@@ -0,0 +1,112 @@
+use std::ops::{Add, Mul};
+
+use traiter::numbers::{DivRemEuclid, One, Zero, Zeroable};
+
+use super::types::{
+    ContinuedFractionTerms, Convergents, Fraction, NormalizeModuli,
+    NormalizeSign,
+};
+
+impl<Component: Clone> Fraction<Component> {
+    pub fn continued_fraction_terms(
+        &self,
+    ) -> ContinuedFractionTerms<Component> {
+        ContinuedFractionTerms {
+            dividend: self.numerator.clone(),
+            divisor: self.denominator.clone(),
+        }
+    }
+}
+
+impl<Component: Clone + One + Zero> Fraction<Component> {
+    pub fn convergents(&self) -> Convergents<Component> {
+        Convergents {
+            terms: self.continued_fraction_terms(),
+            previous_numerator: Component::zero(),
+            previous_denominator: Component::one(),
+            numerator: Component::one(),
+            denominator: Component::zero(),
+        }
+    }
+}
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + Mul<Output = Component>
+            + NormalizeModuli<Output = (Component, Component)>
+            + NormalizeSign<Output = (Component, Component)>
+            + One
+            + Zero,
+    > Fraction<Component>
+where
+    for<'a> &'a Component: Zeroable,
+{
+    pub fn from_continued_fraction_terms<Terms>(terms: Terms) -> Option<Self>
+    where
+        Terms: IntoIterator<Item = Component>,
+    {
+        let (mut previous_numerator, mut previous_denominator) =
+            (Component::zero(), Component::one());
+        let (mut numerator, mut denominator) =
+            (Component::one(), Component::zero());
+        for term in terms {
+            (previous_numerator, numerator) = (
+                numerator.clone(),
+                term.clone() * numerator + previous_numerator,
+            );
+            (previous_denominator, denominator) = (
+                denominator.clone(),
+                term * denominator + previous_denominator,
+            );
+        }
+        Self::new(numerator, denominator)
+    }
+}
+
+impl<Component: Clone + DivRemEuclid<Output = (Component, Component)>> Iterator
+    for ContinuedFractionTerms<Component>
+where
+    for<'a> &'a Component: Zeroable,
+{
+    type Item = Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.divisor.is_zero() {
+            return None;
+        }
+        let dividend =
+            std::mem::replace(&mut self.dividend, self.divisor.clone());
+        let (term, remainder) = dividend.div_rem_euclid(self.divisor.clone());
+        self.divisor = remainder;
+        Some(term)
+    }
+}
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + DivRemEuclid<Output = (Component, Component)>
+            + Mul<Output = Component>,
+    > Iterator for Convergents<Component>
+where
+    for<'a> &'a Component: Zeroable,
+{
+    type Item = Fraction<Component>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.terms.next()?;
+        let numerator = term.clone() * self.numerator.clone()
+            + self.previous_numerator.clone();
+        let denominator = term * self.denominator.clone()
+            + self.previous_denominator.clone();
+        self.previous_numerator =
+            std::mem::replace(&mut self.numerator, numerator.clone());
+        self.previous_denominator =
+            std::mem::replace(&mut self.denominator, denominator.clone());
+        Some(Fraction {
+            numerator,
+            denominator,
+        })
+    }
+}
@@ -0,0 +1,273 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::ops::Mul;
+
+use traiter::numbers::{
+    Abs, BitLength, CheckedDivRemEuclid, Gcd, Pow, Round, Signed, TieBreaking,
+    Unitary, Zeroable,
+};
+
+use crate::big_int::BigInt;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed
+        + Unitary
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Clone
+        + Display
+        + From<u8>
+        + From<usize>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Ord
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+    Self: Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    u32: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+    usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    pub fn to_fixed_string(
+        &self,
+        fractional_digits_count: usize,
+        tie_breaking: TieBreaking,
+    ) -> String {
+        let scaled_value = Self {
+            numerator: self.numerator.clone()
+                * power_of_ten(fractional_digits_count),
+            denominator: self.denominator.clone(),
+        }
+        .round(tie_breaking);
+        to_positional_string(&scaled_value, fractional_digits_count)
+    }
+
+    pub fn to_precision_string(
+        &self,
+        significant_digits_count: usize,
+        tie_breaking: TieBreaking,
+    ) -> Option<String> {
+        let (significand, exponent) = self.to_significand_and_exponent(
+            significant_digits_count,
+            tie_breaking,
+        )?;
+        let shift = exponent - ((significant_digits_count - 1) as isize);
+        Some(if shift >= 0 {
+            to_positional_string(
+                &(significand * power_of_ten(shift as usize)),
+                0usize,
+            )
+        } else {
+            to_positional_string(&significand, (-shift) as usize)
+        })
+    }
+
+    pub fn to_scientific_string(
+        &self,
+        fractional_digits_count: usize,
+        tie_breaking: TieBreaking,
+    ) -> Option<String> {
+        let (significand, exponent) = self.to_significand_and_exponent(
+            fractional_digits_count.checked_add(1)?,
+            tie_breaking,
+        )?;
+        Some(format!(
+            "{}e{}",
+            to_positional_string(&significand, fractional_digits_count),
+            exponent
+        ))
+    }
+
+    /// Returns `None` if more than `max_fractional_digits_count` digits
+    /// (including the repeating period) are needed.
+    pub fn to_repeating_decimal_string(
+        &self,
+        max_fractional_digits_count: usize,
+    ) -> Option<String> {
+        let ten = BigInt::<Digit, DIGIT_BITNESS>::from(10u8);
+        let mut result = String::new();
+        if (&self.numerator).is_negative() {
+            result.push('-');
+        }
+        let (integer_part, mut remainder) = unsafe {
+            (&(&self.numerator).abs())
+                .checked_div_rem_euclid(&self.denominator)
+                .unwrap_unchecked()
+        };
+        result.push_str(&integer_part.to_string());
+        if (&remainder).is_zero() {
+            return Some(result);
+        }
+        result.push('.');
+        let mut non_repeating_digits_count = 0usize;
+        let mut rest = self.denominator.clone();
+        loop {
+            let gcd = (&rest).gcd(&ten);
+            if (&gcd).is_one() {
+                break;
+            }
+            rest = unsafe {
+                (&rest).checked_div_rem_euclid(&gcd).unwrap_unchecked().0
+            };
+            non_repeating_digits_count += 1;
+            if non_repeating_digits_count > max_fractional_digits_count {
+                return None;
+            }
+        }
+        for _ in 0..non_repeating_digits_count {
+            remainder = self.push_next_digit(remainder, &mut result);
+        }
+        if (&remainder).is_zero() {
+            return Some(result);
+        }
+        result.push('(');
+        let period_start = remainder.clone();
+        let mut period_length = 0usize;
+        loop {
+            period_length += 1;
+            if non_repeating_digits_count + period_length
+                > max_fractional_digits_count
+            {
+                return None;
+            }
+            remainder = self.push_next_digit(remainder, &mut result);
+            if remainder == period_start {
+                break;
+            }
+        }
+        result.push(')');
+        Some(result)
+    }
+
+    fn push_next_digit(
+        &self,
+        remainder: BigInt<Digit, DIGIT_BITNESS>,
+        result: &mut String,
+    ) -> BigInt<Digit, DIGIT_BITNESS> {
+        let (digit, remainder) = unsafe {
+            (&(remainder * BigInt::from(10u8)))
+                .checked_div_rem_euclid(&self.denominator)
+                .unwrap_unchecked()
+        };
+        result.push(unsafe {
+            char::from_digit(u32::try_from(digit).unwrap_unchecked(), 10u32)
+                .unwrap_unchecked()
+        });
+        remainder
+    }
+
+    fn is_below_power_of_ten(
+        &self,
+        numerator_modulus: &BigInt<Digit, DIGIT_BITNESS>,
+        exponent: isize,
+    ) -> bool {
+        if exponent >= 0 {
+            numerator_modulus
+                < &(self.denominator.clone() * power_of_ten(exponent as usize))
+        } else {
+            numerator_modulus.clone() * power_of_ten((-exponent) as usize)
+                < self.denominator
+        }
+    }
+
+    fn to_significand_and_exponent(
+        &self,
+        significant_digits_count: usize,
+        tie_breaking: TieBreaking,
+    ) -> Option<(BigInt<Digit, DIGIT_BITNESS>, isize)> {
+        if significant_digits_count == 0usize {
+            return None;
+        } else if (&self.numerator).is_zero() {
+            return Some((self.numerator.clone(), 0isize));
+        }
+        let numerator_modulus = (&self.numerator).abs();
+        let bit_lengths_difference =
+            (usize::try_from((&numerator_modulus).bit_length()).ok()?
+                as isize)
+                - (usize::try_from((&self.denominator).bit_length()).ok()?
+                    as isize);
+        // log10(2) ~ 30103 / 100000, so the estimate is off by at most one
+        let mut exponent =
+            (bit_lengths_difference * 30103isize).div_euclid(100000isize);
+        while self.is_below_power_of_ten(&numerator_modulus, exponent) {
+            exponent -= 1;
+        }
+        while !self.is_below_power_of_ten(&numerator_modulus, exponent + 1) {
+            exponent += 1;
+        }
+        let shift = ((significant_digits_count - 1) as isize) - exponent;
+        let mut significand = if shift >= 0 {
+            Self {
+                numerator: self.numerator.clone()
+                    * power_of_ten(shift as usize),
+                denominator: self.denominator.clone(),
+            }
+        } else {
+            Self {
+                numerator: self.numerator.clone(),
+                denominator: self.denominator.clone()
+                    * power_of_ten((-shift) as usize),
+            }
+        }
+        .round(tie_breaking);
+        if (&significand).abs() == power_of_ten(significant_digits_count) {
+            significand = unsafe {
+                (&significand)
+                    .checked_div_rem_euclid(&BigInt::from(10u8))
+                    .unwrap_unchecked()
+                    .0
+            };
+            exponent += 1;
+        }
+        Some((significand, exponent))
+    }
+}
+
+fn power_of_ten<Digit, const DIGIT_BITNESS: usize>(
+    exponent: usize,
+) -> BigInt<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<u8>
+        + From<usize>
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+{
+    BigInt::from(10u8).pow(BigInt::from(exponent))
+}
+
+fn to_positional_string<Digit, const DIGIT_BITNESS: usize>(
+    scaled_value: &BigInt<Digit, DIGIT_BITNESS>,
+    fractional_digits_count: usize,
+) -> String
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
+        Abs<Output = BigInt<Digit, DIGIT_BITNESS>> + Signed,
+    BigInt<Digit, DIGIT_BITNESS>: Display,
+{
+    let mut digits = scaled_value.abs().to_string();
+    if digits.len() <= fractional_digits_count {
+        digits.insert_str(
+            0,
+            &"0".repeat(fractional_digits_count + 1 - digits.len()),
+        );
+    }
+    if fractional_digits_count > 0usize {
+        digits.insert(digits.len() - fractional_digits_count, '.');
+    }
+    if scaled_value.is_negative() {
+        digits.insert(0, '-');
+    }
+    digits
+}
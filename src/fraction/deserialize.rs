@@ -0,0 +1,100 @@
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use traiter::numbers::{FromStrRadix, One, Zeroable};
+
+use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
+
+use super::types::{Fraction, NormalizeModuli, NormalizeSign};
+
+impl<'de, Component> Deserialize<'de> for Fraction<Component>
+where
+    for<'a> &'a Component: Zeroable,
+    Component: Deserialize<'de>
+        + FromStrRadix
+        + NormalizeModuli<Output = (Component, Component)>
+        + NormalizeSign<Output = (Component, Component)>
+        + One,
+    <Component as FromStrRadix>::Error: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FractionStringVisitor(PhantomData))
+        } else {
+            deserializer
+                .deserialize_tuple(2, FractionComponentsVisitor(PhantomData))
+        }
+    }
+}
+
+struct FractionStringVisitor<Component>(PhantomData<Component>);
+
+impl<Component> Visitor<'_> for FractionStringVisitor<Component>
+where
+    for<'a> &'a Component: Zeroable,
+    Component: FromStrRadix
+        + NormalizeModuli<Output = (Component, Component)>
+        + NormalizeSign<Output = (Component, Component)>
+        + One,
+    <Component as FromStrRadix>::Error: Display,
+{
+    type Value = Fraction<Component>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(
+            "a string with an integer or a ratio of integers separated by '/'",
+        )
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        let (numerator, denominator) = match value.split_once('/') {
+            Some((numerator, denominator)) => (
+                Component::from_str_radix(numerator.trim(), 10)
+                    .map_err(E::custom)?,
+                Component::from_str_radix(denominator.trim(), 10)
+                    .map_err(E::custom)?,
+            ),
+            None => (
+                Component::from_str_radix(value.trim(), 10)
+                    .map_err(E::custom)?,
+                Component::one(),
+            ),
+        };
+        Fraction::new(numerator, denominator)
+            .ok_or_else(|| E::custom(UNDEFINED_DIVISION_ERROR_MESSAGE))
+    }
+}
+
+struct FractionComponentsVisitor<Component>(PhantomData<Component>);
+
+impl<'de, Component> Visitor<'de> for FractionComponentsVisitor<Component>
+where
+    for<'a> &'a Component: Zeroable,
+    Component: Deserialize<'de>
+        + NormalizeModuli<Output = (Component, Component)>
+        + NormalizeSign<Output = (Component, Component)>,
+{
+    type Value = Fraction<Component>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a pair of a numerator and a denominator")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut sequence: A,
+    ) -> Result<Self::Value, A::Error> {
+        let numerator = sequence
+            .next_element::<Component>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let denominator = sequence
+            .next_element::<Component>()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Fraction::new(numerator, denominator)
+            .ok_or_else(|| A::Error::custom(UNDEFINED_DIVISION_ERROR_MESSAGE))
+    }
+}
@@ -5,7 +5,7 @@ use traiter::numbers::CheckedDiv;
 use crate::big_int::BigInt;
 use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
 
-use super::types::Fraction;
+use super::types::{Fraction, NormalizeModuli, NormalizeSign};
 
 impl<Digit, const DIGIT_BITNESS: usize> Div
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
@@ -198,8 +198,22 @@ macro_rules! integer_fraction_div_impl {
             type Output = Self;
 
             fn div(self, divisor: Self) -> Self::Output {
-                self.checked_div(divisor)
-                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+                assert!(
+                    divisor != 0,
+                    "{}",
+                    UNDEFINED_DIVISION_ERROR_MESSAGE
+                );
+                let (dividend_numerator, divisor_numerator) =
+                    self.numerator.normalize_moduli(divisor.numerator);
+                let (dividend_denominator, divisor_denominator) =
+                    self.denominator.normalize_moduli(divisor.denominator);
+                let (numerator, denominator) = (dividend_numerator
+                    * divisor_denominator)
+                    .normalize_sign(dividend_denominator * divisor_numerator);
+                Self {
+                    numerator,
+                    denominator,
+                }
             }
         }
 
@@ -207,8 +221,19 @@ macro_rules! integer_fraction_div_impl {
             type Output = Self;
 
             fn div(self, divisor: $integer) -> Self::Output {
-                self.checked_div(divisor)
-                    .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+                assert!(
+                    divisor != 0,
+                    "{}",
+                    UNDEFINED_DIVISION_ERROR_MESSAGE
+                );
+                let (dividend_numerator, divisor_numerator) =
+                    self.numerator.normalize_moduli(divisor);
+                let (numerator, denominator) = dividend_numerator
+                    .normalize_sign(self.denominator * divisor_numerator);
+                Self {
+                    numerator,
+                    denominator,
+                }
             }
         }
 
@@ -216,10 +241,20 @@ macro_rules! integer_fraction_div_impl {
             type Output = Fraction<Self>;
 
             fn div(self, divisor: Fraction<Self>) -> Self::Output {
-                <$integer as CheckedDiv<Fraction<Self>>>::checked_div(
-                    self, divisor,
-                )
-                .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+                assert!(
+                    divisor != 0,
+                    "{}",
+                    UNDEFINED_DIVISION_ERROR_MESSAGE
+                );
+                let (dividend, divisor_numerator) =
+                    self.normalize_moduli(divisor.numerator);
+                let (numerator, denominator) = (dividend
+                    * divisor.denominator)
+                    .normalize_sign(divisor_numerator);
+                Fraction::<Self> {
+                    numerator,
+                    denominator,
+                }
             }
         }
     )*)
@@ -0,0 +1,62 @@
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::{DivRemEuclid, One, Zero};
+
+use super::types::{FareySequence, Fraction};
+
+impl<Component: Clone + One + PartialOrd + Zero> Fraction<Component> {
+    pub fn farey_sequence(
+        order: Component,
+    ) -> Option<FareySequence<Component>> {
+        if order < Component::one() {
+            None
+        } else {
+            Some(FareySequence {
+                order: order.clone(),
+                numerator: Component::zero(),
+                denominator: Component::one(),
+                next_numerator: Component::one(),
+                next_denominator: order,
+            })
+        }
+    }
+}
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + DivRemEuclid<Output = (Component, Component)>
+            + Mul<Output = Component>
+            + PartialOrd
+            + Sub<Output = Component>,
+    > Iterator for FareySequence<Component>
+{
+    type Item = Fraction<Component>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.numerator > self.denominator {
+            return None;
+        }
+        let scale = (self.order.clone() + self.denominator.clone())
+            .div_rem_euclid(self.next_denominator.clone())
+            .0;
+        let next_numerator = scale.clone() * self.next_numerator.clone()
+            - self.numerator.clone();
+        let next_denominator =
+            scale * self.next_denominator.clone() - self.denominator.clone();
+        let numerator = std::mem::replace(
+            &mut self.numerator,
+            self.next_numerator.clone(),
+        );
+        let denominator = std::mem::replace(
+            &mut self.denominator,
+            self.next_denominator.clone(),
+        );
+        (self.next_numerator, self.next_denominator) =
+            (next_numerator, next_denominator);
+        Some(Fraction {
+            numerator,
+            denominator,
+        })
+    }
+}
@@ -1,5 +1,7 @@
 use traiter::numbers::One;
 
+use crate::big_int::BigInt;
+
 use super::types::Fraction;
 
 impl<Component: One> From<Component> for Fraction<Component> {
@@ -10,3 +12,37 @@ impl<Component: One> From<Component> for Fraction<Component> {
         }
     }
 }
+
+macro_rules! big_int_fraction_from_integer_fraction_impl {
+    ($($integer:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> From<Fraction<$integer>>
+            for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            BigInt<Digit, DIGIT_BITNESS>: From<$integer>,
+        {
+            fn from(value: Fraction<$integer>) -> Self {
+                Self {
+                    numerator: BigInt::from(value.numerator),
+                    denominator: BigInt::from(value.denominator),
+                }
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> From<&Fraction<$integer>>
+            for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            BigInt<Digit, DIGIT_BITNESS>: From<$integer>,
+        {
+            fn from(value: &Fraction<$integer>) -> Self {
+                Self {
+                    numerator: BigInt::from(value.numerator),
+                    denominator: BigInt::from(value.denominator),
+                }
+            }
+        }
+    )*)
+}
+
+big_int_fraction_from_integer_fraction_impl!(
+    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+);
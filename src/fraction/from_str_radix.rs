@@ -0,0 +1,32 @@
+use traiter::numbers::{FromStrRadix, Zeroable};
+
+use super::types::{
+    Fraction, NormalizeModuli, NormalizeSign, TryFromStringError,
+};
+
+impl<
+        Component: FromStrRadix
+            + NormalizeModuli<Output = (Component, Component)>
+            + NormalizeSign<Output = (Component, Component)>,
+    > FromStrRadix for Fraction<Component>
+where
+    for<'a> &'a Component: Zeroable,
+    Self: From<Component>,
+{
+    type Error = TryFromStringError<Component::Error>;
+
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::Error> {
+        match string.split_once('/') {
+            Some((numerator, denominator)) => Self::new(
+                Component::from_str_radix(numerator, radix)
+                    .map_err(TryFromStringError::Component)?,
+                Component::from_str_radix(denominator, radix)
+                    .map_err(TryFromStringError::Component)?,
+            )
+            .ok_or(TryFromStringError::ZeroDenominator),
+            None => Component::from_str_radix(string, radix)
+                .map(Self::from)
+                .map_err(TryFromStringError::Component),
+        }
+    }
+}
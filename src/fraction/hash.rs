@@ -1,17 +1,17 @@
 use std::hash::{Hash, Hasher};
 
 use crate::big_int::BigInt;
+use crate::traits::NumericHash;
 
 use super::types::Fraction;
 
 impl<Digit, const DIGIT_BITNESS: usize> Hash
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    BigInt<Digit, DIGIT_BITNESS>: Hash,
+    Self: NumericHash,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.numerator.hash(state);
-        self.denominator.hash(state);
+        state.write_isize(self.numeric_hash());
     }
 }
 
@@ -19,8 +19,7 @@ macro_rules! integer_fraction_hash_impl {
     ($($integer:ty)*) => ($(
         impl Hash for Fraction<$integer> {
             fn hash<H: Hasher>(&self, state: &mut H) {
-                self.numerator.hash(state);
-                self.denominator.hash(state);
+                state.write_isize(self.numeric_hash());
             }
         }
     )*)
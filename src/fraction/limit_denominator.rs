@@ -0,0 +1,72 @@
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::{DivRemEuclid, One, Zero};
+
+use super::types::Fraction;
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + DivRemEuclid<Output = (Component, Component)>
+            + Mul<Output = Component>
+            + One
+            + Ord
+            + Sub<Output = Component>
+            + Zero,
+    > Fraction<Component>
+{
+    pub fn limit_denominator(
+        &self,
+        max_denominator: Component,
+    ) -> Option<Self> {
+        if max_denominator < Component::one() {
+            return None;
+        } else if self.denominator <= max_denominator {
+            return Some(self.clone());
+        }
+        let (mut previous_numerator, mut previous_denominator) =
+            (Component::zero(), Component::one());
+        let (mut numerator, mut denominator) =
+            (Component::one(), Component::zero());
+        let (mut dividend, mut divisor) =
+            (self.numerator.clone(), self.denominator.clone());
+        loop {
+            let (term, remainder) = dividend.div_rem_euclid(divisor.clone());
+            let next_denominator = previous_denominator.clone()
+                + term.clone() * denominator.clone();
+            if next_denominator > max_denominator {
+                break;
+            }
+            (previous_numerator, numerator) =
+                (numerator.clone(), previous_numerator + term * numerator);
+            (previous_denominator, denominator) =
+                (denominator, next_denominator);
+            (dividend, divisor) = (divisor, remainder);
+        }
+        let scale = (max_denominator - previous_denominator.clone())
+            .div_rem_euclid(denominator.clone())
+            .0;
+        let bound_denominator =
+            previous_denominator + scale.clone() * denominator.clone();
+        let two = Component::one() + Component::one();
+        if bound_denominator
+            <= self
+                .denominator
+                .clone()
+                .div_rem_euclid(two)
+                .0
+                .div_rem_euclid(divisor)
+                .0
+        {
+            Some(Self {
+                numerator,
+                denominator,
+            })
+        } else {
+            Some(Self {
+                numerator: previous_numerator + scale * numerator,
+                denominator: bound_denominator,
+            })
+        }
+    }
+}
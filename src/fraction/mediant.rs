@@ -0,0 +1,22 @@
+use std::ops::Add;
+
+use super::types::{Fraction, NormalizeModuli};
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + NormalizeModuli<Output = (Component, Component)>,
+    > Fraction<Component>
+{
+    pub fn mediant(&self, other: &Self) -> Self {
+        let (numerator, denominator) = (self.numerator.clone()
+            + other.numerator.clone())
+        .normalize_moduli(
+            self.denominator.clone() + other.denominator.clone(),
+        );
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
@@ -1,43 +1,79 @@
-pub use types::{Fraction, FromFloatConstructionError};
+#[cfg(feature = "cbor")]
+pub use cbor::FromCborError;
+pub use types::{
+    ContinuedFractionTerms, Convergents, FareySequence, Fraction,
+    FractionAccumulator, FromFloatConstructionError, FromVarintPairError,
+    SternBrocotDirection, SternBrocotPath, TryFromStringError,
+};
 
 mod abs;
+mod accumulator;
 mod add;
 mod add_assign;
+mod approximate;
+#[cfg(feature = "cbor")]
+mod cbor;
 mod ceil;
+mod checked_add;
 mod checked_div;
 mod checked_div_euclid;
 mod checked_div_rem_euclid;
+mod checked_mul;
 mod checked_pow;
 mod checked_rem;
 mod checked_rem_euclid;
+mod checked_root;
+mod checked_sub;
+mod continued_fraction;
 mod debug;
+mod decimal_string;
+#[cfg(feature = "serde")]
+mod deserialize;
 mod display;
 mod div;
 mod div_assign;
 mod div_euclid;
 mod eq;
+mod farey_sequence;
 mod floor;
 mod from;
+mod from_str_radix;
 mod hash;
+mod limit_denominator;
+mod mediant;
 mod mul;
 mod mul_assign;
 mod neg;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod numeric_hash;
 mod one;
 mod ord;
+mod order_preserving;
 mod partial_eq;
 mod partial_ord;
+mod pg_numeric;
 mod pow;
+mod product;
+mod quantize;
 mod rem;
 mod rem_assign;
 mod rem_euclid;
 mod round;
+#[cfg(feature = "serde")]
+mod serialize;
 mod signed;
+mod simplest_between;
+mod stern_brocot;
 mod sub;
 mod sub_assign;
+mod sum;
 mod trunc;
 mod try_from;
 mod try_into;
+mod try_to_float;
 mod types;
 mod unitary;
+mod varint;
 mod zero;
 mod zeroable;
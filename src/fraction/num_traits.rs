@@ -0,0 +1,346 @@
+use std::convert::TryFrom;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use traiter::numbers::{
+    Abs, CheckedDiv, CheckedRem, FromStrRadix, One, Pow, Sign, Signed, Trunc,
+    Unitary, Zero, Zeroable,
+};
+
+use crate::big_int::{BigInt, TryFromStringError as BigIntTryFromStringError};
+
+use super::types::{Fraction, TryFromStringError};
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::Zero
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Zeroable,
+    Self: Add<Output = Self> + Zero,
+{
+    #[inline]
+    fn zero() -> Self {
+        <Self as Zero>::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Zeroable::is_zero(self)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::One
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Unitary,
+    Self: Mul<Output = Self> + One,
+{
+    #[inline]
+    fn one() -> Self {
+        <Self as One>::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        Unitary::is_one(self)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::Num
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: Div<Output = Self>
+        + FromStrRadix<Error = TryFromStringError<BigIntTryFromStringError>>
+        + num_traits::One
+        + PartialEq
+        + Rem<Output = Self>
+        + Sub<Output = Self>
+        + num_traits::Zero,
+{
+    type FromStrRadixErr = TryFromStringError<BigIntTryFromStringError>;
+
+    #[inline]
+    fn from_str_radix(
+        string: &str,
+        radix: u32,
+    ) -> Result<Self, Self::FromStrRadixErr> {
+        <Self as FromStrRadix>::from_str_radix(string, radix)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::Signed
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Abs<Output = Self> + Signed + Sub<Output = Self>,
+    Self: Neg<Output = Self> + num_traits::Num + PartialOrd,
+{
+    #[inline]
+    fn abs(&self) -> Self {
+        Abs::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            <Self as num_traits::Zero>::zero()
+        } else {
+            self - other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        match Signed::sign(self) {
+            Sign::Negative => -<Self as num_traits::One>::one(),
+            Sign::Positive => <Self as num_traits::One>::one(),
+            Sign::Zero => <Self as num_traits::Zero>::zero(),
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Signed::is_positive(self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Signed::is_negative(self)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::ToPrimitive
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Trunc<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    for<'a> f64: TryFrom<&'a Self>,
+    i64: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+    i128: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+    u64: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+    u128: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self.trunc()).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self.trunc()).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self.trunc()).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self.trunc()).ok()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        f64::try_from(self).ok()
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::FromPrimitive
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>:
+        From<i64> + From<i128> + From<u64> + From<u128> + One,
+    Self: TryFrom<f64>,
+{
+    #[inline]
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(Self::from(BigInt::from(value)))
+    }
+
+    #[inline]
+    fn from_i128(value: i128) -> Option<Self> {
+        Some(Self::from(BigInt::from(value)))
+    }
+
+    #[inline]
+    fn from_u64(value: u64) -> Option<Self> {
+        Some(Self::from(BigInt::from(value)))
+    }
+
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        Some(Self::from(BigInt::from(value)))
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    num_traits::Pow<BigInt<Digit, DIGIT_BITNESS>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: Pow<BigInt<Digit, DIGIT_BITNESS>, Output = Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, exponent: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+        Pow::pow(self, exponent)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    num_traits::Pow<&BigInt<Digit, DIGIT_BITNESS>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: Pow<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, exponent: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+        Pow::pow(self, exponent)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    num_traits::Pow<BigInt<Digit, DIGIT_BITNESS>>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: Pow<
+        BigInt<Digit, DIGIT_BITNESS>,
+        Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+{
+    type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
+
+    #[inline]
+    fn pow(self, exponent: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+        Pow::pow(self, exponent)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    num_traits::Pow<&BigInt<Digit, DIGIT_BITNESS>>
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> Self: Pow<
+        &'a BigInt<Digit, DIGIT_BITNESS>,
+        Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+    >,
+{
+    type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
+
+    #[inline]
+    fn pow(self, exponent: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+        Pow::pow(self, exponent)
+    }
+}
+
+macro_rules! big_int_fraction_pow_integer_impl {
+    ($($integer:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> num_traits::Pow<$integer>
+            for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            BigInt<Digit, DIGIT_BITNESS>: From<$integer>,
+            Self: Pow<BigInt<Digit, DIGIT_BITNESS>, Output = Self>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, exponent: $integer) -> Self::Output {
+                Pow::pow(self, BigInt::from(exponent))
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize> num_traits::Pow<$integer>
+            for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            BigInt<Digit, DIGIT_BITNESS>: From<$integer>,
+            Self: Pow<
+                BigInt<Digit, DIGIT_BITNESS>,
+                Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+            >,
+        {
+            type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
+
+            #[inline]
+            fn pow(self, exponent: $integer) -> Self::Output {
+                Pow::pow(self, BigInt::from(exponent))
+            }
+        }
+    )*)
+}
+
+big_int_fraction_pow_integer_impl!(
+    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+);
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::CheckedAdd
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Add<Output = Self>,
+    Self: Add<Output = Self>,
+{
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::CheckedSub
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Sub<Output = Self>,
+    Self: Sub<Output = Self>,
+{
+    #[inline]
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::CheckedMul
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Mul<Output = Self>,
+    Self: Mul<Output = Self>,
+{
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::CheckedDiv
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: CheckedDiv<Output = Option<Self>>,
+    Self: Div<Output = Self>,
+{
+    #[inline]
+    fn checked_div(&self, divisor: &Self) -> Option<Self> {
+        CheckedDiv::checked_div(self, divisor)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::CheckedRem
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: CheckedRem<Output = Option<Self>>,
+    Self: Rem<Output = Self>,
+{
+    #[inline]
+    fn checked_rem(&self, divisor: &Self) -> Option<Self> {
+        CheckedRem::checked_rem(self, divisor)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> num_traits::CheckedNeg
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Neg<Output = Self>,
+{
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
@@ -0,0 +1,59 @@
+use traiter::numbers::Signed;
+
+use crate::big_int::{to_hash_residue, BigInt};
+use crate::traits::{reduce_modulo_hash_modulus, to_ratio_hash, NumericHash};
+
+use super::types::Fraction;
+
+impl<Digit: Copy, const DIGIT_BITNESS: usize> NumericHash
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Signed,
+    u128: From<Digit>,
+{
+    fn numeric_hash(&self) -> isize {
+        to_ratio_hash(
+            to_hash_residue::<Digit, DIGIT_BITNESS>(
+                self.numerator.as_digits(),
+            ),
+            to_hash_residue::<Digit, DIGIT_BITNESS>(
+                self.denominator.as_digits(),
+            ),
+            (&self.numerator).is_negative(),
+        )
+    }
+}
+
+macro_rules! signed_integer_fraction_numeric_hash_impl {
+    ($($integer:ty)*) => ($(
+        impl NumericHash for Fraction<$integer> {
+            fn numeric_hash(&self) -> isize {
+                to_ratio_hash(
+                    reduce_modulo_hash_modulus(
+                        self.numerator.unsigned_abs() as u128,
+                    ),
+                    reduce_modulo_hash_modulus(self.denominator as u128),
+                    self.numerator.is_negative(),
+                )
+            }
+        }
+    )*)
+}
+
+signed_integer_fraction_numeric_hash_impl!(i8 i16 i32 i64 i128 isize);
+
+macro_rules! unsigned_integer_fraction_numeric_hash_impl {
+    ($($integer:ty)*) => ($(
+        impl NumericHash for Fraction<$integer> {
+            fn numeric_hash(&self) -> isize {
+                to_ratio_hash(
+                    reduce_modulo_hash_modulus(self.numerator as u128),
+                    reduce_modulo_hash_modulus(self.denominator as u128),
+                    false,
+                )
+            }
+        }
+    )*)
+}
+
+unsigned_integer_fraction_numeric_hash_impl!(u8 u16 u32 u64 u128 usize);
@@ -0,0 +1,110 @@
+use std::ops::{Add, Mul};
+
+use traiter::numbers::{
+    DivRemEuclid, FromBytes, One, Signed, ToBytes, Unitary, Zeroable,
+};
+
+use crate::big_int::{
+    read_order_preserving_bytes, write_order_preserving_bytes, BigInt,
+    FromOrderPreservingBytesError,
+};
+
+use super::types::Fraction;
+
+const EVEN_TERMINATOR: u8 = u8::MAX;
+const ODD_TERMINATOR: u8 = u8::MIN;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: DivRemEuclid<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + ToBytes<Output = Vec<u8>>
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
+{
+    pub fn to_order_preserving_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        let (mut dividend, mut divisor) =
+            (self.numerator.clone(), self.denominator.clone());
+        let mut index = 0usize;
+        loop {
+            let (term, remainder) = dividend.div_rem_euclid(&divisor);
+            write_order_preserving_bytes(&term, is_odd(index), &mut result);
+            index += 1;
+            if (&remainder).is_zero() {
+                break;
+            }
+            (dividend, divisor) = (divisor, remainder);
+        }
+        result.push(terminator(index));
+        result
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
+        Mul<Output = BigInt<Digit, DIGIT_BITNESS>> + Signed + Unitary,
+    BigInt<Digit, DIGIT_BITNESS>:
+        Add<Output = BigInt<Digit, DIGIT_BITNESS>> + FromBytes + One,
+{
+    pub fn from_order_preserving_bytes(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromOrderPreservingBytesError> {
+        let mut terms = Vec::<BigInt<Digit, DIGIT_BITNESS>>::new();
+        let mut size = 0usize;
+        loop {
+            let index = terms.len();
+            if index > 0usize
+                && *bytes
+                    .get(size)
+                    .ok_or(FromOrderPreservingBytesError::Truncated)?
+                    == terminator(index)
+            {
+                size += 1;
+                break;
+            }
+            let (term, term_size) =
+                read_order_preserving_bytes(&bytes[size..], is_odd(index))?;
+            if index > 0usize && !(&term).is_positive() {
+                return Err(FromOrderPreservingBytesError::NonCanonical);
+            }
+            terms.push(term);
+            size += term_size;
+        }
+        if terms.len() > 1usize && terms[terms.len() - 1].is_one() {
+            return Err(FromOrderPreservingBytesError::NonCanonical);
+        }
+        let mut terms = terms.into_iter().rev();
+        let mut numerator = unsafe { terms.next().unwrap_unchecked() };
+        let mut denominator = BigInt::one();
+        for term in terms {
+            let next_numerator = &term * &numerator + denominator;
+            denominator = std::mem::replace(&mut numerator, next_numerator);
+        }
+        Ok((
+            Self {
+                numerator,
+                denominator,
+            },
+            size,
+        ))
+    }
+}
+
+#[inline]
+fn is_odd(index: usize) -> bool {
+    index % 2usize == 1usize
+}
+
+#[inline]
+fn terminator(index: usize) -> u8 {
+    if is_odd(index) {
+        ODD_TERMINATOR
+    } else {
+        EVEN_TERMINATOR
+    }
+}
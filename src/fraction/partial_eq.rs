@@ -1,3 +1,5 @@
+use std::convert::TryFrom;
+
 use traiter::numbers::Unitary;
 
 use crate::big_int::BigInt;
@@ -38,6 +40,46 @@ where
     }
 }
 
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq<f64>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: PartialEq + TryFrom<f64>,
+{
+    fn eq(&self, other: &f64) -> bool {
+        Self::try_from(*other).is_ok_and(|other| self.eq(&other))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    PartialEq<Fraction<BigInt<Digit, DIGIT_BITNESS>>> for f64
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: PartialEq<f64>,
+{
+    fn eq(&self, other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>) -> bool {
+        other.eq(self)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq<f32>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: PartialEq<f64>,
+{
+    fn eq(&self, other: &f32) -> bool {
+        self.eq(&f64::from(*other))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    PartialEq<Fraction<BigInt<Digit, DIGIT_BITNESS>>> for f32
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: PartialEq<f32>,
+{
+    fn eq(&self, other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>) -> bool {
+        other.eq(self)
+    }
+}
+
 macro_rules! integer_fraction_partial_eq_impl {
     ($($integer:ty)*) => ($(
         impl PartialEq for Fraction<$integer> {
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::ops::Mul;
 
 use crate::big_int::BigInt;
@@ -47,6 +48,64 @@ where
     }
 }
 
+impl<Digit, const DIGIT_BITNESS: usize> PartialOrd<f64>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: PartialEq<f64> + PartialOrd + TryFrom<f64>,
+{
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        if other.is_nan() {
+            None
+        } else if other.is_infinite() {
+            Some(if other.is_sign_positive() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            })
+        } else {
+            self.partial_cmp(&Self::try_from(*other).ok()?)
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    PartialOrd<Fraction<BigInt<Digit, DIGIT_BITNESS>>> for f64
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: PartialOrd<f64>,
+    Self: PartialEq<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    fn partial_cmp(
+        &self,
+        other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+    ) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialOrd<f32>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: PartialEq<f32> + PartialOrd<f64>,
+{
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        self.partial_cmp(&f64::from(*other))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    PartialOrd<Fraction<BigInt<Digit, DIGIT_BITNESS>>> for f32
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: PartialOrd<f32>,
+    Self: PartialEq<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    fn partial_cmp(
+        &self,
+        other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+    ) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
 macro_rules! integer_partial_ord_fraction_impl {
     ($($integer:ty)*) => ($(
         impl PartialOrd for Fraction<$integer> {
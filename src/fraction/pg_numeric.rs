@@ -0,0 +1,103 @@
+use std::ops::{Mul, Shr};
+
+use traiter::numbers::{
+    CheckedDivRem, Endianness, FromBytes, Pow, Round, TieBreaking, ToBytes,
+    Unitary, Zeroable,
+};
+
+use crate::big_int::{
+    read_pg_numeric, write_pg_numeric, BigInt, FromPgNumericError,
+    NUMERIC_MAX_DISPLAY_SCALE,
+};
+
+use super::types::{Fraction, NormalizeModuli};
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
+        ToBytes<Output = Vec<u8>> + Unitary + Zeroable,
+    for<'a> &'a Self: Mul<BigInt<Digit, DIGIT_BITNESS>, Output = Self>,
+    for<'a> BigInt<Digit, DIGIT_BITNESS>: CheckedDivRem<
+            &'a BigInt<Digit, DIGIT_BITNESS>,
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Clone
+        + From<u8>
+        + From<usize>
+        + Shr<usize, Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+    Self: Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    pub fn to_pg_numeric(
+        &self,
+        non_terminating_scale: usize,
+        tie_breaking: TieBreaking,
+    ) -> Option<Vec<u8>> {
+        let scale = match self.terminating_decimal_scale() {
+            Some(scale) if scale > NUMERIC_MAX_DISPLAY_SCALE => return None,
+            Some(scale) => scale,
+            None if non_terminating_scale > NUMERIC_MAX_DISPLAY_SCALE => {
+                return None
+            }
+            None => non_terminating_scale,
+        };
+        let scaled_value = (self
+            * BigInt::from(10u8).pow(BigInt::from(scale)))
+        .round(tie_breaking);
+        write_pg_numeric(&scaled_value, scale)
+    }
+
+    /// Denominators with more factors of 5 than the scale limit allows
+    /// are reported as exceeding it without checking the rest.
+    fn terminating_decimal_scale(&self) -> Option<usize> {
+        let denominator_bytes =
+            (&self.denominator).to_bytes(Endianness::Little);
+        let zero_bytes_count = denominator_bytes
+            .iter()
+            .take_while(|&&byte| byte == 0)
+            .count();
+        let twos_count = zero_bytes_count * 8
+            + denominator_bytes[zero_bytes_count].trailing_zeros() as usize;
+        let mut rest = self.denominator.clone() >> twos_count;
+        let five = BigInt::<Digit, DIGIT_BITNESS>::from(5u8);
+        let mut fives_count = 0usize;
+        while !(&rest).is_one() {
+            if fives_count > NUMERIC_MAX_DISPLAY_SCALE {
+                return Some(fives_count);
+            }
+            let (quotient, remainder) =
+                unsafe { rest.checked_div_rem(&five).unwrap_unchecked() };
+            if !remainder.is_zero() {
+                return None;
+            }
+            rest = quotient;
+            fives_count += 1;
+        }
+        Some(twos_count.max(fives_count))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: FromBytes
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+{
+    pub fn from_pg_numeric(bytes: &[u8]) -> Result<Self, FromPgNumericError> {
+        let (numerator, denominator) = read_pg_numeric(bytes)?;
+        let (numerator, denominator) = numerator.normalize_moduli(denominator);
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+}
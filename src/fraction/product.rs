@@ -0,0 +1,28 @@
+use std::iter::Product;
+use std::ops::Mul;
+
+use traiter::numbers::One;
+
+use crate::big_int::{balanced_product, BigInt};
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Product
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: Mul<Output = Self> + One,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        balanced_product(iter)
+    }
+}
+
+impl<'a, Digit: 'a, const DIGIT_BITNESS: usize> Product<&'a Self>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: Clone + Mul<Output = Self> + One,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        balanced_product(iter.cloned())
+    }
+}
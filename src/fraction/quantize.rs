@@ -0,0 +1,109 @@
+use std::ops::{Div, Mul};
+
+use traiter::numbers::{
+    Abs, Ceil, CheckedDiv, Floor, Pow, Round, TieBreaking,
+};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, NormalizeModuli};
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a Self: Abs<Output = Self>
+        + CheckedDiv<&'a Self, Output = Option<Self>>
+        + Div<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Self>
+        + Mul<&'a BigInt<Digit, DIGIT_BITNESS>, Output = Self>,
+    BigInt<Digit, DIGIT_BITNESS>: From<usize>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Self, Output = Self>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+    Self: Ceil<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Floor<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + From<BigInt<Digit, DIGIT_BITNESS>>
+        + Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    pub fn round_to_multiple(
+        &self,
+        step: &Self,
+        tie_breaking: TieBreaking,
+    ) -> Option<Self> {
+        self.quantize_to_multiple(step, |value| value.round(tie_breaking))
+    }
+
+    pub fn floor_to_multiple(&self, step: &Self) -> Option<Self> {
+        self.quantize_to_multiple(step, Floor::floor)
+    }
+
+    pub fn ceil_to_multiple(&self, step: &Self) -> Option<Self> {
+        self.quantize_to_multiple(step, Ceil::ceil)
+    }
+
+    pub fn round_to_digits(
+        &self,
+        digits_count: isize,
+        base: usize,
+        tie_breaking: TieBreaking,
+    ) -> Option<Self> {
+        self.quantize_to_digits(digits_count, base, |value| {
+            value.round(tie_breaking)
+        })
+    }
+
+    pub fn floor_to_digits(
+        &self,
+        digits_count: isize,
+        base: usize,
+    ) -> Option<Self> {
+        self.quantize_to_digits(digits_count, base, Floor::floor)
+    }
+
+    pub fn ceil_to_digits(
+        &self,
+        digits_count: isize,
+        base: usize,
+    ) -> Option<Self> {
+        self.quantize_to_digits(digits_count, base, Ceil::ceil)
+    }
+
+    fn quantize_to_multiple(
+        &self,
+        step: &Self,
+        quantizer: impl FnOnce(Self) -> BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Option<Self> {
+        let step = step.abs();
+        let quotient = self.checked_div(&step)?;
+        Some(quantizer(quotient) * step)
+    }
+
+    fn quantize_to_digits(
+        &self,
+        digits_count: isize,
+        base: usize,
+        quantizer: impl FnOnce(Self) -> BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Option<Self> {
+        if base < 2 {
+            return None;
+        }
+        let scale = BigInt::<Digit, DIGIT_BITNESS>::from(base)
+            .pow(BigInt::from(digits_count.unsigned_abs()));
+        Some(if digits_count.is_negative() {
+            Self::from(quantizer(self / &scale) * scale)
+        } else {
+            let (numerator, denominator) =
+                quantizer(self * &scale).normalize_moduli(scale);
+            Self {
+                numerator,
+                denominator,
+            }
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use serde::ser::SerializeTuple;
+use serde::{Serialize, Serializer};
+use traiter::numbers::Unitary;
+
+use super::types::Fraction;
+
+impl<Component: Display + Serialize> Serialize for Fraction<Component>
+where
+    for<'a> &'a Component: Unitary,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.numerator)?;
+            tuple.serialize_element(&self.denominator)?;
+            tuple.end()
+        }
+    }
+}
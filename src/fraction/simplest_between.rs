@@ -0,0 +1,104 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::{DivRemEuclid, One, Zero, Zeroable};
+
+use super::types::Fraction;
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + DivRemEuclid<Output = (Component, Component)>
+            + Mul<Output = Component>
+            + One
+            + Ord
+            + Sub<Output = Component>
+            + Zero,
+    > Fraction<Component>
+where
+    for<'a> &'a Component: Zeroable,
+    Self: Ord,
+{
+    pub fn simplest_between(&self, other: &Self) -> Option<Self> {
+        let (lower, upper) = match self.cmp(other) {
+            Ordering::Less => (self, other),
+            Ordering::Equal => return None,
+            Ordering::Greater => (other, self),
+        };
+        Some(if lower.numerator < Component::zero() {
+            if upper.numerator > Component::zero() {
+                Self {
+                    numerator: Component::zero(),
+                    denominator: Component::one(),
+                }
+            } else {
+                let (numerator, denominator) = Self::simplest_positive_between(
+                    Component::zero() - upper.numerator.clone(),
+                    upper.denominator.clone(),
+                    Component::zero() - lower.numerator.clone(),
+                    lower.denominator.clone(),
+                );
+                Self {
+                    numerator: Component::zero() - numerator,
+                    denominator,
+                }
+            }
+        } else {
+            let (numerator, denominator) = Self::simplest_positive_between(
+                lower.numerator.clone(),
+                lower.denominator.clone(),
+                upper.numerator.clone(),
+                upper.denominator.clone(),
+            );
+            Self {
+                numerator,
+                denominator,
+            }
+        })
+    }
+
+    fn simplest_positive_between(
+        mut lower_numerator: Component,
+        mut lower_denominator: Component,
+        mut upper_numerator: Component,
+        mut upper_denominator: Component,
+    ) -> (Component, Component) {
+        let (mut previous_numerator, mut previous_denominator) =
+            (Component::zero(), Component::one());
+        let (mut numerator, mut denominator) =
+            (Component::one(), Component::zero());
+        loop {
+            let term = lower_numerator
+                .clone()
+                .div_rem_euclid(lower_denominator.clone())
+                .0;
+            let next_term = term.clone() + Component::one();
+            let is_last = upper_denominator.is_zero()
+                || next_term.clone() * upper_denominator.clone()
+                    < upper_numerator;
+            let term = if is_last { next_term } else { term };
+            (previous_numerator, numerator) = (
+                numerator.clone(),
+                term.clone() * numerator + previous_numerator,
+            );
+            (previous_denominator, denominator) = (
+                denominator.clone(),
+                term.clone() * denominator + previous_denominator,
+            );
+            if is_last {
+                break (numerator, denominator);
+            }
+            (
+                lower_numerator,
+                lower_denominator,
+                upper_numerator,
+                upper_denominator,
+            ) = (
+                upper_denominator.clone(),
+                upper_numerator - term.clone() * upper_denominator,
+                lower_denominator.clone(),
+                lower_numerator - term * lower_denominator,
+            );
+        }
+    }
+}
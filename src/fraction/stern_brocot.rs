@@ -0,0 +1,103 @@
+use std::ops::{Add, Mul, Sub};
+
+use traiter::numbers::{DivRemEuclid, One, Zero, Zeroable};
+
+use super::types::{Fraction, SternBrocotDirection, SternBrocotPath};
+
+impl<
+        Component: Clone
+            + DivRemEuclid<Output = (Component, Component)>
+            + PartialOrd
+            + Zero,
+    > Fraction<Component>
+where
+    for<'a> &'a Component: Zeroable,
+{
+    pub fn stern_brocot_path(&self) -> Option<SternBrocotPath<Component>> {
+        if self.numerator <= Component::zero() {
+            return None;
+        }
+        let mut terms = self.continued_fraction_terms();
+        let term = terms.next();
+        Some(SternBrocotPath {
+            terms,
+            term,
+            direction: SternBrocotDirection::Right,
+        })
+    }
+}
+
+impl<
+        Component: Add<Output = Component>
+            + Clone
+            + Mul<Output = Component>
+            + One
+            + PartialOrd
+            + Zero,
+    > Fraction<Component>
+{
+    pub fn from_stern_brocot_path<Path>(path: Path) -> Option<Self>
+    where
+        Path: IntoIterator<Item = (SternBrocotDirection, Component)>,
+    {
+        let (mut left_numerator, mut left_denominator) =
+            (Component::zero(), Component::one());
+        let (mut right_numerator, mut right_denominator) =
+            (Component::one(), Component::zero());
+        for (direction, steps_count) in path {
+            if steps_count < Component::zero() {
+                return None;
+            }
+            match direction {
+                SternBrocotDirection::Left => {
+                    right_numerator = right_numerator
+                        + steps_count.clone() * left_numerator.clone();
+                    right_denominator = right_denominator
+                        + steps_count * left_denominator.clone();
+                }
+                SternBrocotDirection::Right => {
+                    left_numerator = left_numerator
+                        + steps_count.clone() * right_numerator.clone();
+                    left_denominator = left_denominator
+                        + steps_count * right_denominator.clone();
+                }
+            }
+        }
+        Some(Self {
+            numerator: left_numerator + right_numerator,
+            denominator: left_denominator + right_denominator,
+        })
+    }
+}
+
+impl<
+        Component: Clone
+            + DivRemEuclid<Output = (Component, Component)>
+            + One
+            + Sub<Output = Component>,
+    > Iterator for SternBrocotPath<Component>
+where
+    for<'a> &'a Component: Zeroable,
+{
+    type Item = (SternBrocotDirection, Component);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let term = self.term.take()?;
+            self.term = self.terms.next();
+            let direction = self.direction;
+            self.direction = match direction {
+                SternBrocotDirection::Left => SternBrocotDirection::Right,
+                SternBrocotDirection::Right => SternBrocotDirection::Left,
+            };
+            let steps_count = if self.term.is_none() {
+                term - Component::one()
+            } else {
+                term
+            };
+            if !(&steps_count).is_zero() {
+                return Some((direction, steps_count));
+            }
+        }
+    }
+}
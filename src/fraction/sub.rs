@@ -1,4 +1,6 @@
-use std::ops::{Mul, Sub};
+use std::ops::{Div, Mul, Sub};
+
+use traiter::numbers::Gcd;
 
 use crate::big_int::BigInt;
 
@@ -7,30 +9,30 @@ use super::types::{Fraction, NormalizeModuli};
 impl<Digit, const DIGIT_BITNESS: usize> Sub
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<
-        BigInt<Digit, DIGIT_BITNESS>,
-        Output = BigInt<Digit, DIGIT_BITNESS>,
-    >,
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            &'a BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        > + NormalizeModuli<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+        >,
 {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
-        let (numerator, denominator) = (self.numerator * &other.denominator
-            - &self.denominator * other.numerator)
-            .normalize_moduli(self.denominator * other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            - &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -38,28 +40,30 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub<&Self>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            &'a BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        > + NormalizeModuli<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
-        Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+        >,
 {
     type Output = Self;
 
     fn sub(self, other: &Self) -> Self::Output {
-        let (numerator, denominator) = (self.numerator * &other.denominator
-            - &self.denominator * &other.numerator)
-            .normalize_moduli(self.denominator * &other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            - &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -68,17 +72,17 @@ impl<Digit, const DIGIT_BITNESS: usize>
     Sub<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
-        >,
-    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+        >,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
@@ -86,12 +90,15 @@ where
         self,
         other: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
     ) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator * &other.denominator
-            - &self.denominator * other.numerator)
-            .normalize_moduli(&self.denominator * other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            - &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -99,24 +106,30 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
-        Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+        >,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
     fn sub(self, other: Self) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator * &other.denominator
-            - &self.denominator * &other.numerator)
-            .normalize_moduli(&self.denominator * &other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            - &other.numerator * &denominator)
+            .normalize_moduli(gcd);
         Self::Output {
             numerator,
-            denominator,
+            denominator: denominator * other_denominator * gcd,
         }
     }
 }
@@ -128,22 +141,14 @@ where
         BigInt<Digit, DIGIT_BITNESS>,
         Output = BigInt<Digit, DIGIT_BITNESS>,
     >,
-    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-            Output = (
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Self;
 
     fn sub(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (self.numerator
-            - &self.denominator * other)
-            .normalize_moduli(self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self.numerator - &self.denominator * other,
+            denominator: self.denominator,
         }
     }
 }
@@ -151,24 +156,16 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub<&BigInt<Digit, DIGIT_BITNESS>>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-            Output = (
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
         Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Self;
 
     fn sub(self, other: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (self.numerator
-            - &self.denominator * other)
-            .normalize_moduli(self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self.numerator - &self.denominator * other,
+            denominator: self.denominator,
         }
     }
 }
@@ -176,27 +173,21 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub<BigInt<Digit, DIGIT_BITNESS>>
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Sub<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
-        > + Sub<
+        > + Mul<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
         >,
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
     fn sub(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator
-            - &self.denominator * other)
-            .normalize_moduli(&self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: &self.numerator - &self.denominator * other,
+            denominator: self.denominator.clone(),
         }
     }
 }
@@ -204,25 +195,18 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub<&BigInt<Digit, DIGIT_BITNESS>>
     for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Sub<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Sub<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
-        >,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
     fn sub(self, other: &BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
-        let (numerator, denominator) = (&self.numerator
-            - &self.denominator * other)
-            .normalize_moduli(&self.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: &self.numerator - &self.denominator * other,
+            denominator: self.denominator.clone(),
         }
     }
 }
@@ -230,19 +214,14 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub<Fraction<Self>>
     for BigInt<Digit, DIGIT_BITNESS>
 where
-    for<'a> Self: Mul<&'a Self, Output = Self>
-        + NormalizeModuli<Output = (Self, Self)>
-        + Sub<Output = Self>,
+    for<'a> Self: Mul<&'a Self, Output = Self> + Sub<Output = Self>,
 {
     type Output = Fraction<Self>;
 
     fn sub(self, other: Fraction<Self>) -> Self::Output {
-        let (numerator, denominator) = (self * &other.denominator
-            - other.numerator)
-            .normalize_moduli(other.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self * &other.denominator - other.numerator,
+            denominator: other.denominator,
         }
     }
 }
@@ -250,19 +229,15 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Sub<&Fraction<Self>>
     for BigInt<Digit, DIGIT_BITNESS>
 where
-    for<'a> Self: Mul<&'a Self, Output = Self>
-        + NormalizeModuli<&'a Self, Output = (Self, Self)>
-        + Sub<&'a Self, Output = Self>,
+    for<'a> Self:
+        Clone + Mul<&'a Self, Output = Self> + Sub<&'a Self, Output = Self>,
 {
     type Output = Fraction<Self>;
 
     fn sub(self, other: &Fraction<Self>) -> Self::Output {
-        let (numerator, denominator) = (self * &other.denominator
-            - &other.numerator)
-            .normalize_moduli(&other.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self * &other.denominator - &other.numerator,
+            denominator: other.denominator.clone(),
         }
     }
 }
@@ -273,12 +248,7 @@ impl<Digit, const DIGIT_BITNESS: usize>
 where
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
         Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
-    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-            Output = (
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
 
@@ -286,12 +256,9 @@ where
         self,
         other: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
     ) -> Self::Output {
-        let (numerator, denominator) = (self * &other.denominator
-            - other.numerator)
-            .normalize_moduli(other.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self * &other.denominator - other.numerator,
+            denominator: other.denominator,
         }
     }
 }
@@ -302,13 +269,8 @@ impl<Digit, const DIGIT_BITNESS: usize>
 where
     for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
         Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-            &'a BigInt<Digit, DIGIT_BITNESS>,
-            Output = (
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            ),
-        > + Sub<
+    for<'a> BigInt<Digit, DIGIT_BITNESS>: Clone
+        + Sub<
             &'a BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
         >,
@@ -319,12 +281,9 @@ where
         self,
         other: &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
     ) -> Self::Output {
-        let (numerator, denominator) = (self * &other.denominator
-            - &other.numerator)
-            .normalize_moduli(&other.denominator);
         Self::Output {
-            numerator,
-            denominator,
+            numerator: self * &other.denominator - &other.numerator,
+            denominator: other.denominator.clone(),
         }
     }
 }
@@ -335,13 +294,15 @@ macro_rules! integer_fraction_sub_impl {
             type Output = Self;
 
             fn sub(self, other: Self) -> Self::Output {
-                let (numerator, denominator) = (self.numerator
-                    * other.denominator
-                    - self.denominator * other.numerator)
-                    .normalize_moduli(self.denominator * other.denominator);
+                let gcd = self.denominator.gcd(other.denominator);
+                let (denominator, other_denominator) =
+                    (self.denominator / gcd, other.denominator / gcd);
+                let (numerator, gcd) = (self.numerator * other_denominator
+                    - other.numerator * denominator)
+                    .normalize_moduli(gcd);
                 Self::Output {
                     numerator,
-                    denominator,
+                    denominator: denominator * other_denominator * gcd,
                 }
             }
         }
@@ -350,12 +311,9 @@ macro_rules! integer_fraction_sub_impl {
             type Output = Self;
 
             fn sub(self, other: $integer) -> Self::Output {
-                let (numerator, denominator) = (self.numerator
-                    - self.denominator * other)
-                    .normalize_moduli(self.denominator);
                 Self::Output {
-                    numerator,
-                    denominator,
+                    numerator: self.numerator - self.denominator * other,
+                    denominator: self.denominator,
                 }
             }
         }
@@ -364,12 +322,9 @@ macro_rules! integer_fraction_sub_impl {
             type Output = Fraction<Self>;
 
             fn sub(self, other: Fraction<Self>) -> Self::Output {
-                let (numerator, denominator) = (self * other.denominator
-                    - other.numerator)
-                    .normalize_moduli(other.denominator);
                 Self::Output {
-                    numerator,
-                    denominator,
+                    numerator: self * other.denominator - other.numerator,
+                    denominator: other.denominator,
                 }
             }
         }
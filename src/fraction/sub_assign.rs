@@ -1,4 +1,6 @@
-use std::ops::{Mul, Sub, SubAssign};
+use std::ops::{Div, Mul, Sub, SubAssign};
+
+use traiter::numbers::Gcd;
 
 use crate::big_int::BigInt;
 
@@ -7,65 +9,70 @@ use super::types::{Fraction, NormalizeModuli};
 impl<Digit, const DIGIT_BITNESS: usize> SubAssign
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Mul<
-            BigInt<Digit, DIGIT_BITNESS>,
-            Output = BigInt<Digit, DIGIT_BITNESS>,
         >,
 {
     fn sub_assign(&mut self, other: Self) {
-        (self.numerator, self.denominator) = (&self.numerator
-            * &other.denominator
-            - &self.denominator * other.numerator)
-            .normalize_moduli(&self.denominator * other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            - &other.numerator * &denominator)
+            .normalize_moduli(gcd);
+        (self.numerator, self.denominator) =
+            (numerator, denominator * other_denominator * gcd);
     }
 }
 
 impl<Digit, const DIGIT_BITNESS: usize> SubAssign<&Self>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
             Output = (
                 BigInt<Digit, DIGIT_BITNESS>,
                 BigInt<Digit, DIGIT_BITNESS>,
             ),
-        > + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>:
-        Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+        >,
 {
     fn sub_assign(&mut self, other: &Self) {
-        (self.numerator, self.denominator) = (&self.numerator
-            * &other.denominator
-            - &self.denominator * &other.numerator)
-            .normalize_moduli(&self.denominator * &other.denominator);
+        let gcd = (&self.denominator).gcd(&other.denominator);
+        let (denominator, other_denominator) =
+            (&self.denominator / &gcd, &other.denominator / &gcd);
+        let (numerator, gcd) = (&self.numerator * &other_denominator
+            - &other.numerator * &denominator)
+            .normalize_moduli(gcd);
+        (self.numerator, self.denominator) =
+            (numerator, denominator * other_denominator * gcd);
     }
 }
 
 impl<Digit, const DIGIT_BITNESS: usize> SubAssign<BigInt<Digit, DIGIT_BITNESS>>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Sub<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
-        > + Sub<
+        > + Mul<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
         >,
 {
     fn sub_assign(&mut self, other: BigInt<Digit, DIGIT_BITNESS>) {
-        (self.numerator, self.denominator) = (&self.numerator
-            - &self.denominator * other)
-            .normalize_moduli(&self.denominator);
+        self.numerator = &self.numerator - &self.denominator * other;
     }
 }
 
@@ -73,20 +80,13 @@ impl<Digit, const DIGIT_BITNESS: usize>
     SubAssign<&BigInt<Digit, DIGIT_BITNESS>>
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
-        &'a BigInt<Digit, DIGIT_BITNESS>,
-        Output = (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>),
-    >,
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + Sub<
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Sub<
             BigInt<Digit, DIGIT_BITNESS>,
             Output = BigInt<Digit, DIGIT_BITNESS>,
-        >,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     fn sub_assign(&mut self, other: &BigInt<Digit, DIGIT_BITNESS>) {
-        (self.numerator, self.denominator) = (&self.numerator
-            - &self.denominator * other)
-            .normalize_moduli(&self.denominator);
+        self.numerator = &self.numerator - &self.denominator * other;
     }
 }
 
@@ -94,18 +94,20 @@ macro_rules! integer_fraction_sub_assign_impl {
     ($($integer:ty)*) => ($(
         impl SubAssign for Fraction<$integer> {
             fn sub_assign(&mut self, other: Self) {
-                (self.numerator, self.denominator) = (self.numerator
-                    * other.denominator
-                    - self.denominator * other.numerator)
-                    .normalize_moduli(self.denominator * other.denominator);
+                let gcd = self.denominator.gcd(other.denominator);
+                let (denominator, other_denominator) =
+                    (self.denominator / gcd, other.denominator / gcd);
+                let (numerator, gcd) = (self.numerator * other_denominator
+                    - other.numerator * denominator)
+                    .normalize_moduli(gcd);
+                (self.numerator, self.denominator) =
+                    (numerator, denominator * other_denominator * gcd);
             }
         }
 
         impl SubAssign<$integer> for Fraction<$integer> {
             fn sub_assign(&mut self, other: $integer) {
-                (self.numerator, self.denominator) = (self.numerator
-                    - self.denominator * other)
-                    .normalize_moduli(self.denominator);
+                self.numerator = self.numerator - self.denominator * other;
             }
         }
     )*)
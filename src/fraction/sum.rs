@@ -0,0 +1,92 @@
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul};
+
+use traiter::numbers::{Gcd, One, Zero};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, NormalizeModuli};
+
+impl<Digit, const DIGIT_BITNESS: usize> Sum
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Zero,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let (numerator, denominator) = iter.fold(
+            (BigInt::zero(), BigInt::one()),
+            |(numerator, denominator), value| {
+                add_over_common_denominator(numerator, denominator, &value)
+            },
+        );
+        let (numerator, denominator) = numerator.normalize_moduli(denominator);
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl<'a, Digit: 'a, const DIGIT_BITNESS: usize> Sum<&'a Self>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'b> &'b BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Zero,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let (numerator, denominator) = iter.fold(
+            (BigInt::zero(), BigInt::one()),
+            |(numerator, denominator), value| {
+                add_over_common_denominator(numerator, denominator, value)
+            },
+        );
+        let (numerator, denominator) = numerator.normalize_moduli(denominator);
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+fn add_over_common_denominator<Digit, const DIGIT_BITNESS: usize>(
+    numerator: BigInt<Digit, DIGIT_BITNESS>,
+    denominator: BigInt<Digit, DIGIT_BITNESS>,
+    value: &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+) -> (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>)
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    let gcd = (&denominator).gcd(&value.denominator);
+    let value_denominator_cofactor = &value.denominator / &gcd;
+    let denominator_cofactor = &denominator / &gcd;
+    (
+        &numerator * &value_denominator_cofactor
+            + &value.numerator * &denominator_cofactor,
+        denominator * value_denominator_cofactor,
+    )
+}
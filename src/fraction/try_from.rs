@@ -134,3 +134,47 @@ macro_rules! try_integer_fraction_from_float_impl {
 
 try_integer_fraction_from_float_impl!(f32 => i8 i16 i32 i64 u8 u16 u32 u64);
 try_integer_fraction_from_float_impl!(f64 => i8 i16 i32 i64 u8 u16 u32 u64);
+
+macro_rules! try_integer_fraction_from_big_int_fraction_impl {
+    ($($integer:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            TryFrom<Fraction<BigInt<Digit, DIGIT_BITNESS>>> for Fraction<$integer>
+        where
+            $integer: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            type Error = <$integer as TryFrom<BigInt<Digit, DIGIT_BITNESS>>>::Error;
+
+            fn try_from(
+                value: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+            ) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    numerator: <$integer>::try_from(value.numerator)?,
+                    denominator: <$integer>::try_from(value.denominator)?,
+                })
+            }
+        }
+
+        impl<'a, Digit, const DIGIT_BITNESS: usize>
+            TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+            for Fraction<$integer>
+        where
+            $integer: TryFrom<&'a BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            type Error =
+                <$integer as TryFrom<&'a BigInt<Digit, DIGIT_BITNESS>>>::Error;
+
+            fn try_from(
+                value: &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+            ) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    numerator: <$integer>::try_from(&value.numerator)?,
+                    denominator: <$integer>::try_from(&value.denominator)?,
+                })
+            }
+        }
+    )*)
+}
+
+try_integer_fraction_from_big_int_fraction_impl!(
+    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+);
@@ -0,0 +1,66 @@
+use std::convert::TryFrom;
+use std::ops::Shl;
+
+use traiter::numbers::{
+    Abs, BitLength, CheckedDivRemEuclid, LoadExp, Signed, Zeroable,
+};
+
+use crate::big_int::{
+    div_moduli_as_float_parts, BigInt, FloatOverflow, FloatRounding,
+    TryIntoFloatError,
+};
+
+use super::types::Fraction;
+
+macro_rules! big_int_fraction_try_to_float_impl {
+    ($($float:ty => $method:ident)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + CheckedDivRemEuclid<
+                    Output = Option<(
+                        BigInt<Digit, DIGIT_BITNESS>,
+                        BigInt<Digit, DIGIT_BITNESS>,
+                    )>,
+                > + Signed
+                + Zeroable,
+            BigInt<Digit, DIGIT_BITNESS>: Clone
+                + Ord
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+            u64: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+            usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            pub fn $method(
+                &self,
+                rounding: FloatRounding,
+                overflow: FloatOverflow,
+            ) -> Result<$float, TryIntoFloatError> {
+                let is_negative = (&self.numerator).is_negative();
+                let sign = if is_negative { -1.0 } else { 1.0 };
+                div_moduli_as_float_parts(
+                    &(&self.numerator).abs(),
+                    &self.denominator,
+                    is_negative,
+                    <$float>::MANTISSA_DIGITS,
+                    <$float>::MIN_EXP,
+                    <$float>::MAX_EXP,
+                    rounding,
+                    overflow,
+                )
+                .map(|parts| match parts {
+                    Some((mantissa, exponent)) => {
+                        sign * (mantissa as $float).load_exp(exponent)
+                    }
+                    None => sign * <$float>::INFINITY,
+                })
+            }
+        }
+    )*)
+}
+
+big_int_fraction_try_to_float_impl!(
+    f32 => try_to_f32_rounded
+    f64 => try_to_f64_rounded
+);
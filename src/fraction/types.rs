@@ -1,9 +1,34 @@
+use std::error::Error;
 use std::fmt;
 use std::ops::Neg;
 
 use traiter::numbers::{CheckedDiv, Gcd, Signed, Zeroable};
 
-use crate::big_int::BigInt;
+use crate::big_int::{BigInt, FromVarintError};
+
+#[derive(Clone)]
+pub struct ContinuedFractionTerms<Component> {
+    pub(super) dividend: Component,
+    pub(super) divisor: Component,
+}
+
+#[derive(Clone)]
+pub struct Convergents<Component> {
+    pub(super) terms: ContinuedFractionTerms<Component>,
+    pub(super) previous_numerator: Component,
+    pub(super) previous_denominator: Component,
+    pub(super) numerator: Component,
+    pub(super) denominator: Component,
+}
+
+#[derive(Clone)]
+pub struct FareySequence<Component> {
+    pub(super) order: Component,
+    pub(super) numerator: Component,
+    pub(super) denominator: Component,
+    pub(super) next_numerator: Component,
+    pub(super) next_denominator: Component,
+}
 
 pub struct Fraction<Component> {
     pub(super) numerator: Component,
@@ -24,6 +49,13 @@ impl<Component: Clone> Clone for Fraction<Component> {
     }
 }
 
+#[derive(Clone)]
+pub struct FractionAccumulator<Component> {
+    pub(super) numerator: Component,
+    pub(super) denominator: Component,
+    pub(super) normalization_bitness: usize,
+}
+
 impl<
         Component: NormalizeModuli<Output = (Component, Component)>
             + NormalizeSign<Output = (Component, Component)>,
@@ -50,6 +82,10 @@ where
     }
 }
 
+/// Exact fallback for overflowing operations on fractions with components
+/// which have no wider primitive type to compute intermediate results in.
+pub(super) type PromotedFraction = Fraction<BigInt<u32, 31>>;
+
 impl<Component> Fraction<Component> {
     pub fn denominator(&self) -> &Component {
         &self.denominator
@@ -149,7 +185,28 @@ where
     }
 }
 
-macro_rules! integer_normalize_moduli_impl {
+macro_rules! signed_integer_normalize_moduli_impl {
+    ($($integer:ty)*) => ($(
+        impl NormalizeModuli for $integer {
+            type Output = (Self, Self);
+
+            #[inline]
+            fn normalize_moduli(self, other: Self) -> Self::Output {
+                // the only gcd out of range is the modulus of `MIN`,
+                // which wraps into `MIN` and still divides exactly
+                let gcd = self.unsigned_abs().gcd(other.unsigned_abs()) as Self;
+                (
+                    unsafe { self.checked_div(gcd).unwrap_unchecked() },
+                    unsafe { other.checked_div(gcd).unwrap_unchecked() },
+                )
+            }
+        }
+    )*)
+}
+
+signed_integer_normalize_moduli_impl!(i8 i16 i32 i64 i128 isize);
+
+macro_rules! unsigned_integer_normalize_moduli_impl {
     ($($integer:ty)*) => ($(
         impl NormalizeModuli for $integer {
             type Output = (Self, Self);
@@ -166,9 +223,7 @@ macro_rules! integer_normalize_moduli_impl {
     )*)
 }
 
-integer_normalize_moduli_impl!(
-    i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
-);
+unsigned_integer_normalize_moduli_impl!(u8 u16 u32 u64 u128 usize);
 
 pub trait NormalizeSign<Other = Self> {
     type Output;
@@ -236,7 +291,7 @@ pub enum FromFloatConstructionError {
 }
 
 impl FromFloatConstructionError {
-    fn description(self) -> &'static str {
+    fn description(&self) -> &'static str {
         match self {
             FromFloatConstructionError::Infinity => {
                 "Construction from infinity is undefined."
@@ -262,3 +317,92 @@ impl fmt::Display for FromFloatConstructionError {
         fmt::Display::fmt(&self.description(), formatter)
     }
 }
+
+impl Error for FromFloatConstructionError {}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromVarintPairError {
+    Component(FromVarintError),
+    ZeroDenominator,
+}
+
+impl fmt::Debug for FromVarintPairError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl fmt::Display for FromVarintPairError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromVarintPairError::Component(error) => {
+                fmt::Display::fmt(error, formatter)
+            }
+            FromVarintPairError::ZeroDenominator => {
+                formatter.write_str("Denominator should not be zero.")
+            }
+        }
+    }
+}
+
+impl Error for FromVarintPairError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FromVarintPairError::Component(error) => Some(error),
+            FromVarintPairError::ZeroDenominator => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SternBrocotDirection {
+    Left,
+    Right,
+}
+
+#[derive(Clone)]
+pub struct SternBrocotPath<Component> {
+    pub(super) terms: ContinuedFractionTerms<Component>,
+    pub(super) term: Option<Component>,
+    pub(super) direction: SternBrocotDirection,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TryFromStringError<ComponentError> {
+    Component(ComponentError),
+    ZeroDenominator,
+}
+
+impl<ComponentError: fmt::Display> fmt::Debug
+    for TryFromStringError<ComponentError>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl<ComponentError: fmt::Display> fmt::Display
+    for TryFromStringError<ComponentError>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryFromStringError::Component(error) => {
+                fmt::Display::fmt(error, formatter)
+            }
+            TryFromStringError::ZeroDenominator => {
+                formatter.write_str("Denominator should not be zero.")
+            }
+        }
+    }
+}
+
+impl<ComponentError: Error + 'static> Error
+    for TryFromStringError<ComponentError>
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TryFromStringError::Component(error) => Some(error),
+            TryFromStringError::ZeroDenominator => None,
+        }
+    }
+}
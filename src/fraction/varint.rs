@@ -0,0 +1,53 @@
+use traiter::numbers::{FromBytes, ToBytes, Zeroable};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, FromVarintPairError, NormalizeModuli};
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: ToBytes<Output = Vec<u8>>,
+{
+    pub fn to_varint_pair(&self) -> Vec<u8> {
+        let mut result = self.numerator.to_zigzag_varint();
+        result.extend(unsafe {
+            self.denominator.to_unsigned_leb128().unwrap_unchecked()
+        });
+        result
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: FromBytes
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        >,
+{
+    pub fn from_varint_pair(
+        bytes: &[u8],
+    ) -> Result<(Self, usize), FromVarintPairError> {
+        let (numerator, numerator_size) = BigInt::from_zigzag_varint(bytes)
+            .map_err(FromVarintPairError::Component)?;
+        let (denominator, denominator_size) =
+            BigInt::from_unsigned_leb128(&bytes[numerator_size..])
+                .map_err(FromVarintPairError::Component)?;
+        if (&denominator).is_zero() {
+            Err(FromVarintPairError::ZeroDenominator)
+        } else {
+            let (numerator, denominator) =
+                numerator.normalize_moduli(denominator);
+            Ok((
+                Self {
+                    numerator,
+                    denominator,
+                },
+                numerator_size + denominator_size,
+            ))
+        }
+    }
+}
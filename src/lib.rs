@@ -1,10 +1,15 @@
 pub mod big_int;
 mod constants;
 mod contracts;
+mod error;
 pub mod fraction;
 #[cfg(feature = "python_binding")]
 mod python_binding;
+pub mod rational;
 mod traits;
 
+pub use self::error::Error;
+pub use self::traits::{CheckedRoot, NumericHash};
+
 #[doc = include_str!("../README.md")]
 type _DoctestReadme = ();
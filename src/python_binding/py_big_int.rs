@@ -2,9 +2,9 @@ use super::py_endianness::PyEndianness;
 use super::py_fraction::{Fraction, PyFraction};
 use super::utils::{
     compare, try_divmod, try_floordiv, try_lshift, try_mod, try_rshift,
-    HASH_BITS, HASH_MODULUS,
 };
 use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
+use crate::traits::NumericHash;
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::{
     PyMemoryError, PyOverflowError, PyValueError, PyZeroDivisionError,
@@ -23,12 +23,12 @@ use std::convert::TryFrom;
 use traiter::numbers::{
     Abs, BitLength, CheckedDivRemEuclid, CheckedPow, CheckedPowRemEuclid,
     CheckedShl, Endianness, FromBytes, FromStrRadix, Gcd, IsPowerOfTwo, One,
-    Parity, Signed, ToBytes, Unitary, Zero, Zeroable,
+    Parity, Signed, ToBytes, Zero, Zeroable,
 };
 
-#[cfg(target_arch = "x86")]
+#[cfg(target_pointer_width = "32")]
 type Digit = u16;
-#[cfg(not(target_arch = "x86"))]
+#[cfg(target_pointer_width = "64")]
 type Digit = u32;
 
 const DIGIT_BITNESS: usize = (Digit::BITS - 1u32) as usize;
@@ -231,7 +231,7 @@ impl PyBigInt {
     }
 
     fn __hash__(&self) -> isize {
-        hash(&self.0) as isize
+        self.0.numeric_hash()
     }
 
     fn __index__<'py>(&self, py: Python<'py>) -> Bound<'py, PyAny> {
@@ -438,6 +438,12 @@ impl PyBigInt {
                 .into_bound()
                 .into_any()
                 .unbind())
+        } else if let Ok(other) = other.cast::<PyFloat>() {
+            Ok(compare(&self.0, &other.value(), op)
+                .into_pyobject(py)?
+                .into_bound()
+                .into_any()
+                .unbind())
         } else {
             Ok(py.NotImplemented())
         }
@@ -736,41 +742,6 @@ pub(super) fn try_truediv(
     })
 }
 
-fn hash(value: &BigInt) -> usize {
-    if value.digits().len() == 1usize {
-        return if value.is_negative() {
-            usize::MAX
-                - unsafe {
-                    usize::try_from(
-                        value.digits()[0]
-                            + Digit::from(value.digits()[0].is_one()),
-                    )
-                    .unwrap_unchecked()
-                }
-                + 1usize
-        } else {
-            unsafe { usize::try_from(value.digits()[0]).unwrap_unchecked() }
-        };
-    };
-    let mut result = 0usize;
-    for &position in value.digits().iter().rev() {
-        result = ((result << DIGIT_BITNESS) & HASH_MODULUS)
-            | (result >> (HASH_BITS - DIGIT_BITNESS));
-        result += unsafe { usize::try_from(position).unwrap_unchecked() };
-        if result >= HASH_MODULUS {
-            result -= HASH_MODULUS;
-        }
-    }
-    if value.is_negative() {
-        result = usize::MAX - result + 1usize;
-    };
-    if result == usize::MAX {
-        result - 1usize
-    } else {
-        result
-    }
-}
-
 #[inline]
 fn pow_non_negative_exponent(base: &BigInt, exponent: &BigInt) -> BigInt {
     debug_assert!(!exponent.is_negative());
@@ -3,23 +3,23 @@ use super::py_big_int::{
     try_big_int_from_py_integral, try_truediv, BigInt, PyBigInt,
 };
 use super::py_tie_breaking::PyTieBreaking;
-use super::utils::{compare, try_divmod, HASH_INF, HASH_MODULUS};
+use super::utils::{compare, try_divmod};
 use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
+use crate::traits::NumericHash;
 use pyo3::basic::CompareOp;
 use pyo3::exceptions::{
     PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError,
 };
 use pyo3::prelude::{PyAnyMethods, PyFloatMethods};
-use pyo3::types::{PyFloat, PyInt, PyTuple, PyTypeMethods};
+use pyo3::types::{PyFloat, PyInt, PyTuple, PyType, PyTypeMethods};
 use pyo3::{
     intern, pyclass, pymethods, Bound, BoundObject, IntoPyObject, Py, PyAny,
     PyRef, PyResult, PyTypeInfo, Python,
 };
 use std::convert::TryFrom;
 use traiter::numbers::{
-    Abs, Ceil, CheckedDiv, CheckedDivEuclid, CheckedPow, CheckedPowRemEuclid,
-    CheckedRemEuclid, Floor, Round, Signed, TieBreaking, Trunc, Unitary, Zero,
-    Zeroable,
+    Abs, Ceil, CheckedDiv, CheckedDivEuclid, CheckedPow, CheckedRemEuclid,
+    Floor, Pow, Round, Signed, TieBreaking, Trunc, Zero,
 };
 
 pub(super) type Fraction = crate::fraction::Fraction<BigInt>;
@@ -62,6 +62,26 @@ impl PyFraction {
         }
     }
 
+    #[classmethod]
+    #[pyo3(signature = (terms, /))]
+    fn from_continued_fraction_terms(
+        _cls: &Bound<'_, PyType>,
+        terms: &Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        let terms = terms
+            .try_iter()?
+            .map(|term| {
+                term.and_then(|term| try_big_int_from_py_integral(&term))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        match Fraction::from_continued_fraction_terms(terms) {
+            Some(value) => Ok(PyFraction(value)),
+            None => Err(PyZeroDivisionError::new_err(
+                UNDEFINED_DIVISION_ERROR_MESSAGE,
+            )),
+        }
+    }
+
     #[getter]
     fn denominator(&self) -> PyBigInt {
         PyBigInt(self.0.denominator().clone())
@@ -72,6 +92,30 @@ impl PyFraction {
         PyBigInt(self.0.numerator().clone())
     }
 
+    fn continued_fraction_terms(&self) -> Vec<PyBigInt> {
+        self.0.continued_fraction_terms().map(PyBigInt).collect()
+    }
+
+    fn convergents(&self) -> Vec<PyFraction> {
+        self.0.convergents().map(PyFraction).collect()
+    }
+
+    #[pyo3(signature = (max_denominator, /))]
+    fn limit_denominator(
+        &self,
+        max_denominator: &Bound<'_, PyAny>,
+    ) -> PyResult<Self> {
+        match self
+            .0
+            .limit_denominator(try_big_int_from_py_integral(max_denominator)?)
+        {
+            Some(value) => Ok(Self(value)),
+            None => Err(PyValueError::new_err(
+                "Maximum denominator should be positive.",
+            )),
+        }
+    }
+
     #[pyo3(signature = (tie_breaking, /))]
     fn round(&self, tie_breaking: &PyTieBreaking) -> PyBigInt {
         PyBigInt((&self.0).round(tie_breaking.clone().into()))
@@ -194,36 +238,7 @@ impl PyFraction {
     }
 
     fn __hash__(&self) -> isize {
-        let inverted_denominator = unsafe {
-            self.0
-                .denominator()
-                .checked_pow_rem_euclid(
-                    BigInt::from(HASH_MODULUS - 2usize),
-                    BigInt::from(HASH_MODULUS),
-                )
-                .unwrap_unchecked()
-        };
-        let result = if (&inverted_denominator).is_zero() {
-            HASH_INF
-        } else {
-            unsafe {
-                isize::try_from(
-                    (self.0.numerator().abs() * inverted_denominator)
-                        .checked_rem_euclid(BigInt::from(HASH_MODULUS))
-                        .unwrap_unchecked(),
-                )
-                .unwrap_unchecked()
-            }
-        };
-        if (&self.0).is_negative() {
-            if result.is_one() {
-                -2isize
-            } else {
-                -result
-            }
-        } else {
-            result
-        }
+        self.0.numeric_hash()
     }
 
     fn __mod__(&self, divisor: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
@@ -398,6 +413,11 @@ impl PyFraction {
                 .into_pyobject(py)?
                 .into_any()
                 .unbind())
+        } else if let Ok(other) = other.cast::<PyFloat>() {
+            Ok(compare(&self.0, &other.value(), op)
+                .into_pyobject(py)?
+                .into_any()
+                .unbind())
         } else {
             Ok(py.NotImplemented())
         }
@@ -493,31 +513,35 @@ impl PyFraction {
         match digits {
             Some(digits) => {
                 let digits = try_big_int_from_py_integral(digits)?;
-                let is_digits_positive = (&digits).is_positive();
-                let shift = unsafe {
-                    BigInt::from(10)
-                        .checked_pow(digits.abs())
-                        .unwrap_unchecked()
+                let result = match isize::try_from(&digits) {
+                    Ok(digits_count) => unsafe {
+                        self.0
+                            .round_to_digits(
+                                digits_count,
+                                10,
+                                TieBreaking::ToEven,
+                            )
+                            .unwrap_unchecked()
+                    },
+                    Err(_) => {
+                        let is_digits_positive = (&digits).is_positive();
+                        let shift = BigInt::from(10).pow(digits.abs());
+                        let step = if is_digits_positive {
+                            unsafe {
+                                Fraction::new(BigInt::from(1), shift)
+                                    .unwrap_unchecked()
+                            }
+                        } else {
+                            Fraction::from(shift)
+                        };
+                        unsafe {
+                            self.0
+                                .round_to_multiple(&step, TieBreaking::ToEven)
+                                .unwrap_unchecked()
+                        }
+                    }
                 };
-                if is_digits_positive {
-                    Ok(Self(unsafe {
-                        Fraction::new(
-                            (&self.0 * &shift).round(TieBreaking::ToEven),
-                            shift,
-                        )
-                        .unwrap_unchecked()
-                    })
-                    .into_pyobject(py)?
-                    .into_any()
-                    .unbind())
-                } else {
-                    Ok(Self(Fraction::from(
-                        (&self.0 / &shift).round(TieBreaking::ToEven) * shift,
-                    ))
-                    .into_pyobject(py)?
-                    .into_any()
-                    .unbind())
-                }
+                Ok(Self(result).into_pyobject(py)?.into_any().unbind())
             }
             None => Ok(PyBigInt((&self.0).round(TieBreaking::ToEven))
                 .into_pyobject(py)?
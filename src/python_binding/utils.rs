@@ -9,13 +9,6 @@ use traiter::numbers::{
     CheckedShr,
 };
 
-#[cfg(target_arch = "x86")]
-pub(super) const HASH_BITS: usize = 31;
-#[cfg(not(target_arch = "x86"))]
-pub(super) const HASH_BITS: usize = 61usize;
-pub(super) const HASH_INF: isize = 314_159isize;
-pub(super) const HASH_MODULUS: usize = (1usize << HASH_BITS) - 1usize;
-
 #[inline]
 pub(super) fn try_divmod<
     Dividend: CheckedDivRemEuclid<Divisor, Output = Option<(Quotient, Remainder)>>,
@@ -0,0 +1,39 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::Abs;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Abs for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Abs<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Self;
+
+    fn abs(self) -> Self::Output {
+        match self.0 {
+            Value::Small(value) if *value.numerator() != i64::MIN => {
+                Self(Value::Small(value.abs()))
+            }
+            value => Self::from_big(value.into_big().abs()),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Abs for &Rational<Digit, DIGIT_BITNESS>
+where
+    Rational<Digit, DIGIT_BITNESS>:
+        Clone + Abs<Output = Rational<Digit, DIGIT_BITNESS>>,
+{
+    type Output = Rational<Digit, DIGIT_BITNESS>;
+
+    fn abs(self) -> Self::Output {
+        self.clone().abs()
+    }
+}
@@ -0,0 +1,29 @@
+use std::ops::{Add, AddAssign};
+
+use traiter::numbers::Zero;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> AddAssign
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Add<Output = Self>,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .add(other);
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> AddAssign<&Self>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Clone + Add<Output = Self>,
+{
+    fn add_assign(&mut self, other: &Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .add(other.clone());
+    }
+}
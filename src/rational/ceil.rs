@@ -0,0 +1,43 @@
+use traiter::numbers::Ceil;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Ceil for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Ceil<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn ceil(self) -> Self::Output {
+        match self.0 {
+            Value::Small(value) if *value.numerator() != i64::MIN => {
+                BigInt::from(value.ceil())
+            }
+            value => value.into_big().ceil(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Ceil
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Ceil<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn ceil(self) -> Self::Output {
+        match &self.0 {
+            Value::Small(value) if *value.numerator() != i64::MIN => {
+                BigInt::from(value.clone().ceil())
+            }
+            value => value.to_big().ceil(),
+        }
+    }
+}
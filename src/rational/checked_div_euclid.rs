@@ -0,0 +1,50 @@
+use traiter::numbers::CheckedDivEuclid;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{checked_div_rem_floor, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedDivEuclid
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        CheckedDivEuclid<Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn checked_div_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some((result, _)) = checked_div_rem_floor(first, second) {
+                return Some(BigInt::from(result));
+            }
+        }
+        self.0.into_big().checked_div_euclid(other.0.into_big())
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedDivEuclid
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        CheckedDivEuclid<Output = Option<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Option<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn checked_div_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some((result, _)) = checked_div_rem_floor(first, second) {
+                return Some(BigInt::from(result));
+            }
+        }
+        self.0.with_big(&other.0, |first, second| {
+            first.checked_div_euclid(second)
+        })
+    }
+}
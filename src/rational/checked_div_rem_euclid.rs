@@ -0,0 +1,84 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::CheckedDivRemEuclid;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{checked_div_rem_floor, narrow, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedDivRemEuclid
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedDivRemEuclid<
+        Output = Option<(
+            BigInt<Digit, DIGIT_BITNESS>,
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+        )>,
+    >,
+{
+    type Output = Option<(BigInt<Digit, DIGIT_BITNESS>, Self)>;
+
+    fn checked_div_rem_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some((quotient, remainder)) =
+                checked_div_rem_floor(first, second)
+            {
+                if let Some(remainder) = narrow(remainder) {
+                    return Some((
+                        BigInt::from(quotient),
+                        Self(Value::Small(remainder)),
+                    ));
+                }
+            }
+        }
+        self.0
+            .into_big()
+            .checked_div_rem_euclid(other.0.into_big())
+            .map(|(quotient, remainder)| (quotient, Self::from_big(remainder)))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedDivRemEuclid
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedDivRemEuclid<
+        Output = Option<(
+            BigInt<Digit, DIGIT_BITNESS>,
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+        )>,
+    >,
+{
+    type Output =
+        Option<(BigInt<Digit, DIGIT_BITNESS>, Rational<Digit, DIGIT_BITNESS>)>;
+
+    fn checked_div_rem_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some((quotient, remainder)) =
+                checked_div_rem_floor(first, second)
+            {
+                if let Some(remainder) = narrow(remainder) {
+                    return Some((
+                        BigInt::from(quotient),
+                        Rational(Value::Small(remainder)),
+                    ));
+                }
+            }
+        }
+        self.0
+            .with_big(&other.0, |first, second| {
+                first.checked_div_rem_euclid(second)
+            })
+            .map(|(quotient, remainder)| {
+                (quotient, Rational::from_big(remainder))
+            })
+    }
+}
@@ -0,0 +1,36 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::CheckedPow;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    CheckedPow<BigInt<Digit, DIGIT_BITNESS>> for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    for<'a> u32: TryFrom<&'a BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedPow<
+        BigInt<Digit, DIGIT_BITNESS>,
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+{
+    type Output = Option<Self>;
+
+    fn checked_pow(
+        self,
+        exponent: BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Self::Output {
+        if let (Value::Small(value), Ok(exponent)) =
+            (&self.0, u32::try_from(&exponent))
+        {
+            if let Some(result) = value.clone().checked_pow(exponent) {
+                return Some(Self(Value::Small(result)));
+            }
+        }
+        self.0.into_big().checked_pow(exponent).map(Self::from_big)
+    }
+}
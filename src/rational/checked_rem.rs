@@ -0,0 +1,61 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::CheckedRem;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{narrow, widen, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRem
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        CheckedRem<Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>>,
+{
+    type Output = Option<Self>;
+
+    fn checked_rem(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) =
+                widen(first).checked_rem(widen(second)).and_then(narrow)
+            {
+                return Some(Self(Value::Small(result)));
+            }
+        }
+        self.0
+            .into_big()
+            .checked_rem(other.0.into_big())
+            .map(Self::from_big)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRem
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        CheckedRem<Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>>,
+{
+    type Output = Option<Rational<Digit, DIGIT_BITNESS>>;
+
+    fn checked_rem(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) =
+                widen(first).checked_rem(widen(second)).and_then(narrow)
+            {
+                return Some(Rational(Value::Small(result)));
+            }
+        }
+        self.0
+            .with_big(&other.0, |first, second| first.checked_rem(second))
+            .map(Rational::from_big)
+    }
+}
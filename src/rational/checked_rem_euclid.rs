@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::CheckedRemEuclid;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{checked_div_rem_floor, narrow, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRemEuclid
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedRemEuclid<
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+{
+    type Output = Option<Self>;
+
+    fn checked_rem_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = checked_div_rem_floor(first, second)
+                .and_then(|(_, remainder)| narrow(remainder))
+            {
+                return Some(Self(Value::Small(result)));
+            }
+        }
+        self.0
+            .into_big()
+            .checked_rem_euclid(other.0.into_big())
+            .map(Self::from_big)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> CheckedRemEuclid
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>: CheckedRemEuclid<
+        Output = Option<Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    >,
+{
+    type Output = Option<Rational<Digit, DIGIT_BITNESS>>;
+
+    fn checked_rem_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = checked_div_rem_floor(first, second)
+                .and_then(|(_, remainder)| narrow(remainder))
+            {
+                return Some(Rational(Value::Small(result)));
+            }
+        }
+        self.0
+            .with_big(&other.0, |first, second| {
+                first.checked_rem_euclid(second)
+            })
+            .map(Rational::from_big)
+    }
+}
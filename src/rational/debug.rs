@@ -0,0 +1,29 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Debug
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Value::Big(value) => write!(
+                formatter,
+                "{}::from({:?})",
+                std::any::type_name::<Self>(),
+                value
+            ),
+            Value::Small(value) => write!(
+                formatter,
+                "{}::from({:?})",
+                std::any::type_name::<Self>(),
+                value
+            ),
+        }
+    }
+}
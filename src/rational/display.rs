@@ -0,0 +1,19 @@
+use std::fmt::{Display, Formatter};
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Display
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: Display,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Value::Big(value) => Display::fmt(value, formatter),
+            Value::Small(value) => Display::fmt(value, formatter),
+        }
+    }
+}
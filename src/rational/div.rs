@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+use std::ops::Div;
+
+use traiter::numbers::CheckedDiv;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Div for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Div<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = first.clone().checked_div(second.clone()) {
+                return Self(Value::Small(result));
+            }
+        }
+        Self::from_big(self.0.into_big().div(other.0.into_big()))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Div for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Div<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Rational<Digit, DIGIT_BITNESS>;
+
+    fn div(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = first.clone().checked_div(second.clone()) {
+                return Rational(Value::Small(result));
+            }
+        }
+        Rational::from_big(
+            self.0.with_big(&other.0, |first, second| first.div(second)),
+        )
+    }
+}
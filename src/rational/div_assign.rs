@@ -0,0 +1,29 @@
+use std::ops::{Div, DivAssign};
+
+use traiter::numbers::Zero;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> DivAssign
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Div<Output = Self>,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .div(other);
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> DivAssign<&Self>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Clone + Div<Output = Self>,
+{
+    fn div_assign(&mut self, other: &Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .div(other.clone());
+    }
+}
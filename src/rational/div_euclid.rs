@@ -0,0 +1,49 @@
+use traiter::numbers::DivEuclid;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{checked_div_rem_floor, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> DivEuclid
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        DivEuclid<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn div_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some((result, _)) = checked_div_rem_floor(first, second) {
+                return BigInt::from(result);
+            }
+        }
+        self.0.into_big().div_euclid(other.0.into_big())
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> DivEuclid
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        DivEuclid<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn div_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some((result, _)) = checked_div_rem_floor(first, second) {
+                return BigInt::from(result);
+            }
+        }
+        self.0
+            .with_big(&other.0, |first, second| first.div_euclid(second))
+    }
+}
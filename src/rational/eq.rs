@@ -0,0 +1,9 @@
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::Rational;
+
+impl<Digit, const DIGIT_BITNESS: usize> Eq for Rational<Digit, DIGIT_BITNESS> where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: Eq
+{
+}
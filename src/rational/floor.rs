@@ -0,0 +1,40 @@
+use traiter::numbers::Floor;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Floor
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Floor<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn floor(self) -> Self::Output {
+        match self.0 {
+            Value::Small(value) => BigInt::from(value.floor()),
+            value => value.into_big().floor(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Floor
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Floor<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn floor(self) -> Self::Output {
+        match &self.0 {
+            Value::Small(value) => BigInt::from(value.floor()),
+            value => value.to_big().floor(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::convert::TryFrom;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> From<i64>
+    for Rational<Digit, DIGIT_BITNESS>
+{
+    fn from(value: i64) -> Self {
+        Self(Value::Small(Fraction::from(value)))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> From<Fraction<i64>>
+    for Rational<Digit, DIGIT_BITNESS>
+{
+    fn from(value: Fraction<i64>) -> Self {
+        Self(Value::Small(value))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> From<BigInt<Digit, DIGIT_BITNESS>>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: From<BigInt<Digit, DIGIT_BITNESS>>,
+{
+    fn from(value: BigInt<Digit, DIGIT_BITNESS>) -> Self {
+        Self::from_big(Fraction::from(value))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize>
+    From<Fraction<BigInt<Digit, DIGIT_BITNESS>>>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    fn from(value: Fraction<BigInt<Digit, DIGIT_BITNESS>>) -> Self {
+        Self::from_big(value)
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> From<Rational<Digit, DIGIT_BITNESS>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+{
+    fn from(value: Rational<Digit, DIGIT_BITNESS>) -> Self {
+        value.0.into_big()
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> From<&Rational<Digit, DIGIT_BITNESS>>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+{
+    fn from(value: &Rational<Digit, DIGIT_BITNESS>) -> Self {
+        value.0.to_big()
+    }
+}
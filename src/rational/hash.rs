@@ -0,0 +1,14 @@
+use std::hash::{Hash, Hasher};
+
+use crate::traits::NumericHash;
+
+use super::types::Rational;
+
+impl<Digit, const DIGIT_BITNESS: usize> Hash for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: NumericHash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_isize(self.numeric_hash());
+    }
+}
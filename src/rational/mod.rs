@@ -0,0 +1,43 @@
+pub use types::Rational;
+
+mod abs;
+mod add;
+mod add_assign;
+mod ceil;
+mod checked_div;
+mod checked_div_euclid;
+mod checked_div_rem_euclid;
+mod checked_pow;
+mod checked_rem;
+mod checked_rem_euclid;
+mod debug;
+mod display;
+mod div;
+mod div_assign;
+mod div_euclid;
+mod eq;
+mod floor;
+mod from;
+mod hash;
+mod mul;
+mod mul_assign;
+mod neg;
+mod numeric_hash;
+mod one;
+mod ord;
+mod partial_eq;
+mod partial_ord;
+mod pow;
+mod rem;
+mod rem_assign;
+mod rem_euclid;
+mod round;
+mod signed;
+mod sub;
+mod sub_assign;
+mod trunc;
+mod try_from;
+mod types;
+mod unitary;
+mod zero;
+mod zeroable;
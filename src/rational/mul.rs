@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+use std::ops::Mul;
+
+use traiter::numbers::CheckedMul;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Mul for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Mul<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = first.clone().checked_mul(second.clone()) {
+                return Self(Value::Small(result));
+            }
+        }
+        Self::from_big(self.0.into_big().mul(other.0.into_big()))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Mul for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Mul<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Rational<Digit, DIGIT_BITNESS>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = first.clone().checked_mul(second.clone()) {
+                return Rational(Value::Small(result));
+            }
+        }
+        Rational::from_big(
+            self.0.with_big(&other.0, |first, second| first.mul(second)),
+        )
+    }
+}
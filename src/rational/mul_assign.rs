@@ -0,0 +1,29 @@
+use std::ops::{Mul, MulAssign};
+
+use traiter::numbers::Zero;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> MulAssign
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Mul<Output = Self>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .mul(other);
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> MulAssign<&Self>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Clone + Mul<Output = Self>,
+{
+    fn mul_assign(&mut self, other: &Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .mul(other.clone());
+    }
+}
@@ -0,0 +1,38 @@
+use std::convert::TryFrom;
+use std::ops::Neg;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Neg for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Neg<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self.0 {
+            Value::Small(value) if *value.numerator() != i64::MIN => {
+                Self(Value::Small(value.neg()))
+            }
+            value => Self::from_big(value.into_big().neg()),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Neg for &Rational<Digit, DIGIT_BITNESS>
+where
+    Rational<Digit, DIGIT_BITNESS>:
+        Clone + Neg<Output = Rational<Digit, DIGIT_BITNESS>>,
+{
+    type Output = Rational<Digit, DIGIT_BITNESS>;
+
+    fn neg(self) -> Self::Output {
+        self.clone().neg()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+use crate::traits::NumericHash;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> NumericHash
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: NumericHash,
+{
+    fn numeric_hash(&self) -> isize {
+        match &self.0 {
+            Value::Big(value) => value.numeric_hash(),
+            Value::Small(value) => value.numeric_hash(),
+        }
+    }
+}
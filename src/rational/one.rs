@@ -0,0 +1,13 @@
+use traiter::numbers::One;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> One
+    for Rational<Digit, DIGIT_BITNESS>
+{
+    fn one() -> Self {
+        Self(Value::Small(Fraction::one()))
+    }
+}
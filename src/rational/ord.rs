@@ -0,0 +1,30 @@
+use std::cmp::Ordering;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Ord for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: Ord,
+    Self: Eq,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Value::Big(value), Value::Big(other_value)) => {
+                value.cmp(other_value)
+            }
+            (Value::Small(value), Value::Small(other_value)) => {
+                ((*value.numerator() as i128)
+                    * (*other_value.denominator() as i128))
+                    .cmp(
+                        &((*value.denominator() as i128)
+                            * (*other_value.numerator() as i128)),
+                    )
+            }
+            (value, other_value) => value.to_big().cmp(&other_value.to_big()),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialEq
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Value::Big(value), Value::Big(other_value)) => {
+                value == other_value
+            }
+            (Value::Small(value), Value::Small(other_value)) => {
+                value == other_value
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use std::cmp::Ordering;
+
+use super::types::Rational;
+
+impl<Digit, const DIGIT_BITNESS: usize> PartialOrd
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,19 @@
+use traiter::numbers::{CheckedPow, Pow};
+
+use crate::big_int::BigInt;
+use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
+
+use super::types::Rational;
+
+impl<Digit, const DIGIT_BITNESS: usize> Pow<BigInt<Digit, DIGIT_BITNESS>>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: CheckedPow<BigInt<Digit, DIGIT_BITNESS>, Output = Option<Self>>,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
+        self.checked_pow(exponent)
+            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE)
+    }
+}
@@ -0,0 +1,57 @@
+use std::convert::TryFrom;
+use std::ops::Rem;
+
+use traiter::numbers::CheckedRem;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{narrow, widen, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Rem for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Rem<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) =
+                widen(first).checked_rem(widen(second)).and_then(narrow)
+            {
+                return Self(Value::Small(result));
+            }
+        }
+        Self::from_big(self.0.into_big().rem(other.0.into_big()))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Rem for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Rem<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Rational<Digit, DIGIT_BITNESS>;
+
+    fn rem(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) =
+                widen(first).checked_rem(widen(second)).and_then(narrow)
+            {
+                return Rational(Value::Small(result));
+            }
+        }
+        Rational::from_big(
+            self.0.with_big(&other.0, |first, second| first.rem(second)),
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use std::ops::{Rem, RemAssign};
+
+use traiter::numbers::Zero;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> RemAssign
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Rem<Output = Self>,
+{
+    fn rem_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            % other;
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> RemAssign<&Self>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Clone + Rem<Output = Self>,
+{
+    fn rem_assign(&mut self, other: &Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            % other.clone();
+    }
+}
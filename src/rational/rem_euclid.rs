@@ -0,0 +1,59 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::RemEuclid;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{checked_div_rem_floor, narrow, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> RemEuclid
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        RemEuclid<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Self;
+
+    fn rem_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = checked_div_rem_floor(first, second)
+                .and_then(|(_, remainder)| narrow(remainder))
+            {
+                return Self(Value::Small(result));
+            }
+        }
+        Self::from_big(self.0.into_big().rem_euclid(other.0.into_big()))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> RemEuclid
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    for<'a> &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        RemEuclid<Output = Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    type Output = Rational<Digit, DIGIT_BITNESS>;
+
+    fn rem_euclid(self, other: Self) -> Self::Output {
+        if let (Value::Small(first), Value::Small(second)) =
+            (&self.0, &other.0)
+        {
+            if let Some(result) = checked_div_rem_floor(first, second)
+                .and_then(|(_, remainder)| narrow(remainder))
+            {
+                return Rational(Value::Small(result));
+            }
+        }
+        Rational::from_big(
+            self.0
+                .with_big(&other.0, |first, second| first.rem_euclid(second)),
+        )
+    }
+}
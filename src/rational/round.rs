@@ -0,0 +1,45 @@
+use traiter::numbers::{Round, TieBreaking};
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{widen, Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Round
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64> + From<i128>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn round(self, tie_breaking: TieBreaking) -> Self::Output {
+        match self.0 {
+            // doubled remainders of small values may not fit in `i64`
+            Value::Small(value) => {
+                BigInt::from(widen(&value).round(tie_breaking))
+            }
+            value => value.into_big().round(tie_breaking),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Round
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64> + From<i128>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn round(self, tie_breaking: TieBreaking) -> Self::Output {
+        match &self.0 {
+            Value::Small(value) => {
+                BigInt::from(widen(value).round(tie_breaking))
+            }
+            value => value.to_big().round(tie_breaking),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use traiter::numbers::{Sign, Signed};
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<'a, Digit, const DIGIT_BITNESS: usize> Signed
+    for &'a Rational<Digit, DIGIT_BITNESS>
+where
+    &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>: Signed,
+{
+    fn is_negative(self) -> bool {
+        match &self.0 {
+            Value::Big(value) => value.is_negative(),
+            Value::Small(value) => value.is_negative(),
+        }
+    }
+
+    fn is_positive(self) -> bool {
+        match &self.0 {
+            Value::Big(value) => value.is_positive(),
+            Value::Small(value) => value.is_positive(),
+        }
+    }
+
+    fn sign(self) -> Sign {
+        match &self.0 {
+            Value::Big(value) => value.sign(),
+            Value::Small(value) => value.sign(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Signed
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: Signed,
+{
+    fn is_negative(self) -> bool {
+        (&self).is_negative()
+    }
+
+    fn is_positive(self) -> bool {
+        (&self).is_positive()
+    }
+
+    fn sign(self) -> Sign {
+        (&self).sign()
+    }
+}
@@ -0,0 +1,29 @@
+use std::ops::{Sub, SubAssign};
+
+use traiter::numbers::Zero;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> SubAssign
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Sub<Output = Self>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .sub(other);
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> SubAssign<&Self>
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    Self: Clone + Sub<Output = Self>,
+{
+    fn sub_assign(&mut self, other: &Self) {
+        *self = std::mem::replace(self, Self(Value::Small(Fraction::zero())))
+            .sub(other.clone());
+    }
+}
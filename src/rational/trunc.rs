@@ -0,0 +1,44 @@
+use traiter::numbers::Trunc;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Trunc
+    for Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Trunc<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn trunc(self) -> Self::Output {
+        match self.0 {
+            Value::Small(value) if *value.numerator() != i64::MIN => {
+                BigInt::from(value.trunc())
+            }
+            value => value.into_big().trunc(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Trunc
+    for &Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>:
+        Trunc<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    type Output = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn trunc(self) -> Self::Output {
+        match &self.0 {
+            Value::Small(value) if *value.numerator() != i64::MIN => {
+                BigInt::from(value.clone().trunc())
+            }
+            value => value.to_big().trunc(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::convert::TryFrom;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+use super::types::Rational;
+
+macro_rules! rational_try_from_float_impl {
+    ($($float:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> TryFrom<$float>
+            for Rational<Digit, DIGIT_BITNESS>
+        where
+            for<'a> Fraction<i64>:
+                TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>: TryFrom<$float>,
+        {
+            type Error = <Fraction<BigInt<Digit, DIGIT_BITNESS>> as TryFrom<
+                $float,
+            >>::Error;
+
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                Fraction::try_from(value).map(Self::from_big)
+            }
+        }
+    )*)
+}
+
+rational_try_from_float_impl!(f32 f64);
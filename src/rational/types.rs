@@ -0,0 +1,162 @@
+use std::convert::TryFrom;
+
+use traiter::numbers::CheckedDivRemEuclid;
+
+use crate::big_int::BigInt;
+use crate::fraction::Fraction;
+
+#[derive(Clone)]
+pub struct Rational<Digit, const DIGIT_BITNESS: usize>(
+    pub(super) Value<Digit, DIGIT_BITNESS>,
+);
+
+#[derive(Clone)]
+pub(super) enum Value<Digit, const DIGIT_BITNESS: usize> {
+    Big(Fraction<BigInt<Digit, DIGIT_BITNESS>>),
+    Small(Fraction<i64>),
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Rational<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+{
+    pub fn denominator(&self) -> BigInt<Digit, DIGIT_BITNESS> {
+        match &self.0 {
+            Value::Big(value) => value.denominator().clone(),
+            Value::Small(value) => BigInt::from(*value.denominator()),
+        }
+    }
+
+    pub fn numerator(&self) -> BigInt<Digit, DIGIT_BITNESS> {
+        match &self.0 {
+            Value::Big(value) => value.numerator().clone(),
+            Value::Small(value) => BigInt::from(*value.numerator()),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Rational<Digit, DIGIT_BITNESS> {
+    pub fn is_small(&self) -> bool {
+        matches!(self.0, Value::Small(_))
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Rational<Digit, DIGIT_BITNESS>
+where
+    for<'a> Fraction<i64>: TryFrom<&'a Fraction<BigInt<Digit, DIGIT_BITNESS>>>,
+{
+    pub(super) fn from_big(
+        value: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+    ) -> Self {
+        let small_value = Fraction::<i64>::try_from(&value).ok();
+        Self(match small_value {
+            Some(small_value) => Value::Small(small_value),
+            None => Value::Big(value),
+        })
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Value<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+{
+    pub(super) fn into_big(self) -> Fraction<BigInt<Digit, DIGIT_BITNESS>> {
+        match self {
+            Value::Big(value) => value,
+            Value::Small(value) => Fraction::from(value),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Value<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: From<i64>,
+{
+    /// Applies `operation` to big representations of both values
+    /// without cloning the ones which are already big.
+    pub(super) fn with_big<Output>(
+        &self,
+        other: &Self,
+        operation: impl FnOnce(
+            &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+            &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+        ) -> Output,
+    ) -> Output {
+        match (self, other) {
+            (Value::Big(first), Value::Big(second)) => {
+                operation(first, second)
+            }
+            (Value::Big(first), Value::Small(second)) => {
+                operation(first, &Fraction::from(second))
+            }
+            (Value::Small(first), Value::Big(second)) => {
+                operation(&Fraction::from(first), second)
+            }
+            (Value::Small(first), Value::Small(second)) => {
+                operation(&Fraction::from(first), &Fraction::from(second))
+            }
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Value<Digit, DIGIT_BITNESS>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + From<i64>,
+{
+    pub(super) fn to_big(&self) -> Fraction<BigInt<Digit, DIGIT_BITNESS>> {
+        match self {
+            Value::Big(value) => value.clone(),
+            Value::Small(value) => Fraction::from(value),
+        }
+    }
+}
+
+/// Widens components of a small value
+/// so that their cross products cannot overflow.
+pub(super) fn widen(value: &Fraction<i64>) -> Fraction<i128> {
+    unsafe {
+        Fraction::new(
+            i128::from(*value.numerator()),
+            i128::from(*value.denominator()),
+        )
+        .unwrap_unchecked()
+    }
+}
+
+/// Divides small values with flooring as big values do
+/// returning the quotient along with the remainder
+/// in widened components.
+pub(super) fn checked_div_rem_floor(
+    dividend: &Fraction<i64>,
+    divisor: &Fraction<i64>,
+) -> Option<(i128, Fraction<i128>)> {
+    let (numerator, denominator) = (
+        i128::from(*dividend.numerator()),
+        i128::from(*dividend.denominator()),
+    );
+    let (divisor_numerator, divisor_denominator) = (
+        i128::from(*divisor.numerator()),
+        i128::from(*divisor.denominator()),
+    );
+    let (numerator, divisor_numerator) = (
+        numerator * divisor_denominator,
+        divisor_numerator * denominator,
+    );
+    let (quotient, remainder_numerator) = if divisor_numerator.is_negative() {
+        let (quotient, remainder_numerator) =
+            (-numerator).checked_div_rem_euclid(-divisor_numerator)?;
+        (quotient, -remainder_numerator)
+    } else {
+        numerator.checked_div_rem_euclid(divisor_numerator)?
+    };
+    Fraction::new(remainder_numerator, denominator * divisor_denominator)
+        .map(|remainder| (quotient, remainder))
+}
+
+/// Narrows components of a widened value back if they fit.
+pub(super) fn narrow(value: Fraction<i128>) -> Option<Fraction<i64>> {
+    Fraction::new(
+        i64::try_from(*value.numerator()).ok()?,
+        i64::try_from(*value.denominator()).ok()?,
+    )
+}
@@ -0,0 +1,22 @@
+use traiter::numbers::Unitary;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Unitary
+    for &Rational<Digit, DIGIT_BITNESS>
+{
+    fn is_one(self) -> bool {
+        match &self.0 {
+            Value::Big(_) => false,
+            Value::Small(value) => value.is_one(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Unitary
+    for Rational<Digit, DIGIT_BITNESS>
+{
+    fn is_one(self) -> bool {
+        (&self).is_one()
+    }
+}
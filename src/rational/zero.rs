@@ -0,0 +1,13 @@
+use traiter::numbers::Zero;
+
+use crate::fraction::Fraction;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Zero
+    for Rational<Digit, DIGIT_BITNESS>
+{
+    fn zero() -> Self {
+        Self(Value::Small(Fraction::zero()))
+    }
+}
@@ -0,0 +1,22 @@
+use traiter::numbers::Zeroable;
+
+use super::types::{Rational, Value};
+
+impl<Digit, const DIGIT_BITNESS: usize> Zeroable
+    for &Rational<Digit, DIGIT_BITNESS>
+{
+    fn is_zero(self) -> bool {
+        match &self.0 {
+            Value::Big(_) => false,
+            Value::Small(value) => value.is_zero(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Zeroable
+    for Rational<Digit, DIGIT_BITNESS>
+{
+    fn is_zero(self) -> bool {
+        (&self).is_zero()
+    }
+}
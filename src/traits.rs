@@ -1,8 +1,10 @@
 use std::ops::Neg;
 
-use traiter::numbers::Signed;
+use traiter::numbers::{FractExp, Signed};
 
-use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
+use crate::constants::{
+    HASH_BITS, HASH_INF, HASH_MODULUS, UNDEFINED_DIVISION_ERROR_MESSAGE,
+};
 
 pub trait TryDivAsFloat<Divisor, Output> {
     type Error;
@@ -168,6 +170,144 @@ macro_rules! float_min_exp_impl {
 
 float_min_exp_impl!(f32 f64);
 
+pub trait CheckedRoot<Degree> {
+    type Output;
+
+    fn checked_root(self, degree: Degree) -> Self::Output;
+}
+
+pub trait NumericHash {
+    fn numeric_hash(&self) -> isize;
+}
+
+macro_rules! signed_integer_numeric_hash_impl {
+    ($($integer:ty)*) => ($(
+        impl NumericHash for $integer {
+            fn numeric_hash(&self) -> isize {
+                to_signed_hash(
+                    reduce_modulo_hash_modulus(self.unsigned_abs() as u128),
+                    self.is_negative(),
+                )
+            }
+        }
+    )*)
+}
+
+signed_integer_numeric_hash_impl!(i8 i16 i32 i64 i128 isize);
+
+macro_rules! unsigned_integer_numeric_hash_impl {
+    ($($integer:ty)*) => ($(
+        impl NumericHash for $integer {
+            fn numeric_hash(&self) -> isize {
+                to_signed_hash(reduce_modulo_hash_modulus(*self as u128), false)
+            }
+        }
+    )*)
+}
+
+unsigned_integer_numeric_hash_impl!(u8 u16 u32 u64 u128 usize);
+
+impl NumericHash for f32 {
+    fn numeric_hash(&self) -> isize {
+        f64::from(*self).numeric_hash()
+    }
+}
+
+impl NumericHash for f64 {
+    fn numeric_hash(&self) -> isize {
+        const CHUNK_BITNESS: usize = 28;
+        if self.is_infinite() {
+            return if self.is_sign_positive() {
+                HASH_INF
+            } else {
+                -HASH_INF
+            };
+        } else if self.is_nan() {
+            return 0;
+        }
+        let (mut fraction, mut exponent) = self.abs().fract_exp();
+        let mut result = 0usize;
+        while fraction != 0.0 {
+            result = ((result << CHUNK_BITNESS) & HASH_MODULUS)
+                | (result >> (HASH_BITS - CHUNK_BITNESS));
+            fraction *= (1usize << CHUNK_BITNESS) as f64;
+            exponent -= CHUNK_BITNESS as i32;
+            let chunk = fraction as usize;
+            fraction -= chunk as f64;
+            result += chunk;
+            if result >= HASH_MODULUS {
+                result -= HASH_MODULUS;
+            }
+        }
+        let exponent = if exponent.is_negative() {
+            HASH_BITS - 1 - ((-1 - exponent) as usize) % HASH_BITS
+        } else {
+            (exponent as usize) % HASH_BITS
+        };
+        result = ((result << exponent) & HASH_MODULUS)
+            | (result >> (HASH_BITS - exponent));
+        to_signed_hash(result, self.is_sign_negative())
+    }
+}
+
+#[inline]
+pub(crate) fn reduce_modulo_hash_modulus(mut value: u128) -> usize {
+    let modulus = HASH_MODULUS as u128;
+    while value > modulus {
+        value = (value & modulus) + (value >> HASH_BITS);
+    }
+    if value == modulus {
+        0
+    } else {
+        value as usize
+    }
+}
+
+pub(crate) fn to_ratio_hash(
+    numerator_residue: usize,
+    denominator_residue: usize,
+    is_negative: bool,
+) -> isize {
+    let mut inverted_denominator = 1usize;
+    let mut base = denominator_residue;
+    let mut exponent = HASH_MODULUS - 2;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            inverted_denominator = reduce_modulo_hash_modulus(
+                (inverted_denominator as u128) * (base as u128),
+            );
+        }
+        base = reduce_modulo_hash_modulus((base as u128) * (base as u128));
+        exponent >>= 1;
+    }
+    if inverted_denominator == 0 {
+        if is_negative {
+            -HASH_INF
+        } else {
+            HASH_INF
+        }
+    } else {
+        to_signed_hash(
+            reduce_modulo_hash_modulus(
+                (numerator_residue as u128) * (inverted_denominator as u128),
+            ),
+            is_negative,
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn to_signed_hash(residue: usize, is_negative: bool) -> isize {
+    let result = residue as isize;
+    if !is_negative {
+        result
+    } else if result == 1 {
+        -2
+    } else {
+        -result
+    }
+}
+
 pub trait Oppose {
     type Result: Copy + Neg<Output = Self::Result> + Signed;
 }
@@ -267,5 +407,43 @@ integer_wrapping_sub_impl!(
     i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
 );
 
+pub(crate) trait UnsignedAbs {
+    type Output;
+
+    fn unsigned_abs(self) -> Self::Output;
+}
+
+macro_rules! signed_integer_unsigned_abs_impl {
+    ($($integer:ty => $unsigned:ty)*) => ($(
+        impl UnsignedAbs for $integer {
+            type Output = $unsigned;
+
+            #[inline(always)]
+            fn unsigned_abs(self) -> Self::Output {
+                <$integer>::unsigned_abs(self)
+            }
+        }
+    )*)
+}
+
+signed_integer_unsigned_abs_impl!(
+    i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize
+);
+
+macro_rules! unsigned_integer_unsigned_abs_impl {
+    ($($integer:ty)*) => ($(
+        impl UnsignedAbs for $integer {
+            type Output = $integer;
+
+            #[inline(always)]
+            fn unsigned_abs(self) -> Self::Output {
+                self
+            }
+        }
+    )*)
+}
+
+unsigned_integer_unsigned_abs_impl!(u8 u16 u32 u64 u128 usize);
+
 pub type DoublePrecisionOf<T> = <T as DoublePrecision>::Result;
 pub type OppositionOf<T> = <T as Oppose>::Result;
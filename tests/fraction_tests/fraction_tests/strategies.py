@@ -14,6 +14,7 @@ tie_breakings = strategies.sampled_from(
     ]
 )
 floats = strategies.floats()
+finite_floats = strategies.floats(allow_infinity=False, allow_nan=False)
 integers = strategies.integers()
 zero_integers = strategies.builds(int)
 non_zero_integers = integers.filter(bool)
@@ -52,3 +53,10 @@ small_integers = strategies.integers(-100, 100)
 small_ints_with_builtins = strategies.builds(
     to_int_with_builtin, small_integers
 )
+positive_integers = strategies.integers(1)
+positive_ints_with_builtins = strategies.builds(
+    to_int_with_builtin, positive_integers
+)
+non_positive_ints_or_builtins = strategies.integers(max_value=0).map(
+    Int
+) | strategies.integers(max_value=0)
@@ -45,3 +45,21 @@ def test_connection_with_builtin(
     second, second_builtin = second_with_builtin
 
     assert equivalence(first == second, first_builtin == second_builtin)
+
+
+@given(strategies.fractions_with_builtins, strategies.floats)
+def test_connection_with_builtin_float(
+    first_with_builtin: FractionWithBuiltin, second: float
+) -> None:
+    first, first_builtin = first_with_builtin
+
+    assert equivalence(first == second, first_builtin == second)
+    assert equivalence(second == first, second == first_builtin)
+
+
+@given(strategies.finite_floats)
+def test_connection_with_finite_float(value: float) -> None:
+    fraction = Fraction(value)
+
+    assert fraction == value
+    assert value == fraction
@@ -58,3 +58,13 @@ def test_connection_with_builtin(
     second, second_builtin = second_with_builtin
 
     assert equivalence(first <= second, first_builtin <= second_builtin)
+
+
+@given(strategies.fractions_with_builtins, strategies.floats)
+def test_connection_with_builtin_float(
+    first_with_builtin: FractionWithBuiltin, second: float
+) -> None:
+    first, first_builtin = first_with_builtin
+
+    assert equivalence(first <= second, first_builtin <= second)
+    assert equivalence(second <= first, second <= first_builtin)
@@ -0,0 +1,31 @@
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from rithm.integer import Int
+
+from . import strategies
+
+
+@given(strategies.fractions)
+def test_basic(fraction: Fraction) -> None:
+    result = fraction.continued_fraction_terms()
+
+    assert isinstance(result, list)
+    assert all(isinstance(term, Int) for term in result)
+
+
+@given(strategies.fractions)
+def test_value(fraction: Fraction) -> None:
+    result = fraction.continued_fraction_terms()
+
+    assert len(result) > 0
+    assert result[0] == fraction.__floor__()
+    assert all(term > 0 for term in result[1:])
+    assert len(result) == 1 or result[-1] > 1
+
+
+@given(strategies.fractions)
+def test_round_trip(fraction: Fraction) -> None:
+    result = fraction.continued_fraction_terms()
+
+    assert Fraction.from_continued_fraction_terms(result) == fraction
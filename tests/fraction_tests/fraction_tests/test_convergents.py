@@ -0,0 +1,27 @@
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from tests.utils import is_fraction_valid
+
+from . import strategies
+
+
+@given(strategies.fractions)
+def test_basic(fraction: Fraction) -> None:
+    result = fraction.convergents()
+
+    assert isinstance(result, list)
+    assert all(isinstance(convergent, Fraction) for convergent in result)
+    assert all(is_fraction_valid(convergent) for convergent in result)
+
+
+@given(strategies.fractions)
+def test_value(fraction: Fraction) -> None:
+    result = fraction.convergents()
+
+    assert len(result) == len(fraction.continued_fraction_terms())
+    assert result[-1] == fraction
+    assert all(
+        abs(fraction - next_convergent) < abs(fraction - convergent)
+        for convergent, next_convergent in zip(result, result[1:])
+    )
@@ -0,0 +1,38 @@
+import pytest
+from hypothesis import given, strategies as hypothesis_strategies
+
+from rithm.fraction import Fraction
+from rithm.integer import Int
+from tests.utils import IntOrBuiltin, is_fraction_valid
+
+from . import strategies
+
+
+@given(
+    strategies.ints_or_builtins,
+    hypothesis_strategies.lists(strategies.positive_integers),
+)
+def test_basic(first_term: IntOrBuiltin, rest_terms: list[int]) -> None:
+    result = Fraction.from_continued_fraction_terms([first_term, *rest_terms])
+
+    assert isinstance(result, Fraction)
+    assert is_fraction_valid(result)
+
+
+@given(strategies.ints_or_builtins, strategies.positive_integers)
+def test_value(first_term: IntOrBuiltin, second_term: int) -> None:
+    result = Fraction.from_continued_fraction_terms([first_term, second_term])
+
+    assert result == first_term + Fraction(1, second_term)
+
+
+@given(strategies.ints_or_builtins)
+def test_single_term(term: IntOrBuiltin) -> None:
+    result = Fraction.from_continued_fraction_terms([term])
+
+    assert result == Fraction(Int(term))
+
+
+def test_no_terms() -> None:
+    with pytest.raises(ZeroDivisionError):
+        Fraction.from_continued_fraction_terms([])
@@ -0,0 +1,68 @@
+import math
+
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from tests.utils import (
+    FractionWithBuiltin,
+    IntOrBuiltin,
+    IntWithBuiltin,
+    is_equivalent_to_builtin_fraction,
+    is_fraction_valid,
+)
+
+from . import strategies
+
+
+@given(strategies.fractions, strategies.positive_integers)
+def test_basic(fraction: Fraction, max_denominator: int) -> None:
+    result = fraction.limit_denominator(max_denominator)
+
+    assert isinstance(result, Fraction)
+    assert is_fraction_valid(result)
+
+
+@given(strategies.fractions, strategies.positive_integers)
+def test_value(fraction: Fraction, max_denominator: int) -> None:
+    result = fraction.limit_denominator(max_denominator)
+
+    assert result.denominator <= max_denominator
+    assert all(
+        abs(fraction - result)
+        <= abs(fraction - Fraction(numerator, denominator))
+        for denominator in range(1, min(max_denominator, 100) + 1)
+        for numerator in [
+            math.floor(fraction * denominator),
+            math.ceil(fraction * denominator),
+        ]
+    )
+
+
+@given(
+    strategies.fractions_with_builtins, strategies.positive_ints_with_builtins
+)
+def test_connection_with_builtin(
+    fraction_with_builtin: FractionWithBuiltin,
+    max_denominator_with_builtin: IntWithBuiltin,
+) -> None:
+    fraction, builtin_fraction = fraction_with_builtin
+    max_denominator, builtin_max_denominator = max_denominator_with_builtin
+
+    assert is_equivalent_to_builtin_fraction(
+        fraction.limit_denominator(max_denominator),
+        builtin_fraction.limit_denominator(builtin_max_denominator),
+    )
+
+
+@given(strategies.fractions, strategies.non_positive_ints_or_builtins)
+def test_non_positive_max_denominator(
+    fraction: Fraction, max_denominator: IntOrBuiltin
+) -> None:
+    with pytest.raises(ValueError):
+        fraction.limit_denominator(max_denominator)
+
+
+@given(strategies.fractions)
+def test_idempotence(fraction: Fraction) -> None:
+    assert fraction.limit_denominator(fraction.denominator) == fraction
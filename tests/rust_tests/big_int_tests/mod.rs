@@ -0,0 +1,22 @@
+mod test_asn1;
+mod test_big_int_ref;
+#[cfg(feature = "cbor")]
+mod test_cbor;
+mod test_checked_root;
+mod test_from;
+#[cfg(feature = "num-traits")]
+mod test_num_traits;
+mod test_numeric_hash;
+mod test_order_preserving;
+mod test_pg_numeric;
+mod test_primitive_arithmetic;
+mod test_product;
+mod test_radix_io;
+#[cfg(feature = "serde")]
+mod test_serde;
+mod test_sign_and_digits;
+mod test_sum;
+mod test_try_from_string;
+mod test_try_into;
+mod test_try_to_float;
+mod test_varint;
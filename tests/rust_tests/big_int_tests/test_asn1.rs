@@ -0,0 +1,88 @@
+use proptest::prelude::*;
+use rithm::big_int::FromAsn1IntegerError;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_der_round_trip(value in big_ints(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_der_integer();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(
+            BigInt::from_der_integer(&bytes),
+            Ok((value.clone(), size))
+        );
+        prop_assert_eq!(BigInt::from_ber_integer(&bytes), Ok((value, size)));
+    }
+}
+
+#[test]
+fn test_known_values() {
+    for (value, bytes) in [
+        (0i32, &[0x02, 0x01, 0x00][..]),
+        (127, &[0x02, 0x01, 0x7f][..]),
+        (128, &[0x02, 0x02, 0x00, 0x80][..]),
+        (256, &[0x02, 0x02, 0x01, 0x00][..]),
+        (-128, &[0x02, 0x01, 0x80][..]),
+        (-129, &[0x02, 0x02, 0xff, 0x7f][..]),
+    ] {
+        assert_eq!(BigInt::from(value).to_der_integer(), bytes);
+    }
+}
+
+#[test]
+fn test_long_length() {
+    let value = BigInt::from(1u8) << BigInt::from(1024u16);
+    let bytes = value.to_der_integer();
+
+    assert_eq!(&bytes[..4], &[0x02, 0x81, 0x81, 0x01]);
+    assert_eq!(BigInt::from_der_integer(&bytes), Ok((value, bytes.len())));
+}
+
+#[test]
+fn test_non_minimal_encodings() {
+    let non_minimal_content = [0x02, 0x02, 0x00, 0x01];
+    assert_eq!(
+        BigInt::from_der_integer(&non_minimal_content),
+        Err(FromAsn1IntegerError::NonMinimalContent)
+    );
+    assert_eq!(
+        BigInt::from_ber_integer(&non_minimal_content),
+        Ok((BigInt::from(1u8), 4))
+    );
+    let non_minimal_length = [0x02, 0x81, 0x01, 0x05];
+    assert_eq!(
+        BigInt::from_der_integer(&non_minimal_length),
+        Err(FromAsn1IntegerError::NonMinimalLength)
+    );
+    assert_eq!(
+        BigInt::from_ber_integer(&non_minimal_length),
+        Ok((BigInt::from(5u8), 4))
+    );
+}
+
+#[test]
+fn test_invalid() {
+    for (bytes, error) in [
+        (&[][..], FromAsn1IntegerError::Truncated),
+        (
+            &[0x04, 0x01, 0x00][..],
+            FromAsn1IntegerError::InvalidTag(0x04),
+        ),
+        (
+            &[0x02, 0x80, 0x00][..],
+            FromAsn1IntegerError::IndefiniteLength,
+        ),
+        (&[0x02, 0xff][..], FromAsn1IntegerError::ReservedLength),
+        (&[0x02, 0x00][..], FromAsn1IntegerError::EmptyContent),
+        (&[0x02, 0x02, 0x01][..], FromAsn1IntegerError::Truncated),
+        (
+            &[0x02, 0x89, 0x01, 0, 0, 0, 0, 0, 0, 0, 0][..],
+            FromAsn1IntegerError::TooLongLength,
+        ),
+    ] {
+        assert_eq!(BigInt::from_ber_integer(bytes), Err(error));
+    }
+}
@@ -0,0 +1,82 @@
+use std::cmp::Ordering;
+
+use proptest::prelude::*;
+use rithm::big_int::BigIntRef;
+use traiter::numbers::{
+    BitLength, CheckedDiv, CheckedRem, Endianness, Signed, ToBytes, Zeroable,
+};
+
+use crate::strategies::{big_ints, non_zero_big_ints, BigInt};
+
+type BigIntView<'a> = BigIntRef<'a, u32, 31>;
+
+proptest! {
+    #[test]
+    fn test_from_sign_and_digits(value in big_ints(), zeros_count in 0usize..4) {
+        let (sign, mut digits) = value.clone().into_sign_and_digits();
+        digits.extend(std::iter::repeat(0).take(zeros_count));
+
+        let view = BigIntView::from_sign_and_digits(sign, &digits).unwrap();
+
+        prop_assert_eq!(view.as_digits(), value.as_digits());
+        prop_assert_eq!(BigInt::from(view), value);
+    }
+
+    #[test]
+    fn test_unary_operations(value in big_ints()) {
+        let view = BigIntView::from(&value);
+
+        prop_assert_eq!(view.to_string(), value.to_string());
+        prop_assert_eq!(-view, -value.clone());
+        prop_assert_eq!(view.bit_length(), (&value).bit_length());
+        prop_assert_eq!(view.is_negative(), (&value).is_negative());
+        prop_assert_eq!(view.is_positive(), (&value).is_positive());
+        prop_assert_eq!(view.is_zero(), (&value).is_zero());
+        prop_assert_eq!(
+            view.to_bytes(Endianness::Little),
+            (&value).to_bytes(Endianness::Little)
+        );
+    }
+
+    #[test]
+    fn test_binary_operations(first in big_ints(), second in big_ints()) {
+        let (first_view, second_view) =
+            (BigIntView::from(&first), BigIntView::from(&second));
+
+        prop_assert_eq!(first_view + second_view, &first + &second);
+        prop_assert_eq!(first_view - second_view, &first - &second);
+        prop_assert_eq!(first_view * second_view, &first * &second);
+        prop_assert_eq!(
+            first_view.checked_div(second_view),
+            (&first).checked_div(&second)
+        );
+        prop_assert_eq!(
+            first_view.checked_rem(second_view),
+            (&first).checked_rem(&second)
+        );
+        prop_assert_eq!(first_view.cmp(&second_view), first.cmp(&second));
+        prop_assert_eq!(first_view == second, first == second);
+        prop_assert_eq!(
+            first_view.partial_cmp(&second),
+            Some(first.cmp(&second))
+        );
+    }
+
+    #[test]
+    fn test_division(dividend in big_ints(), divisor in non_zero_big_ints()) {
+        let (dividend_view, divisor_view) =
+            (BigIntView::from(&dividend), BigIntView::from(&divisor));
+
+        prop_assert_eq!(dividend_view / divisor_view, &dividend / &divisor);
+        prop_assert_eq!(dividend_view % divisor_view, &dividend % &divisor);
+    }
+}
+
+#[test]
+fn test_reflexive_comparison() {
+    let value = BigInt::from(-5i8);
+    let view = BigIntView::from(&value);
+
+    assert_eq!(view.cmp(&view), Ordering::Equal);
+    assert_eq!(value, view);
+}
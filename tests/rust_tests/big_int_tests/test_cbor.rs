@@ -0,0 +1,63 @@
+use proptest::prelude::*;
+use rithm::big_int::FromCborError;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in big_ints(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_cbor();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(BigInt::from_cbor(&bytes), Ok((value, size)));
+    }
+}
+
+#[test]
+fn test_known_values() {
+    for (value, bytes) in [
+        (BigInt::from(0u8), vec![0x00]),
+        (BigInt::from(23u8), vec![0x17]),
+        (BigInt::from(24u8), vec![0x18, 0x18]),
+        (BigInt::from(-1i8), vec![0x20]),
+        (BigInt::from(-500i16), vec![0x39, 0x01, 0xf3]),
+        (BigInt::from(u64::MAX), [&[0x1b][..], &[0xff; 8]].concat()),
+        (
+            BigInt::from(u128::from(u64::MAX) + 1),
+            [&[0xc2, 0x49, 0x01][..], &[0x00; 8]].concat(),
+        ),
+        (
+            -BigInt::from(u128::from(u64::MAX) + 2),
+            [&[0xc3, 0x49, 0x01][..], &[0x00; 8]].concat(),
+        ),
+    ] {
+        assert_eq!(value.to_cbor(), bytes);
+        assert_eq!(BigInt::from_cbor(&bytes), Ok((value, bytes.len())));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    for (bytes, error) in [
+        (&[][..], FromCborError::Truncated),
+        (&[0x19, 0x01][..], FromCborError::Truncated),
+        (&[0xc2, 0x49, 0x01][..], FromCborError::Truncated),
+        (&[0x5f][..], FromCborError::IndefiniteLength),
+        (&[0x1c][..], FromCborError::Malformed),
+        (&[0x18, 0x05][..], FromCborError::NonCanonical),
+        (&[0xc2, 0x41, 0x01][..], FromCborError::NonCanonical),
+        (
+            &[0xc2, 0x49, 0x00, 1, 1, 1, 1, 1, 1, 1, 1][..],
+            FromCborError::NonCanonical,
+        ),
+        (&[0xc4, 0x41, 0x01][..], FromCborError::InvalidTag(4)),
+        (&[0x60][..], FromCborError::UnexpectedMajorType(3)),
+        (
+            &[0xc2, 0x61, 0x01][..],
+            FromCborError::UnexpectedMajorType(3),
+        ),
+    ] {
+        assert_eq!(BigInt::from_cbor(bytes), Err(error));
+    }
+}
@@ -0,0 +1,73 @@
+use proptest::prelude::*;
+use rithm::CheckedRoot;
+use traiter::numbers::{Abs, One, Pow, Zero};
+
+use crate::strategies::{big_ints, non_zero_big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_power_round_trip(value in big_ints(), degree in 1usize..6) {
+        let power = (&value).pow(BigInt::from(degree));
+
+        if degree % 2 == 0 {
+            prop_assert_eq!(
+                (&power).checked_root(degree),
+                Some((&value).abs())
+            );
+        } else {
+            prop_assert_eq!((&power).checked_root(degree), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_non_powers(value in non_zero_big_ints(), degree in 2usize..6) {
+        let power = (&value).abs().pow(BigInt::from(degree));
+
+        prop_assert_eq!((power + BigInt::one()).checked_root(degree), None);
+    }
+
+    #[test]
+    fn test_degree_connection(value in big_ints(), degree in 1usize..6) {
+        prop_assert_eq!(
+            (&value).checked_root(&BigInt::from(degree)),
+            (&value).checked_root(degree)
+        );
+        prop_assert_eq!(
+            value.clone().checked_root(BigInt::from(degree)),
+            (&value).checked_root(degree)
+        );
+    }
+
+    #[test]
+    fn test_invalid_degree(value in big_ints(), degree in i8::MIN..=0) {
+        prop_assert_eq!(value.checked_root(BigInt::from(degree)), None);
+    }
+
+    #[test]
+    fn test_even_degree_of_negative(
+        value in non_zero_big_ints(),
+        degree in 1usize..4,
+    ) {
+        prop_assert_eq!((-(&value).abs()).checked_root(2 * degree), None);
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    assert_eq!(BigInt::zero().checked_root(1usize), Some(BigInt::zero()));
+    assert_eq!(BigInt::zero().checked_root(7usize), Some(BigInt::zero()));
+    assert_eq!(BigInt::one().checked_root(100usize), Some(BigInt::one()));
+    assert_eq!(
+        BigInt::from(-1i8).checked_root(101usize),
+        Some(BigInt::from(-1i8))
+    );
+    assert_eq!(
+        BigInt::from(u128::MAX).checked_root(1usize),
+        Some(BigInt::from(u128::MAX))
+    );
+    assert_eq!(
+        BigInt::from(1u128 << 126).checked_root(63usize),
+        Some(BigInt::from(4u8))
+    );
+    assert_eq!(BigInt::from(2u8).checked_root(2usize), None);
+}
@@ -0,0 +1,41 @@
+use proptest::prelude::*;
+
+use crate::strategies::{BigInt, SmallDigitBigInt};
+
+macro_rules! test_from_integer {
+    ($($name:ident: $integer:ty)*) => ($(
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn test_boundaries() {
+                for value in [<$integer>::MIN, <$integer>::MAX] {
+                    assert_eq!(BigInt::from(value).to_string(), value.to_string());
+                    assert_eq!(
+                        SmallDigitBigInt::from(value).to_string(),
+                        value.to_string()
+                    );
+                }
+            }
+
+            proptest! {
+                #[test]
+                fn test_basic(value in any::<$integer>()) {
+                    prop_assert_eq!(
+                        BigInt::from(value).to_string(),
+                        value.to_string()
+                    );
+                    prop_assert_eq!(
+                        SmallDigitBigInt::from(value).to_string(),
+                        value.to_string()
+                    );
+                }
+            }
+        }
+    )*)
+}
+
+test_from_integer!(
+    i8: i8 i16: i16 i32: i32 i64: i64 i128: i128 isize: isize
+    u8: u8 u16: u16 u32: u32 u64: u64 u128: u128 usize: usize
+);
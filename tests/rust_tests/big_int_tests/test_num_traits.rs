@@ -0,0 +1,169 @@
+use std::convert::TryFrom;
+
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive,
+    Num, ToPrimitive,
+};
+use proptest::prelude::*;
+use traiter::numbers::{Abs, FromStrRadix, Pow, Sign, Signed};
+
+use crate::strategies::{big_ints, non_zero_big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_from_str_radix(value in big_ints(), radix in 2u32..=36) {
+        let string = value.to_string();
+
+        prop_assert_eq!(
+            <BigInt as Num>::from_str_radix(&string, 10),
+            <BigInt as FromStrRadix>::from_str_radix(&string, 10)
+        );
+        prop_assert_eq!(
+            <BigInt as Num>::from_str_radix(&string, radix),
+            <BigInt as FromStrRadix>::from_str_radix(&string, radix)
+        );
+    }
+
+    #[test]
+    fn test_signed(first in big_ints(), second in big_ints()) {
+        prop_assert_eq!(
+            num_traits::Signed::abs(&first),
+            Abs::abs(&first)
+        );
+        prop_assert_eq!(
+            num_traits::Signed::is_positive(&first),
+            Signed::is_positive(&first)
+        );
+        prop_assert_eq!(
+            num_traits::Signed::is_negative(&first),
+            Signed::is_negative(&first)
+        );
+        prop_assert_eq!(
+            num_traits::Signed::signum(&first),
+            match Signed::sign(&first) {
+                Sign::Negative => BigInt::from(-1i8),
+                Sign::Positive => BigInt::from(1u8),
+                Sign::Zero => BigInt::from(0u8),
+            }
+        );
+        prop_assert_eq!(
+            num_traits::Signed::abs_sub(&first, &second),
+            if first <= second {
+                BigInt::from(0u8)
+            } else {
+                &first - &second
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_primitive(value in any::<i128>()) {
+        let big_int = BigInt::from(value);
+
+        prop_assert_eq!(big_int.to_i64(), i64::try_from(&big_int).ok());
+        prop_assert_eq!(big_int.to_i128(), Some(value));
+        prop_assert_eq!(big_int.to_u64(), u64::try_from(&big_int).ok());
+        prop_assert_eq!(big_int.to_u128(), u128::try_from(&big_int).ok());
+        prop_assert_eq!(big_int.to_f64(), f64::try_from(&big_int).ok());
+    }
+
+    #[test]
+    fn test_from_primitive(
+        signed in any::<i128>(),
+        unsigned in any::<u128>(),
+        float in any::<f64>(),
+    ) {
+        prop_assert_eq!(
+            BigInt::from_i64(signed as i64),
+            Some(BigInt::from(signed as i64))
+        );
+        prop_assert_eq!(BigInt::from_i128(signed), Some(BigInt::from(signed)));
+        prop_assert_eq!(
+            BigInt::from_u64(unsigned as u64),
+            Some(BigInt::from(unsigned as u64))
+        );
+        prop_assert_eq!(
+            BigInt::from_u128(unsigned),
+            Some(BigInt::from(unsigned))
+        );
+        prop_assert_eq!(BigInt::from_f64(float), BigInt::try_from(float).ok());
+    }
+
+    #[test]
+    fn test_pow(base in big_ints(), exponent in 0u8..16) {
+        let expected = Pow::pow(&base, BigInt::from(exponent));
+
+        prop_assert_eq!(
+            num_traits::Pow::pow(&base, exponent),
+            expected.clone()
+        );
+        prop_assert_eq!(
+            num_traits::Pow::pow(base.clone(), exponent),
+            expected.clone()
+        );
+        prop_assert_eq!(
+            num_traits::Pow::pow(&base, &BigInt::from(exponent)),
+            expected.clone()
+        );
+        prop_assert_eq!(
+            num_traits::Pow::pow(base, BigInt::from(exponent)),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic(
+        first in big_ints(),
+        second in big_ints(),
+    ) {
+        prop_assert_eq!(first.checked_add(&second), Some(&first + &second));
+        prop_assert_eq!(first.checked_sub(&second), Some(&first - &second));
+        prop_assert_eq!(first.checked_mul(&second), Some(&first * &second));
+        prop_assert_eq!(
+            CheckedDiv::checked_div(&first, &second),
+            traiter::numbers::CheckedDiv::checked_div(&first, &second)
+        );
+        prop_assert_eq!(
+            CheckedRem::checked_rem(&first, &second),
+            traiter::numbers::CheckedRem::checked_rem(&first, &second)
+        );
+    }
+
+    #[test]
+    fn test_checked_division_by_non_zero(
+        dividend in big_ints(),
+        divisor in non_zero_big_ints(),
+    ) {
+        prop_assert!(CheckedDiv::checked_div(&dividend, &divisor).is_some());
+        prop_assert!(CheckedRem::checked_rem(&dividend, &divisor).is_some());
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    let zero = BigInt::from(0u8);
+
+    assert_eq!(BigInt::from(i64::MAX).to_i64(), Some(i64::MAX));
+    assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!((BigInt::from(i64::MAX) + 1u8).to_i64(), None);
+    assert_eq!((BigInt::from(i64::MIN) - 1u8).to_i64(), None);
+    assert_eq!(BigInt::from(u64::MAX).to_u64(), Some(u64::MAX));
+    assert_eq!((BigInt::from(u64::MAX) + 1u8).to_u64(), None);
+    assert_eq!(BigInt::from(-1i8).to_u64(), None);
+    assert_eq!(BigInt::from_f64(f64::NAN), None);
+    assert_eq!(BigInt::from_f64(f64::INFINITY), None);
+    assert_eq!(BigInt::from_f64(f64::NEG_INFINITY), None);
+    assert!(<BigInt as Num>::from_str_radix("10", 1).is_err());
+    assert!(<BigInt as Num>::from_str_radix("10", 37).is_err());
+    assert!(<BigInt as Num>::from_str_radix("12", 2).is_err());
+    assert!(<BigInt as Num>::from_str_radix("", 10).is_err());
+    assert_eq!(
+        <BigInt as Num>::from_str_radix("-ff", 16),
+        Ok(BigInt::from(-255i16))
+    );
+    assert!(CheckedDiv::checked_div(&BigInt::from(1u8), &zero).is_none());
+    assert!(CheckedRem::checked_rem(&BigInt::from(1u8), &zero).is_none());
+    assert!(num_traits::Zero::is_zero(&zero));
+    assert_eq!(<BigInt as num_traits::Zero>::zero(), zero);
+    assert!(num_traits::One::is_one(&BigInt::from(1u8)));
+}
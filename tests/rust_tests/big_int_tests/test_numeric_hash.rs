@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use rithm::NumericHash;
+
+use crate::strategies::{big_ints, BigInt, SmallDigitBigInt};
+
+#[cfg(target_pointer_width = "32")]
+const HASH_BITS: u32 = 31;
+#[cfg(target_pointer_width = "64")]
+const HASH_BITS: u32 = 61;
+const HASH_MODULUS: u128 = (1 << HASH_BITS) - 1;
+
+proptest! {
+    #[test]
+    fn test_primitive_connection(value in any::<i128>()) {
+        prop_assert_eq!(
+            BigInt::from(value).numeric_hash(),
+            value.numeric_hash()
+        );
+        prop_assert_eq!(
+            SmallDigitBigInt::from(value).numeric_hash(),
+            value.numeric_hash()
+        );
+    }
+
+    #[test]
+    fn test_float_connection(value in any::<i32>()) {
+        let float = f64::from(value);
+
+        prop_assert_eq!(
+            BigInt::try_from(float).unwrap().numeric_hash(),
+            float.numeric_hash()
+        );
+    }
+
+    #[test]
+    fn test_modulus_periodicity(value in big_ints()) {
+        prop_assert_eq!(
+            (&value * (HASH_MODULUS + 1)).numeric_hash(),
+            value.numeric_hash()
+        );
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    assert_eq!(BigInt::from(0u8).numeric_hash(), 0);
+    assert_eq!(BigInt::from(-1i8).numeric_hash(), -2);
+    assert_eq!(BigInt::from(HASH_MODULUS).numeric_hash(), 0);
+    assert_eq!(
+        BigInt::from(HASH_MODULUS - 1).numeric_hash(),
+        (HASH_MODULUS - 1) as isize
+    );
+    assert_eq!(BigInt::from(HASH_MODULUS + 1).numeric_hash(), 1);
+    assert_eq!((-BigInt::from(HASH_MODULUS + 1)).numeric_hash(), -2);
+    assert_eq!(HASH_MODULUS.numeric_hash(), 0);
+    assert_eq!(
+        u128::MAX.numeric_hash(),
+        BigInt::from(u128::MAX).numeric_hash()
+    );
+    assert_eq!(0.5f64.numeric_hash(), 1isize << (HASH_BITS - 1));
+    assert_eq!(f64::INFINITY.numeric_hash(), 314_159);
+    assert_eq!(f64::NEG_INFINITY.numeric_hash(), -314_159);
+}
@@ -0,0 +1,75 @@
+use proptest::prelude::*;
+use rithm::big_int::FromOrderPreservingBytesError;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in big_ints(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_order_preserving_bytes();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(
+            BigInt::from_order_preserving_bytes(&bytes),
+            Ok((value, size))
+        );
+    }
+
+    #[test]
+    fn test_order(first in big_ints(), second in big_ints()) {
+        prop_assert_eq!(
+            first
+                .to_order_preserving_bytes()
+                .cmp(&second.to_order_preserving_bytes()),
+            first.cmp(&second)
+        );
+    }
+
+    #[test]
+    fn test_composite_keys_order(
+        first in (big_ints(), big_ints()),
+        second in (big_ints(), big_ints()),
+    ) {
+        let to_key = |(major, minor): &(BigInt, BigInt)| {
+            let mut result = major.to_order_preserving_bytes();
+            result.extend(minor.to_order_preserving_bytes());
+            result
+        };
+
+        prop_assert_eq!(to_key(&first).cmp(&to_key(&second)), first.cmp(&second));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    for (bytes, error) in [
+        (&[][..], FromOrderPreservingBytesError::Truncated),
+        (
+            &[0x81, 0x01, 0x02, 0x05][..],
+            FromOrderPreservingBytesError::Truncated,
+        ),
+        (
+            &[0x42][..],
+            FromOrderPreservingBytesError::InvalidHeader(0x42),
+        ),
+        (
+            &[0x81, 0x00][..],
+            FromOrderPreservingBytesError::NonCanonical,
+        ),
+        (
+            &[0x81, 0x02, 0x00, 0x01, 0x05][..],
+            FromOrderPreservingBytesError::NonCanonical,
+        ),
+        (
+            &[0x81, 0x01, 0x02, 0x00, 0x05][..],
+            FromOrderPreservingBytesError::NonCanonical,
+        ),
+        (
+            &[0x81, 0x09, 1, 1, 1, 1, 1, 1, 1, 1, 1][..],
+            FromOrderPreservingBytesError::TooLongLength,
+        ),
+    ] {
+        assert_eq!(BigInt::from_order_preserving_bytes(bytes), Err(error));
+    }
+}
@@ -0,0 +1,65 @@
+use proptest::prelude::*;
+use rithm::big_int::FromPgNumericError;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in big_ints()) {
+        let bytes = value.to_pg_numeric().unwrap();
+
+        prop_assert_eq!(BigInt::from_pg_numeric(&bytes), Ok(value));
+    }
+}
+
+#[test]
+fn test_known_values() {
+    for (value, bytes) in [
+        (BigInt::from(0u8), &[0, 0, 0, 0, 0, 0, 0, 0][..]),
+        (BigInt::from(10000u16), &[0, 1, 0, 1, 0, 0, 0, 0, 0, 1][..]),
+        (
+            BigInt::from(-12345678i32),
+            &[0, 2, 0, 1, 0x40, 0, 0, 0, 0x04, 0xd2, 0x16, 0x2e][..],
+        ),
+    ] {
+        assert_eq!(value.to_pg_numeric().as_deref(), Some(bytes));
+        assert_eq!(BigInt::from_pg_numeric(bytes), Ok(value));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    for (bytes, error) in [
+        (
+            &[0, 0, 0, 0, 0, 0, 0][..],
+            FromPgNumericError::InvalidLength,
+        ),
+        (
+            &[0, 1, 0, 0, 0, 0, 0, 0][..],
+            FromPgNumericError::InvalidLength,
+        ),
+        (&[0, 0, 0, 0, 0xc0, 0, 0, 0][..], FromPgNumericError::NaN),
+        (
+            &[0, 0, 0, 0, 0xd0, 0, 0, 0][..],
+            FromPgNumericError::Infinity,
+        ),
+        (
+            &[0, 0, 0, 0, 0xf0, 0, 0, 0][..],
+            FromPgNumericError::Infinity,
+        ),
+        (
+            &[0, 0, 0, 0, 0x12, 0x34, 0, 0][..],
+            FromPgNumericError::InvalidSign(0x1234),
+        ),
+        (
+            &[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10][..],
+            FromPgNumericError::InvalidDigit(10000),
+        ),
+        (
+            &[0, 1, 0xff, 0xff, 0, 0, 0, 4, 0x13, 0x88][..],
+            FromPgNumericError::NonIntegral,
+        ),
+    ] {
+        assert_eq!(BigInt::from_pg_numeric(bytes), Err(error));
+    }
+}
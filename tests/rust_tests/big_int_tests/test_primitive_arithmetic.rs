@@ -0,0 +1,130 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::{CheckedDiv, CheckedRem, Endianness, FromBytes};
+
+use crate::strategies::{BigInt, SmallDigitBigInt};
+
+type SingleBitDigitBigInt = rithm::big_int::BigInt<u8, 1>;
+
+macro_rules! primitive_arithmetic_tests_impl {
+    ($($module:ident => $integer:ty)*) => ($(
+        mod $module {
+            use super::*;
+
+            fn values() -> impl Strategy<Value = $integer> {
+                prop_oneof![
+                    Just(<$integer>::MIN),
+                    Just(<$integer>::MAX),
+                    Just(0 as $integer),
+                    Just(1 as $integer),
+                    any::<$integer>(),
+                ]
+            }
+
+            fn bytes() -> impl Strategy<Value = Vec<u8>> {
+                vec(any::<u8>(), 1..48)
+            }
+
+            proptest! {
+                #[test]
+                fn test_matches_big_int_operand(
+                    bytes in bytes(),
+                    value in values(),
+                ) {
+                    let first = BigInt::from_bytes(&bytes, Endianness::Little);
+                    let second = BigInt::from(value);
+
+                    prop_assert_eq!(&first + value, &first + &second);
+                    prop_assert_eq!(value + &first, &second + &first);
+                    prop_assert_eq!(&first - value, &first - &second);
+                    prop_assert_eq!(value - &first, &second - &first);
+                    prop_assert_eq!(&first * value, &first * &second);
+                    prop_assert_eq!(value * &first, &second * &first);
+                    prop_assert_eq!(
+                        (&first).checked_div(value),
+                        (&first).checked_div(&second)
+                    );
+                    prop_assert_eq!(
+                        (&first).checked_rem(value),
+                        (&first).checked_rem(&second)
+                    );
+                    prop_assert_eq!(first.clone() + value, &first + value);
+                }
+
+                #[test]
+                fn test_small_digits_match_big_int_operand(
+                    bytes in bytes(),
+                    value in values(),
+                ) {
+                    let first = SmallDigitBigInt::from_bytes(
+                        &bytes,
+                        Endianness::Little,
+                    );
+                    let second = SmallDigitBigInt::from(value);
+
+                    prop_assert_eq!(&first + value, &first + &second);
+                    prop_assert_eq!(&first - value, &first - &second);
+                    prop_assert_eq!(&first * value, &first * &second);
+                    prop_assert_eq!(
+                        (&first).checked_div(value),
+                        (&first).checked_div(&second)
+                    );
+                    prop_assert_eq!(
+                        (&first).checked_rem(value),
+                        (&first).checked_rem(&second)
+                    );
+                }
+
+                #[test]
+                fn test_round_trip(bytes in bytes(), value in values()) {
+                    let first = BigInt::from_bytes(&bytes, Endianness::Little);
+
+                    prop_assert_eq!(&first + value - value, first.clone());
+                    prop_assert_eq!(
+                        SmallDigitBigInt::from(value) + value - value,
+                        SmallDigitBigInt::from(value)
+                    );
+                }
+            }
+
+            #[test]
+            fn test_boundaries() {
+                for value in [<$integer>::MIN, <$integer>::MAX] {
+                    assert_eq!(
+                        BigInt::from(0u8) + value,
+                        BigInt::from(value)
+                    );
+                    assert_eq!(
+                        SmallDigitBigInt::from(0u8) + value,
+                        SmallDigitBigInt::from(value)
+                    );
+                    assert_eq!(
+                        SingleBitDigitBigInt::from(0u8) + value,
+                        SingleBitDigitBigInt::from(value)
+                    );
+                    assert_eq!(
+                        SingleBitDigitBigInt::from(value) - value,
+                        SingleBitDigitBigInt::from(0u8)
+                    );
+                }
+                assert_eq!(
+                    (&BigInt::from(1u8)).checked_div(0 as $integer),
+                    None
+                );
+                assert_eq!(
+                    (&SmallDigitBigInt::from(1u8)).checked_rem(0 as $integer),
+                    None
+                );
+            }
+        }
+    )*)
+}
+
+primitive_arithmetic_tests_impl!(
+    i8_operand => i8
+    i64_operand => i64
+    i128_operand => i128
+    u8_operand => u8
+    u128_operand => u128
+    usize_operand => usize
+);
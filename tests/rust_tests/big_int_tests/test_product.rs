@@ -0,0 +1,37 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::One;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_matches_fold(values in vec(big_ints(), 0..32)) {
+        let expected = values
+            .iter()
+            .fold(BigInt::one(), |product, value| product * value);
+
+        prop_assert_eq!(
+            values.iter().product::<BigInt>(),
+            expected.clone()
+        );
+        prop_assert_eq!(values.into_iter().product::<BigInt>(), expected);
+    }
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(
+        Vec::<BigInt>::new().into_iter().product::<BigInt>(),
+        BigInt::one()
+    );
+}
+
+#[test]
+fn test_factorial() {
+    let expected = (1u16..=300).fold(BigInt::from(1u8), |product, value| {
+        product * BigInt::from(value)
+    });
+
+    assert_eq!((1u16..=300).map(BigInt::from).product::<BigInt>(), expected);
+}
@@ -0,0 +1,148 @@
+use std::io::{BufRead, ErrorKind, Read};
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::{Endianness, FromBytes};
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in big_ints(), radix in 2u32..=36) {
+        let mut buffer = Vec::new();
+        value.write_radix(&mut buffer, radix).unwrap();
+
+        prop_assert_eq!(
+            BigInt::read_radix(buffer.as_slice(), radix).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_decimal_matches_display(value in big_ints()) {
+        let mut buffer = Vec::new();
+        value.write_radix(&mut buffer, 10).unwrap();
+
+        prop_assert_eq!(String::from_utf8(buffer).unwrap(), value.to_string());
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn test_multiple_chunks(bytes in vec(any::<u8>(), 2048..4096)) {
+        let value = BigInt::from_bytes(&bytes, Endianness::Little);
+        let mut buffer = Vec::new();
+        value.write_radix(&mut buffer, 10).unwrap();
+
+        prop_assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            value.to_string()
+        );
+        prop_assert_eq!(
+            BigInt::read_radix(buffer.as_slice(), 10).unwrap(),
+            value
+        );
+    }
+}
+
+#[test]
+fn test_invalid_radix() {
+    let value = BigInt::from(10u8);
+    for radix in [1, 37] {
+        assert_eq!(
+            value.write_radix(Vec::new(), radix).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            BigInt::read_radix(b"10".as_slice(), radix)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidInput
+        );
+    }
+}
+
+#[test]
+fn test_invalid_data() {
+    for bytes in [
+        b"".as_slice(),
+        b"-".as_slice(),
+        b"z12".as_slice(),
+        b"_1".as_slice(),
+        b"1__0".as_slice(),
+        b"10_".as_slice(),
+        b"1_z".as_slice(),
+    ] {
+        assert_eq!(
+            BigInt::read_radix(bytes, 10).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+    assert_eq!(
+        BigInt::read_radix(b"0x_".as_slice(), 0).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(
+        BigInt::read_radix(b"  0x1f \n".as_slice(), 0).unwrap(),
+        BigInt::from(31u8)
+    );
+}
+
+#[test]
+fn test_trailing_data() {
+    let mut reader = b"-12z 34".as_slice();
+
+    assert_eq!(
+        BigInt::read_radix(&mut reader, 10).unwrap(),
+        BigInt::from(-12)
+    );
+    assert_eq!(reader, b"z 34");
+
+    let mut reader = b"0x1fg\nrest".as_slice();
+
+    assert_eq!(
+        BigInt::read_radix(&mut reader, 0).unwrap(),
+        BigInt::from(31)
+    );
+    assert_eq!(reader, b"g\nrest");
+
+    let mut reader = b"1 2".as_slice();
+
+    assert_eq!(
+        BigInt::read_radix(&mut reader, 10).unwrap(),
+        BigInt::from(1)
+    );
+    assert_eq!(
+        BigInt::read_radix(&mut reader, 10).unwrap(),
+        BigInt::from(2)
+    );
+    assert!(reader.fill_buf().unwrap().is_empty());
+
+    let mut rest = String::new();
+    let mut reader = b"255 rest".as_slice();
+
+    assert_eq!(
+        BigInt::read_radix(&mut reader, 16).unwrap(),
+        BigInt::from(0x255)
+    );
+    reader.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, " rest");
+}
+
+#[test]
+fn test_separators() {
+    for (bytes, radix, expected) in [
+        (b"1_000_000".as_slice(), 10, 1_000_000),
+        (b"0x_ff_ff".as_slice(), 0, 0xffff),
+        (b"0b1_0".as_slice(), 2, 0b10),
+        (b"0_7".as_slice(), 8, 0o7),
+        (b"-1_2 3".as_slice(), 10, -12),
+    ] {
+        assert_eq!(
+            BigInt::read_radix(bytes, radix).unwrap(),
+            BigInt::from(expected)
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use proptest::prelude::*;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_human_readable_round_trip(value in big_ints()) {
+        let serialized = serde_json::to_string(&value).unwrap();
+
+        prop_assert_eq!(serde_json::from_str::<BigInt>(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn test_compact_round_trip(value in big_ints()) {
+        let serialized = rmp_serde::to_vec(&value).unwrap();
+
+        prop_assert_eq!(rmp_serde::from_slice::<BigInt>(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn test_integer_literals(value in any::<i64>()) {
+        prop_assert_eq!(
+            serde_json::from_str::<BigInt>(&value.to_string()).unwrap(),
+            BigInt::from(value)
+        );
+    }
+}
+
+#[test]
+fn test_non_ascii_characters() {
+    assert!(serde_json::from_str::<BigInt>("\"1€\"").is_err());
+    assert!(serde_json::from_str::<BigInt>("\"٣\"").is_err());
+}
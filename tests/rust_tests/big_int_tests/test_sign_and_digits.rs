@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use rithm::big_int::FromSignAndDigitsError;
+use traiter::numbers::Sign;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in big_ints()) {
+        let digits = value.as_digits().to_vec();
+        let (sign, sign_digits) = value.clone().into_sign_and_digits();
+
+        prop_assert_eq!(&sign_digits, &digits);
+        prop_assert_eq!(BigInt::from_sign_and_digits(sign, &digits), Ok(value));
+    }
+
+    #[test]
+    fn test_leading_zeros(value in big_ints(), zeros_count in 1usize..4) {
+        let (sign, mut digits) = value.clone().into_sign_and_digits();
+        digits.extend(std::iter::repeat(0).take(zeros_count));
+
+        prop_assert_eq!(BigInt::from_sign_and_digits(sign, &digits), Ok(value));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(
+        BigInt::from_sign_and_digits(Sign::Zero, &[]),
+        Err(FromSignAndDigitsError::NoDigits)
+    );
+    assert_eq!(
+        BigInt::from_sign_and_digits(Sign::Positive, &[1 << 31]),
+        Err(FromSignAndDigitsError::TooLargeDigit)
+    );
+    assert_eq!(
+        BigInt::from_sign_and_digits(Sign::Positive, &[0, 0]),
+        Err(FromSignAndDigitsError::InconsistentSign)
+    );
+    assert_eq!(
+        BigInt::from_sign_and_digits(Sign::Zero, &[1]),
+        Err(FromSignAndDigitsError::InconsistentSign)
+    );
+    assert_eq!(
+        BigInt::from_sign_and_digits(Sign::Zero, &[0, 0]),
+        Ok(BigInt::from(0u8))
+    );
+}
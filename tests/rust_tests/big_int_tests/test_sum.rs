@@ -0,0 +1,25 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::Zero;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_matches_fold(values in vec(big_ints(), 0..32)) {
+        let expected = values
+            .iter()
+            .fold(BigInt::zero(), |sum, value| sum + value);
+
+        prop_assert_eq!(values.iter().sum::<BigInt>(), expected.clone());
+        prop_assert_eq!(values.into_iter().sum::<BigInt>(), expected);
+    }
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(
+        Vec::<BigInt>::new().into_iter().sum::<BigInt>(),
+        BigInt::zero()
+    );
+}
@@ -0,0 +1,27 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use rithm::big_int::TryFromStringError;
+
+use crate::strategies::{big_ints, BigInt};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in big_ints()) {
+        prop_assert_eq!(BigInt::try_from(value.to_string().as_str()), Ok(value));
+    }
+}
+
+#[test]
+fn test_non_ascii_characters() {
+    for (string, character) in [("1€", '€'), ("٣", '٣'), ("0xÿ", 'ÿ')]
+    {
+        assert_eq!(
+            BigInt::try_from(string),
+            Err(TryFromStringError::InvalidDigit(
+                character,
+                if string.starts_with("0x") { 16 } else { 10 }
+            ))
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+
+use crate::strategies::{BigInt, SmallDigitBigInt};
+
+macro_rules! test_try_into_unsigned_integer {
+    ($($name:ident: $integer:ty)*) => ($(
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn test_boundaries() {
+                let maximum = <$integer>::MAX as u128;
+                for value in [maximum, maximum + 1, maximum + 5, u128::MAX] {
+                    let expected = <$integer>::try_from(value).ok();
+                    assert_eq!(
+                        <$integer>::try_from(BigInt::from(value)).ok(),
+                        expected
+                    );
+                    assert_eq!(
+                        <$integer>::try_from(SmallDigitBigInt::from(value))
+                            .ok(),
+                        expected
+                    );
+                }
+            }
+
+            proptest! {
+                #[test]
+                fn test_basic(value in any::<i128>()) {
+                    let expected = <$integer>::try_from(value).ok();
+                    prop_assert_eq!(
+                        <$integer>::try_from(&BigInt::from(value)).ok(),
+                        expected
+                    );
+                    prop_assert_eq!(
+                        <$integer>::try_from(SmallDigitBigInt::from(value))
+                            .ok(),
+                        expected
+                    );
+                }
+            }
+        }
+    )*)
+}
+
+test_try_into_unsigned_integer!(
+    u8: u8 u16: u16 u32: u32 u64: u64 usize: usize
+);
+
+#[test]
+fn test_u128_boundaries() {
+    for value in [u128::MAX, u128::MAX - 1] {
+        assert_eq!(u128::try_from(BigInt::from(value)).ok(), Some(value));
+        assert_eq!(
+            u128::try_from(SmallDigitBigInt::from(value)).ok(),
+            Some(value)
+        );
+    }
+    for increment in [1u8, 6u8] {
+        assert!(u128::try_from(
+            BigInt::from(u128::MAX) + BigInt::from(increment)
+        )
+        .is_err());
+        assert!(u128::try_from(
+            SmallDigitBigInt::from(u128::MAX)
+                + SmallDigitBigInt::from(increment)
+        )
+        .is_err());
+    }
+}
+
+macro_rules! test_try_into_signed_integer {
+    ($($name:ident: $integer:ty)*) => ($(
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn test_boundaries() {
+                let minimum = <$integer>::MIN as i128;
+                let maximum = <$integer>::MAX as i128;
+                for value in [
+                    minimum - 1,
+                    minimum,
+                    maximum,
+                    maximum + 1,
+                    i128::MIN + 1,
+                    i128::MAX,
+                ] {
+                    let expected = <$integer>::try_from(value).ok();
+                    assert_eq!(
+                        <$integer>::try_from(BigInt::from(value)).ok(),
+                        expected
+                    );
+                    assert_eq!(
+                        <$integer>::try_from(SmallDigitBigInt::from(value))
+                            .ok(),
+                        expected
+                    );
+                }
+            }
+
+            proptest! {
+                #[test]
+                fn test_basic(value in any::<i128>()) {
+                    let expected = <$integer>::try_from(value).ok();
+                    prop_assert_eq!(
+                        <$integer>::try_from(&BigInt::from(value)).ok(),
+                        expected
+                    );
+                    prop_assert_eq!(
+                        <$integer>::try_from(SmallDigitBigInt::from(value))
+                            .ok(),
+                        expected
+                    );
+                }
+            }
+        }
+    )*)
+}
+
+test_try_into_signed_integer!(
+    i8: i8 i16: i16 i32: i32 i64: i64 isize: isize
+);
+
+#[test]
+fn test_i128_boundaries() {
+    for value in [i128::MIN, i128::MIN + 1, i128::MAX] {
+        assert_eq!(i128::try_from(BigInt::from(value)).ok(), Some(value));
+        assert_eq!(
+            i128::try_from(SmallDigitBigInt::from(value)).ok(),
+            Some(value)
+        );
+    }
+    let one = BigInt::from(1u8);
+    assert!(i128::try_from(BigInt::from(i128::MAX) + &one).is_err());
+    assert!(i128::try_from(BigInt::from(i128::MIN) - &one).is_err());
+}
@@ -0,0 +1,169 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use rithm::big_int::{FloatOverflow, FloatRounding, TryIntoFloatError};
+use traiter::numbers::Pow;
+
+use crate::strategies::{big_ints, BigInt};
+
+fn next_up(value: f64) -> f64 {
+    if value == 0.0 {
+        f64::from_bits(1)
+    } else if value > 0.0 {
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_nearest_even(value in any::<i128>()) {
+        let big_int = BigInt::from(value);
+
+        prop_assert_eq!(
+            big_int.try_to_f64_rounded(
+                FloatRounding::NearestEven,
+                FloatOverflow::Error
+            ),
+            Ok(value as f64)
+        );
+        prop_assert_eq!(
+            big_int.try_to_f32_rounded(
+                FloatRounding::NearestEven,
+                FloatOverflow::Error
+            ),
+            Ok(value as f32)
+        );
+    }
+
+    #[test]
+    fn test_directed(value in big_ints()) {
+        let round = |rounding| {
+            value
+                .try_to_f64_rounded(rounding, FloatOverflow::Error)
+                .unwrap()
+        };
+        let lower = round(FloatRounding::TowardNegative);
+        let upper = round(FloatRounding::TowardPositive);
+
+        prop_assert!(lower <= value && value <= upper);
+        if lower == upper {
+            prop_assert!(value == lower);
+        } else {
+            prop_assert_eq!(upper, next_up(lower));
+        }
+        prop_assert_eq!(
+            round(FloatRounding::TowardZero),
+            if value < 0u8 { upper } else { lower }
+        );
+        let nearest = round(FloatRounding::NearestEven);
+        prop_assert!(nearest == lower || nearest == upper);
+    }
+
+    #[test]
+    fn test_directed_f32(value in big_ints()) {
+        let round = |rounding| {
+            f64::from(
+                value
+                    .try_to_f32_rounded(rounding, FloatOverflow::Infinity)
+                    .unwrap(),
+            )
+        };
+
+        prop_assert!(
+            round(FloatRounding::TowardNegative) <= value
+                && value <= round(FloatRounding::TowardPositive)
+        );
+    }
+}
+
+#[test]
+fn test_overflow() {
+    let value = BigInt::from(10u8).pow(BigInt::from(400u16));
+
+    for (rounding, overflow, expected) in [
+        (
+            FloatRounding::NearestEven,
+            FloatOverflow::Error,
+            Err(TryIntoFloatError::TooLarge),
+        ),
+        (
+            FloatRounding::NearestEven,
+            FloatOverflow::Infinity,
+            Ok(f64::INFINITY),
+        ),
+        (
+            FloatRounding::TowardPositive,
+            FloatOverflow::Error,
+            Err(TryIntoFloatError::TooLarge),
+        ),
+        (
+            FloatRounding::TowardZero,
+            FloatOverflow::Error,
+            Ok(f64::MAX),
+        ),
+        (
+            FloatRounding::TowardNegative,
+            FloatOverflow::Error,
+            Ok(f64::MAX),
+        ),
+        (
+            FloatRounding::TowardNegative,
+            FloatOverflow::Infinity,
+            Ok(f64::MAX),
+        ),
+    ] {
+        assert_eq!(value.try_to_f64_rounded(rounding, overflow), expected);
+        assert_eq!(
+            (-value.clone()).try_to_f64_rounded(
+                match rounding {
+                    FloatRounding::TowardNegative => {
+                        FloatRounding::TowardPositive
+                    }
+                    FloatRounding::TowardPositive => {
+                        FloatRounding::TowardNegative
+                    }
+                    rounding => rounding,
+                },
+                overflow
+            ),
+            expected.map(|value| -value)
+        );
+    }
+}
+
+#[test]
+fn test_boundary() {
+    let max = BigInt::try_from(f64::MAX).unwrap();
+    let half_ulp = BigInt::from(2u8).pow(BigInt::from(970u16));
+
+    assert_eq!(
+        max.try_to_f64_rounded(
+            FloatRounding::NearestEven,
+            FloatOverflow::Error
+        ),
+        Ok(f64::MAX)
+    );
+    assert_eq!(
+        (&max + &half_ulp).try_to_f64_rounded(
+            FloatRounding::NearestEven,
+            FloatOverflow::Error
+        ),
+        Err(TryIntoFloatError::TooLarge)
+    );
+    assert_eq!(
+        (&max + &half_ulp - BigInt::from(1u8)).try_to_f64_rounded(
+            FloatRounding::NearestEven,
+            FloatOverflow::Error
+        ),
+        Ok(f64::MAX)
+    );
+    assert_eq!(
+        (&max + &half_ulp).try_to_f64_rounded(
+            FloatRounding::TowardZero,
+            FloatOverflow::Error
+        ),
+        Ok(f64::MAX)
+    );
+}
@@ -0,0 +1,122 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use rithm::big_int::FromVarintError;
+
+use crate::strategies::{big_ints, BigInt};
+
+fn to_unsigned_leb128(mut value: u64) -> Vec<u8> {
+    let mut result = Vec::new();
+    loop {
+        let group = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            result.push(group);
+            break result;
+        }
+        result.push(group | 0x80);
+    }
+}
+
+fn to_signed_leb128(mut value: i64) -> Vec<u8> {
+    let mut result = Vec::new();
+    loop {
+        let group = (value & 0x7f) as u8;
+        value >>= 7;
+        if (value == 0 && group & 0x40 == 0)
+            || (value == -1 && group & 0x40 != 0)
+        {
+            result.push(group);
+            break result;
+        }
+        result.push(group | 0x80);
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_unsigned_leb128_round_trip(value in big_ints(), suffix in any::<Vec<u8>>()) {
+        match value.to_unsigned_leb128() {
+            Some(mut bytes) => {
+                let size = bytes.len();
+                bytes.extend(suffix);
+
+                prop_assert_eq!(
+                    BigInt::from_unsigned_leb128(&bytes),
+                    Ok((value, size))
+                );
+            }
+            None => prop_assert!(value < 0u8),
+        }
+    }
+
+    #[test]
+    fn test_signed_leb128_round_trip(value in big_ints(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_signed_leb128();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(BigInt::from_signed_leb128(&bytes), Ok((value, size)));
+    }
+
+    #[test]
+    fn test_zigzag_varint_round_trip(value in big_ints(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_zigzag_varint();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(BigInt::from_zigzag_varint(&bytes), Ok((value, size)));
+    }
+
+    #[test]
+    fn test_primitive_connection(value in any::<i64>()) {
+        let big_int = BigInt::from(value);
+
+        prop_assert_eq!(
+            big_int.to_unsigned_leb128(),
+            u64::try_from(value).ok().map(to_unsigned_leb128)
+        );
+        prop_assert_eq!(big_int.to_signed_leb128(), to_signed_leb128(value));
+        prop_assert_eq!(
+            big_int.to_zigzag_varint(),
+            to_unsigned_leb128(((value << 1) ^ (value >> 63)) as u64)
+        );
+    }
+}
+
+#[test]
+fn test_known_values() {
+    assert_eq!(
+        BigInt::from(624485u32).to_unsigned_leb128(),
+        Some(vec![0xe5, 0x8e, 0x26])
+    );
+    assert_eq!(
+        BigInt::from(-123456i32).to_signed_leb128(),
+        vec![0xc0, 0xbb, 0x78]
+    );
+    assert_eq!(BigInt::from(-1i8).to_zigzag_varint(), vec![0x01]);
+    assert_eq!(
+        BigInt::from(u128::MAX)
+            .to_unsigned_leb128()
+            .map(|bytes| bytes.len()),
+        Some(19)
+    );
+}
+
+#[test]
+fn test_truncated() {
+    for bytes in [&[][..], &[0x80][..], &[0xff, 0xff][..]] {
+        assert_eq!(
+            BigInt::from_unsigned_leb128(bytes),
+            Err(FromVarintError::Truncated)
+        );
+        assert_eq!(
+            BigInt::from_signed_leb128(bytes),
+            Err(FromVarintError::Truncated)
+        );
+        assert_eq!(
+            BigInt::from_zigzag_varint(bytes),
+            Err(FromVarintError::Truncated)
+        );
+    }
+}
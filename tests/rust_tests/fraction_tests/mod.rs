@@ -0,0 +1,26 @@
+mod test_accumulator;
+mod test_add;
+mod test_approximate;
+#[cfg(feature = "cbor")]
+mod test_cbor;
+mod test_checked_arithmetic;
+mod test_checked_root;
+mod test_continued_fraction;
+mod test_decimal_string;
+mod test_farey_sequence;
+mod test_mediant;
+#[cfg(feature = "num-traits")]
+mod test_num_traits;
+mod test_numeric_hash;
+mod test_order_preserving;
+mod test_pg_numeric;
+mod test_product;
+mod test_quantize;
+#[cfg(feature = "serde")]
+mod test_serde;
+mod test_simplest_between;
+mod test_stern_brocot;
+mod test_sub;
+mod test_sum;
+mod test_try_to_float;
+mod test_varint;
@@ -0,0 +1,114 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use rithm::fraction::FractionAccumulator;
+use traiter::numbers::Zero;
+
+use crate::strategies::{big_ints, fractions, BigInt, Fraction};
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Add(Fraction),
+    AddInteger(BigInt),
+    Mul(Fraction),
+    MulInteger(BigInt),
+    Sub(Fraction),
+    SubInteger(BigInt),
+}
+
+fn operations() -> impl Strategy<Value = Vec<Operation>> {
+    vec(
+        prop_oneof![
+            fractions().prop_map(Operation::Add),
+            big_ints().prop_map(Operation::AddInteger),
+            fractions().prop_map(Operation::Mul),
+            big_ints().prop_map(Operation::MulInteger),
+            fractions().prop_map(Operation::Sub),
+            big_ints().prop_map(Operation::SubInteger),
+        ],
+        0..16,
+    )
+}
+
+fn apply(
+    mut accumulator: FractionAccumulator<BigInt>,
+    mut expected: Fraction,
+    operations: Vec<Operation>,
+) -> (FractionAccumulator<BigInt>, Fraction) {
+    for operation in operations {
+        match operation {
+            Operation::Add(value) => {
+                accumulator += &value;
+                expected += value;
+            }
+            Operation::AddInteger(value) => {
+                accumulator += &value;
+                expected += value;
+            }
+            Operation::Mul(value) => {
+                accumulator *= &value;
+                expected *= value;
+            }
+            Operation::MulInteger(value) => {
+                accumulator *= &value;
+                expected *= value;
+            }
+            Operation::Sub(value) => {
+                accumulator -= &value;
+                expected -= value;
+            }
+            Operation::SubInteger(value) => {
+                accumulator -= &value;
+                expected -= value;
+            }
+        }
+    }
+    (accumulator, expected)
+}
+
+proptest! {
+    #[test]
+    fn test_matches_fraction(
+        initial in fractions(),
+        operations in operations(),
+    ) {
+        let (accumulator, expected) = apply(
+            FractionAccumulator::from(initial.clone()),
+            initial,
+            operations,
+        );
+
+        prop_assert_eq!(accumulator.to_fraction(), expected.clone());
+        prop_assert_eq!(Fraction::from(accumulator), expected);
+    }
+
+    #[test]
+    fn test_normalization_bitness(
+        initial in fractions(),
+        operations in operations(),
+        normalization_bitness in 0usize..256,
+    ) {
+        let (mut accumulator, expected) = apply(
+            FractionAccumulator::with_normalization_bitness(
+                initial.clone(),
+                normalization_bitness,
+            ),
+            initial,
+            operations,
+        );
+
+        prop_assert_eq!(accumulator.to_fraction(), expected.clone());
+        accumulator.normalize();
+        prop_assert_eq!(accumulator.to_fraction(), expected);
+    }
+
+    #[test]
+    fn test_zero(operations in operations()) {
+        let (accumulator, expected) = apply(
+            FractionAccumulator::zero(),
+            Fraction::zero(),
+            operations,
+        );
+
+        prop_assert_eq!(Fraction::from(accumulator), expected);
+    }
+}
@@ -0,0 +1,65 @@
+use proptest::prelude::*;
+
+use crate::strategies::{big_ints, fractions, BigInt, Fraction};
+
+fn naive_add(first: &Fraction, second: &Fraction) -> Fraction {
+    Fraction::new(
+        first.numerator() * second.denominator()
+            + second.numerator() * first.denominator(),
+        first.denominator() * second.denominator(),
+    )
+    .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn test_matches_cross_multiplication(
+        first in fractions(),
+        second in fractions(),
+    ) {
+        let expected = naive_add(&first, &second);
+
+        prop_assert_eq!(&first + &second, expected.clone());
+        prop_assert_eq!(first.clone() + &second, expected.clone());
+        prop_assert_eq!(&first + second.clone(), expected.clone());
+        prop_assert_eq!(first.clone() + second.clone(), expected.clone());
+        let mut accumulated = first.clone();
+        accumulated += &second;
+        prop_assert_eq!(accumulated, expected.clone());
+        let mut accumulated = first;
+        accumulated += second;
+        prop_assert_eq!(accumulated, expected);
+    }
+
+    #[test]
+    fn test_integer(first in fractions(), second in big_ints()) {
+        let expected =
+            naive_add(&first, &Fraction::from(second.clone()));
+
+        prop_assert_eq!(&first + &second, expected.clone());
+        prop_assert_eq!(&second + &first, expected.clone());
+        let mut accumulated = first;
+        accumulated += second;
+        prop_assert_eq!(accumulated, expected);
+    }
+
+    #[test]
+    fn test_shared_denominator_factors(
+        numerators in (big_ints(), big_ints()),
+        denominators in (1u32.., 1u32..),
+        common_factor in 1u32..,
+    ) {
+        let first = Fraction::new(
+            numerators.0,
+            BigInt::from(denominators.0) * BigInt::from(common_factor),
+        )
+        .unwrap();
+        let second = Fraction::new(
+            numerators.1,
+            BigInt::from(denominators.1) * BigInt::from(common_factor),
+        )
+        .unwrap();
+
+        prop_assert_eq!(&first + &second, naive_add(&first, &second));
+    }
+}
@@ -0,0 +1,323 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use traiter::numbers::{Abs, One, Pow, Signed, Zero};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+fn power_of_ten(exponent: u32) -> BigInt {
+    BigInt::from(10u8).pow(BigInt::from(exponent))
+}
+
+fn reciprocal_power_of_ten(exponent: u32) -> Fraction {
+    Fraction::new(BigInt::one(), power_of_ten(exponent)).unwrap()
+}
+
+/// Parses a truncated decimal expansion
+/// returning it along with the truncation error bound.
+fn parse_reference(string: &str) -> (Fraction, Fraction) {
+    let (integer_part, fractional_part) = string.split_once('.').unwrap();
+    let exponent = u32::try_from(fractional_part.len()).unwrap();
+    (
+        Fraction::new(
+            BigInt::try_from(
+                [integer_part, fractional_part].concat().as_str(),
+            )
+            .unwrap(),
+            power_of_ten(exponent),
+        )
+        .unwrap(),
+        reciprocal_power_of_ten(exponent),
+    )
+}
+
+fn assert_within(
+    approximation: &Fraction,
+    reference: &str,
+    tolerance: &Fraction,
+) {
+    let (value, error) = parse_reference(reference);
+
+    assert!(
+        (approximation - &value).abs() <= tolerance + &error,
+        "{} is farther than {} from {}",
+        approximation,
+        tolerance,
+        reference
+    );
+}
+
+/// Returns bounds of the exponent of `value` which are at most `width` apart.
+fn exp_bounds(value: &Fraction, width: &Fraction) -> (Fraction, Fraction) {
+    let two = Fraction::from(BigInt::from(2u8));
+    let modulus = value.abs();
+    let mut sum = Fraction::one();
+    let mut term = Fraction::one();
+    let mut index = 0u32;
+    loop {
+        index += 1;
+        term = term * &modulus / Fraction::from(BigInt::from(index));
+        if &modulus * &two <= BigInt::from(index) && &term * &two <= *width {
+            break;
+        }
+        sum += &term;
+    }
+    let upper_bound = &sum + &term * &two;
+    if value.is_negative() {
+        (Fraction::one() / upper_bound, Fraction::one() / sum)
+    } else {
+        (sum, upper_bound)
+    }
+}
+
+fn tolerances() -> impl Strategy<Value = Fraction> {
+    prop_oneof![
+        (0u32..40).prop_map(reciprocal_power_of_ten),
+        (1u64..).prop_map(|denominator| {
+            Fraction::new(BigInt::one(), BigInt::from(denominator)).unwrap()
+        }),
+    ]
+}
+
+fn small_fractions() -> impl Strategy<Value = Fraction> {
+    (-64i8..64, 1u8..16).prop_map(|(numerator, denominator)| {
+        Fraction::new(BigInt::from(numerator), BigInt::from(denominator))
+            .unwrap()
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_sqrt_bounds(value in fractions(), tolerance in tolerances()) {
+        let value = value.abs();
+
+        let result = value.approximate_sqrt(&tolerance).unwrap();
+
+        let upper_bound = &result + &tolerance;
+        let lower_bound = &result - &tolerance;
+        prop_assert!(&upper_bound * &upper_bound >= value);
+        prop_assert!(
+            !(&lower_bound).is_positive()
+                || &lower_bound * &lower_bound <= value
+        );
+    }
+
+    #[test]
+    fn test_exp_bounds(value in small_fractions(), tolerance in tolerances()) {
+        let result = value.approximate_exp(&tolerance).unwrap();
+
+        let (lower_bound, upper_bound) = exp_bounds(
+            &value,
+            &(&tolerance / Fraction::from(BigInt::from(16u8))),
+        );
+        prop_assert!(&result + &tolerance >= lower_bound);
+        prop_assert!(&result - &tolerance <= upper_bound);
+    }
+
+    #[test]
+    fn test_ln_bounds(value in small_fractions(), tolerance in tolerances()) {
+        let value = value.abs();
+        prop_assume!((&value).is_positive());
+
+        let result = value.approximate_ln(&tolerance).unwrap();
+
+        let width =
+            &tolerance * &value / Fraction::from(BigInt::from(16u8));
+        let (lower_bound, _) = exp_bounds(&(&result - &tolerance), &width);
+        let (_, upper_bound) = exp_bounds(&(&result + &tolerance), &width);
+        prop_assert!(lower_bound <= value);
+        prop_assert!(upper_bound >= value);
+    }
+
+    #[test]
+    fn test_sin_cos_connection(
+        numerator in any::<i32>(),
+        denominator in 1u8..100,
+        exponent in 1u32..20,
+    ) {
+        let value = Fraction::new(
+            BigInt::from(numerator),
+            BigInt::from(denominator),
+        )
+        .unwrap();
+        let tolerance = reciprocal_power_of_ten(exponent);
+
+        let sine = value.approximate_sin(&tolerance).unwrap();
+        let cosine = value.approximate_cos(&tolerance).unwrap();
+
+        prop_assert!(
+            (&sine * &sine + &cosine * &cosine - Fraction::one()).abs()
+                <= &tolerance * Fraction::from(BigInt::from(5u8))
+        );
+    }
+}
+
+#[test]
+fn test_constants() {
+    let two = Fraction::from(BigInt::from(2u8));
+    let one_third = Fraction::new(BigInt::one(), BigInt::from(3u8)).unwrap();
+    for exponent in [0, 1, 5, 20, 50] {
+        let tolerance = reciprocal_power_of_ten(exponent);
+        assert_within(
+            &Fraction::approximate_pi(&tolerance).unwrap(),
+            "3.141592653589793238462643383279502884197169399375105820974944",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::approximate_e(&tolerance).unwrap(),
+            "2.718281828459045235360287471352662497757247093699959574966967",
+            &tolerance,
+        );
+        assert_within(
+            &two.approximate_sqrt(&tolerance).unwrap(),
+            "1.414213562373095048801688724209698078569671875376948073176679",
+            &tolerance,
+        );
+        assert_within(
+            &two.approximate_ln(&tolerance).unwrap(),
+            "0.693147180559945309417232121458176568075500134360255254120680",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::from(BigInt::from(10u8))
+                .approximate_ln(&tolerance)
+                .unwrap(),
+            "2.302585092994045684017991454684364207601101488628772976033327",
+            &tolerance,
+        );
+        assert_within(
+            &-one_third.approximate_ln(&tolerance).unwrap(),
+            "1.098612288668109691395245236922525704647490557822749451734694",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::new(BigInt::from(-3i8), BigInt::from(2u8))
+                .unwrap()
+                .approximate_exp(&tolerance)
+                .unwrap(),
+            "0.223130160148429828933280470764012521342171629361079328743835",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::from(BigInt::from(10u8))
+                .approximate_exp(&tolerance)
+                .unwrap(),
+            "22026.46579480671651695790064528424436635351261855678107423542",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::one().approximate_sin(&tolerance).unwrap(),
+            "0.841470984807896506652502321630298999622563060798371065672751",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::one().approximate_cos(&tolerance).unwrap(),
+            "0.540302305868139717400936607442976603732310420617922227670097",
+            &tolerance,
+        );
+        assert_within(
+            &-Fraction::from(BigInt::from(100u8))
+                .approximate_sin(&tolerance)
+                .unwrap(),
+            "0.506365641109758793656557610459785432065032721290657323443392",
+            &tolerance,
+        );
+        assert_within(
+            &Fraction::from(BigInt::from(100u8))
+                .approximate_cos(&tolerance)
+                .unwrap(),
+            "0.862318872287683934101938513950842535510084008535510829280162",
+            &tolerance,
+        );
+    }
+}
+
+#[test]
+fn test_large_exp_arguments() {
+    let argument = Fraction::from(BigInt::from(333_333u32));
+    let tolerance = Fraction::from(power_of_ten(144_730));
+
+    let result = argument.approximate_exp(&tolerance).unwrap();
+
+    let reference = Fraction::from(
+        BigInt::try_from("481433444313203503842591771034412693604").unwrap()
+            * power_of_ten(144_764 - 38),
+    );
+    let reference_error = Fraction::from(power_of_ten(144_764 - 38));
+    assert!((result - reference).abs() <= tolerance + reference_error);
+    assert_eq!(
+        (-argument)
+            .approximate_exp(&reciprocal_power_of_ten(1))
+            .unwrap(),
+        Fraction::zero()
+    );
+}
+
+#[test]
+fn test_large_negative_exp_arguments() {
+    let half = Fraction::new(BigInt::one(), BigInt::from(2u8)).unwrap();
+    for argument in [-power_of_ten(8), -power_of_ten(10), -power_of_ten(100)] {
+        assert_eq!(
+            Fraction::from(argument).approximate_exp(&half),
+            Some(Fraction::zero())
+        );
+    }
+    // exp(-1000) > 2 ** -1443 so the result should be non-zero
+    let tolerance = Fraction::new(
+        BigInt::one(),
+        BigInt::from(2u8).pow(BigInt::from(1450u16)),
+    )
+    .unwrap();
+    let argument = Fraction::from(BigInt::from(-1000i16));
+    let result = argument.approximate_exp(&tolerance).unwrap();
+    let (lower_bound, upper_bound) = exp_bounds(&argument, &tolerance);
+    assert!(&result + &tolerance >= lower_bound);
+    assert!(&result - &tolerance <= upper_bound);
+    assert!(result.is_positive());
+}
+
+#[test]
+fn test_exact_values() {
+    let tolerance = reciprocal_power_of_ten(10);
+
+    assert_eq!(
+        Fraction::zero().approximate_exp(&tolerance),
+        Some(Fraction::one())
+    );
+    assert_eq!(
+        Fraction::one().approximate_ln(&tolerance),
+        Some(Fraction::zero())
+    );
+    assert_eq!(
+        Fraction::new(BigInt::from(9u8), BigInt::from(4u8))
+            .unwrap()
+            .approximate_sqrt(&tolerance),
+        Some(Fraction::new(BigInt::from(3u8), BigInt::from(2u8)).unwrap())
+    );
+    assert_eq!(
+        Fraction::zero().approximate_sin(&tolerance),
+        Some(Fraction::zero())
+    );
+    assert_eq!(
+        Fraction::zero().approximate_cos(&tolerance),
+        Some(Fraction::one())
+    );
+}
+
+#[test]
+fn test_invalid_arguments() {
+    let value = Fraction::one();
+    for tolerance in [Fraction::zero(), -reciprocal_power_of_ten(1)] {
+        assert_eq!(Fraction::approximate_pi(&tolerance), None);
+        assert_eq!(Fraction::approximate_e(&tolerance), None);
+        assert_eq!(value.approximate_exp(&tolerance), None);
+        assert_eq!(value.approximate_ln(&tolerance), None);
+        assert_eq!(value.approximate_sqrt(&tolerance), None);
+        assert_eq!(value.approximate_sin(&tolerance), None);
+        assert_eq!(value.approximate_cos(&tolerance), None);
+    }
+    let tolerance = reciprocal_power_of_ten(1);
+    assert_eq!(Fraction::zero().approximate_ln(&tolerance), None);
+    assert_eq!((-&value).approximate_ln(&tolerance), None);
+    assert_eq!((-value).approximate_sqrt(&tolerance), None);
+}
@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+use rithm::big_int::FromCborError as BigIntFromCborError;
+use rithm::fraction::FromCborError;
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in fractions(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_cbor();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(Fraction::from_cbor(&bytes), Ok((value, size)));
+    }
+}
+
+#[test]
+fn test_known_values() {
+    let value = Fraction::new(BigInt::from(-1i8), BigInt::from(2u8)).unwrap();
+    let bytes = [0xd8, 0x1e, 0x82, 0x20, 0x02];
+
+    assert_eq!(value.to_cbor(), bytes);
+    assert_eq!(Fraction::from_cbor(&bytes), Ok((value, bytes.len())));
+}
+
+#[test]
+fn test_invalid() {
+    for (bytes, error) in [
+        (
+            &[][..],
+            FromCborError::Component(BigIntFromCborError::Truncated),
+        ),
+        (
+            &[0x82, 0x01, 0x02][..],
+            FromCborError::UnexpectedMajorType(4),
+        ),
+        (&[0xc2, 0x82, 0x01, 0x02][..], FromCborError::InvalidTag(2)),
+        (
+            &[0xd8, 0x1e, 0x01][..],
+            FromCborError::UnexpectedMajorType(0),
+        ),
+        (
+            &[0xd8, 0x1e, 0x83, 0x01, 0x02, 0x03][..],
+            FromCborError::InvalidArrayLength(3),
+        ),
+        (
+            &[0xd8, 0x1e, 0x82, 0x01][..],
+            FromCborError::Component(BigIntFromCborError::Truncated),
+        ),
+        (
+            &[0xd8, 0x1e, 0x82, 0x01, 0x00][..],
+            FromCborError::ZeroDenominator,
+        ),
+        (
+            &[0xd8, 0x1e, 0x82, 0x02, 0x04][..],
+            FromCborError::NonNormalized,
+        ),
+        (
+            &[0xd8, 0x1e, 0x82, 0x01, 0x21][..],
+            FromCborError::NonNormalized,
+        ),
+    ] {
+        assert_eq!(Fraction::from_cbor(bytes), Err(error));
+    }
+}
@@ -0,0 +1,231 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use rithm::fraction;
+use traiter::numbers::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedPow, CheckedSub, Zeroable,
+};
+
+use crate::strategies::{
+    i128_fractions, i64_fractions, i8_fractions, u128_fractions, u8_fractions,
+    BigInt, Fraction,
+};
+
+macro_rules! checked_arithmetic_tests {
+    ($($module:ident => $integer:ty, $fractions:ident)*) => ($(
+        mod $module {
+            use super::*;
+
+            fn to_exact(value: fraction::Fraction<$integer>) -> Fraction {
+                Fraction::from(value)
+            }
+
+            fn from_exact(
+                value: Fraction,
+            ) -> Option<fraction::Fraction<$integer>> {
+                fraction::Fraction::<$integer>::try_from(value).ok()
+            }
+
+            proptest! {
+                #[test]
+                fn test_checked_add(
+                    first in $fractions(),
+                    second in $fractions(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_add(second.clone()),
+                        from_exact(to_exact(first) + to_exact(second))
+                    );
+                }
+
+                #[test]
+                fn test_checked_add_integer(
+                    first in $fractions(),
+                    second in any::<$integer>(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_add(second),
+                        from_exact(
+                            to_exact(first.clone()) + BigInt::from(second)
+                        )
+                    );
+                    prop_assert_eq!(
+                        CheckedAdd::checked_add(second, first.clone()),
+                        first.checked_add(second)
+                    );
+                }
+
+                #[test]
+                fn test_checked_sub(
+                    first in $fractions(),
+                    second in $fractions(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_sub(second.clone()),
+                        from_exact(to_exact(first) - to_exact(second))
+                    );
+                }
+
+                #[test]
+                fn test_checked_sub_integer(
+                    first in $fractions(),
+                    second in any::<$integer>(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_sub(second),
+                        from_exact(
+                            to_exact(first.clone()) - BigInt::from(second)
+                        )
+                    );
+                    prop_assert_eq!(
+                        CheckedSub::checked_sub(second, first.clone()),
+                        from_exact(BigInt::from(second) - to_exact(first))
+                    );
+                }
+
+                #[test]
+                fn test_checked_mul(
+                    first in $fractions(),
+                    second in $fractions(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_mul(second.clone()),
+                        from_exact(to_exact(first) * to_exact(second))
+                    );
+                }
+
+                #[test]
+                fn test_checked_mul_integer(
+                    first in $fractions(),
+                    second in any::<$integer>(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_mul(second),
+                        from_exact(
+                            to_exact(first.clone()) * BigInt::from(second)
+                        )
+                    );
+                    prop_assert_eq!(
+                        CheckedMul::checked_mul(second, first.clone()),
+                        first.checked_mul(second)
+                    );
+                }
+
+                #[test]
+                fn test_checked_div(
+                    first in $fractions(),
+                    second in $fractions(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_div(second.clone()),
+                        if second.numerator().is_zero() {
+                            None
+                        } else {
+                            from_exact(to_exact(first) / to_exact(second))
+                        }
+                    );
+                }
+
+                #[test]
+                fn test_checked_div_integer(
+                    first in $fractions(),
+                    second in any::<$integer>(),
+                ) {
+                    prop_assert_eq!(
+                        first.clone().checked_div(second),
+                        if second.is_zero() {
+                            None
+                        } else {
+                            from_exact(
+                                to_exact(first.clone()) / BigInt::from(second),
+                            )
+                        }
+                    );
+                    prop_assert_eq!(
+                        CheckedDiv::checked_div(second, first.clone()),
+                        if first.numerator().is_zero() {
+                            None
+                        } else {
+                            from_exact(BigInt::from(second) / to_exact(first))
+                        }
+                    );
+                }
+
+                #[test]
+                fn test_checked_pow(
+                    base in $fractions(),
+                    exponent in 0u32..8,
+                ) {
+                    prop_assert_eq!(
+                        base.clone().checked_pow(exponent),
+                        from_exact(
+                            to_exact(base)
+                                .checked_pow(BigInt::from(exponent))
+                                .unwrap()
+                        )
+                    );
+                }
+            }
+        }
+    )*)
+}
+
+checked_arithmetic_tests!(
+    i8_tests => i8, i8_fractions
+    i64_tests => i64, i64_fractions
+    i128_tests => i128, i128_fractions
+    u8_tests => u8, u8_fractions
+    u128_tests => u128, u128_fractions
+);
+
+#[test]
+fn test_reduced_result_fits() {
+    let half_max = fraction::Fraction::new(i128::MAX, 2).unwrap();
+
+    assert_eq!(
+        half_max.clone().checked_add(half_max.clone()),
+        Some(fraction::Fraction::from(i128::MAX))
+    );
+    assert_eq!(
+        fraction::Fraction::from(i128::MAX).checked_sub(half_max.clone()),
+        Some(half_max.clone())
+    );
+    assert_eq!(half_max.clone().checked_add(1i128), None);
+    let half_max = fraction::Fraction::new(u128::MAX, 2).unwrap();
+    assert_eq!(
+        half_max.clone().checked_add(half_max),
+        Some(fraction::Fraction::from(u128::MAX))
+    );
+    let third = fraction::Fraction::new(1u128, 3).unwrap();
+    assert_eq!(
+        third.clone().checked_mul(u128::MAX),
+        Some(fraction::Fraction::from(u128::MAX / 3))
+    );
+}
+
+#[test]
+fn test_minimum() {
+    let minimum = fraction::Fraction::from(i128::MIN);
+
+    assert_eq!(
+        fraction::Fraction::new(i128::MIN, 2),
+        Some(fraction::Fraction::from(i128::MIN / 2))
+    );
+    assert_eq!(
+        fraction::Fraction::new(i64::MIN, 4),
+        Some(fraction::Fraction::from(i64::MIN / 4))
+    );
+    assert_eq!(minimum.clone().checked_add(0i128), Some(minimum.clone()));
+    assert_eq!(minimum.clone().checked_mul(1i128), Some(minimum.clone()));
+    assert_eq!(
+        minimum.clone().checked_div(minimum.clone()),
+        Some(fraction::Fraction::from(1i128))
+    );
+    assert_eq!(minimum.clone().checked_div(-1i128), None);
+    assert_eq!(minimum.clone().checked_sub(1i128), None);
+    assert_eq!(fraction::Fraction::from(i64::MIN).checked_mul(-1i64), None);
+    assert_eq!(
+        fraction::Fraction::from(i64::MIN).checked_div(2i64),
+        Some(fraction::Fraction::from(i64::MIN / 2))
+    );
+}
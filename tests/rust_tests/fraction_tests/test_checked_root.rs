@@ -0,0 +1,80 @@
+use proptest::prelude::*;
+use rithm::CheckedRoot;
+use traiter::numbers::{Abs, CheckedPow, One, Zero};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_power_round_trip(value in fractions(), degree in 1usize..5) {
+        let power = (&value).checked_pow(BigInt::from(degree)).unwrap();
+
+        prop_assert_eq!(
+            (&power).checked_root(degree),
+            Some(if degree % 2 == 0 { (&value).abs() } else { value })
+        );
+    }
+
+    #[test]
+    fn test_rational_exponent(
+        value in fractions(),
+        numerator in 0usize..4,
+        denominator in 1usize..4,
+    ) {
+        let base = (&value)
+            .abs()
+            .checked_pow(BigInt::from(denominator))
+            .unwrap();
+        let exponent = Fraction::new(
+            BigInt::from(numerator),
+            BigInt::from(denominator),
+        )
+        .unwrap();
+
+        prop_assert_eq!(
+            base.checked_pow(exponent),
+            (&value).abs().checked_pow(BigInt::from(numerator))
+        );
+    }
+
+    #[test]
+    fn test_integer_exponent_connection(
+        value in fractions(),
+        exponent in 0usize..4,
+    ) {
+        prop_assert_eq!(
+            (&value).checked_pow(&Fraction::from(BigInt::from(exponent))),
+            (&value).checked_pow(BigInt::from(exponent))
+        );
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    let two = Fraction::from(BigInt::from(2u8));
+    let half = Fraction::new(BigInt::one(), BigInt::from(2u8)).unwrap();
+
+    assert_eq!((&two).checked_pow(&half), None);
+    assert_eq!(
+        Fraction::new(BigInt::from(4u8), BigInt::from(9u8))
+            .unwrap()
+            .checked_pow(&half),
+        Some(Fraction::new(BigInt::from(2u8), BigInt::from(3u8)).unwrap())
+    );
+    assert_eq!(
+        Fraction::new(BigInt::from(4u8), BigInt::from(9u8))
+            .unwrap()
+            .checked_pow(-&half),
+        Some(Fraction::new(BigInt::from(3u8), BigInt::from(2u8)).unwrap())
+    );
+    assert_eq!(
+        Fraction::from(BigInt::from(-8i8)).checked_root(3usize),
+        Some(Fraction::from(BigInt::from(-2i8)))
+    );
+    assert_eq!(
+        Fraction::from(BigInt::from(-4i8)).checked_root(2usize),
+        None
+    );
+    assert_eq!(Fraction::zero().checked_pow(-&half), None);
+    assert_eq!(Fraction::zero().checked_root(0usize), None);
+}
@@ -0,0 +1,83 @@
+use proptest::prelude::*;
+use traiter::numbers::{Abs, Ceil, Floor};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in fractions()) {
+        prop_assert_eq!(
+            Fraction::from_continued_fraction_terms(
+                value.continued_fraction_terms()
+            ),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn test_convergents(value in fractions()) {
+        let convergents = value.convergents().collect::<Vec<_>>();
+
+        prop_assert_eq!(
+            convergents.len(),
+            value.continued_fraction_terms().count()
+        );
+        prop_assert_eq!(convergents.last(), Some(&value));
+        for pair in convergents.windows(2) {
+            prop_assert!(
+                pair[0].denominator() <= pair[1].denominator()
+            );
+            prop_assert!(
+                (&pair[1] - &value).abs() <= (&pair[0] - &value).abs()
+            );
+        }
+    }
+
+    #[test]
+    fn test_limit_denominator(
+        value in fractions(),
+        max_denominator in 1u8..,
+    ) {
+        let max_denominator = BigInt::from(max_denominator);
+        let result = value.limit_denominator(max_denominator.clone()).unwrap();
+
+        prop_assert!(result.denominator() <= &max_denominator);
+        let distance = (&result - &value).abs();
+        let mut denominator = BigInt::from(1u8);
+        while denominator <= max_denominator {
+            let scaled = &value * Fraction::from(denominator.clone());
+            for numerator in [(&scaled).floor(), scaled.ceil()] {
+                let candidate =
+                    Fraction::new(numerator, denominator.clone()).unwrap();
+                prop_assert!(distance <= (&candidate - &value).abs());
+            }
+            denominator += BigInt::from(1u8);
+        }
+    }
+}
+
+#[test]
+fn test_limit_denominator_invalid() {
+    let value = Fraction::new(BigInt::from(1u8), BigInt::from(3u8)).unwrap();
+
+    assert_eq!(value.limit_denominator(BigInt::from(0u8)), None);
+    assert_eq!(value.limit_denominator(BigInt::from(-1i8)), None);
+}
+
+#[test]
+fn test_limit_denominator_pi() {
+    let pi = Fraction::new(
+        BigInt::from(3_141_592_653_589_793u64),
+        BigInt::from(1_000_000_000_000_000u64),
+    )
+    .unwrap();
+
+    assert_eq!(
+        pi.limit_denominator(BigInt::from(10u8)),
+        Fraction::new(BigInt::from(22u8), BigInt::from(7u8))
+    );
+    assert_eq!(
+        pi.limit_denominator(BigInt::from(1000u16)),
+        Fraction::new(BigInt::from(355u16), BigInt::from(113u8))
+    );
+}
@@ -0,0 +1,185 @@
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use traiter::numbers::{Abs, Pow, TieBreaking};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+fn power_of_ten(exponent: usize) -> BigInt {
+    BigInt::from(10u8).pow(BigInt::from(exponent))
+}
+
+fn parse_positional(string: &str) -> Fraction {
+    let (integer_part, fractional_part) =
+        string.split_once('.').unwrap_or((string, ""));
+    Fraction::new(
+        BigInt::try_from([integer_part, fractional_part].concat().as_str())
+            .unwrap(),
+        power_of_ten(fractional_part.len()),
+    )
+    .unwrap()
+}
+
+fn parse_repeating(string: &str) -> Fraction {
+    let Some((prefix, period)) = string.split_once('(') else {
+        return parse_positional(string);
+    };
+    let period = period.strip_suffix(')').unwrap();
+    let non_repeating_digits_count = prefix.split_once('.').unwrap().1.len();
+    let period_value = Fraction::new(
+        BigInt::try_from(period).unwrap(),
+        power_of_ten(non_repeating_digits_count)
+            * (power_of_ten(period.len()) - BigInt::from(1u8)),
+    )
+    .unwrap();
+    let prefix_value = parse_positional(prefix);
+    if prefix.starts_with('-') {
+        prefix_value - period_value
+    } else {
+        prefix_value + period_value
+    }
+}
+
+fn half_unit(fractional_digits_count: usize) -> Fraction {
+    Fraction::new(
+        BigInt::from(1u8),
+        BigInt::from(2u8) * power_of_ten(fractional_digits_count),
+    )
+    .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn test_fixed(value in fractions(), digits_count in 0usize..20) {
+        let string =
+            value.to_fixed_string(digits_count, TieBreaking::ToEven);
+
+        prop_assert!(
+            (parse_positional(&string) - &value).abs()
+                <= half_unit(digits_count)
+        );
+    }
+
+    #[test]
+    fn test_scientific(value in fractions(), digits_count in 0usize..20) {
+        let string = value
+            .to_scientific_string(digits_count, TieBreaking::ToEven)
+            .unwrap();
+        let (significand, exponent) = string.split_once('e').unwrap();
+        let significand = parse_positional(significand);
+        let exponent = exponent.parse::<isize>().unwrap();
+        let scale = Fraction::from(power_of_ten(exponent.unsigned_abs()));
+        let scaled_value = if exponent >= 0 {
+            &value / &scale
+        } else {
+            &value * &scale
+        };
+
+        prop_assert!(
+            (&significand - &scaled_value).abs() <= half_unit(digits_count)
+        );
+        if value != BigInt::from(0u8) {
+            let significand_modulus = significand.abs();
+            prop_assert!(significand_modulus >= BigInt::from(1u8));
+            prop_assert!(significand_modulus < BigInt::from(10u8));
+        }
+    }
+
+    #[test]
+    fn test_repeating_round_trip(value in fractions()) {
+        if let Some(string) = value.to_repeating_decimal_string(1000) {
+            prop_assert_eq!(parse_repeating(&string), value);
+        }
+    }
+
+    #[test]
+    fn test_repeating_round_trip_small(
+        numerator in any::<i16>(),
+        denominator in 1i16..,
+    ) {
+        let value = Fraction::new(
+            BigInt::from(numerator),
+            BigInt::from(denominator),
+        )
+        .unwrap();
+        let string = value
+            .to_repeating_decimal_string(usize::from(u16::MAX))
+            .unwrap();
+
+        prop_assert_eq!(parse_repeating(&string), value);
+    }
+}
+
+#[test]
+fn test_scientific_digits_count_overflow() {
+    let value = Fraction::new(BigInt::from(1u8), BigInt::from(3u8)).unwrap();
+
+    assert_eq!(
+        value.to_scientific_string(usize::MAX, TieBreaking::ToEven),
+        None
+    );
+}
+
+#[test]
+fn test_scientific_exponent_boundaries() {
+    for (numerator, denominator, expected) in [
+        (999u16, 1u16, "9.99e2"),
+        (1000, 1, "1.00e3"),
+        (9995, 10, "1.00e3"),
+        (1, 1000, "1.00e-3"),
+        (1, 999, "1.00e-3"),
+        (1, 1001, "9.99e-4"),
+        (1023, 1024, "9.99e-1"),
+        (1024, 1023, "1.00e0"),
+    ] {
+        let value =
+            Fraction::new(BigInt::from(numerator), BigInt::from(denominator))
+                .unwrap();
+
+        assert_eq!(
+            value.to_scientific_string(2, TieBreaking::ToEven),
+            Some(expected.to_string())
+        );
+    }
+}
+
+#[test]
+fn test_repeating_known_values() {
+    for (numerator, denominator, expected) in [
+        (1i8, 6u8, "0.1(6)"),
+        (-1, 3, "-0.(3)"),
+        (1, 7, "0.(142857)"),
+        (7, 4, "1.75"),
+        (22, 1, "22"),
+    ] {
+        let value =
+            Fraction::new(BigInt::from(numerator), BigInt::from(denominator))
+                .unwrap();
+
+        assert_eq!(
+            value.to_repeating_decimal_string(6),
+            Some(expected.to_string())
+        );
+    }
+}
+
+#[test]
+fn test_repeating_digits_limit() {
+    let one_seventh =
+        Fraction::new(BigInt::from(1u8), BigInt::from(7u8)).unwrap();
+    let one_sixtieth =
+        Fraction::new(BigInt::from(1u8), BigInt::from(60u8)).unwrap();
+
+    assert_eq!(one_seventh.to_repeating_decimal_string(5), None);
+    assert_eq!(one_sixtieth.to_repeating_decimal_string(2), None);
+    assert_eq!(
+        one_sixtieth.to_repeating_decimal_string(3),
+        Some("0.01(6)".to_string())
+    );
+    let huge_period = Fraction::new(
+        BigInt::from(1u8),
+        BigInt::from(2u8).pow(BigInt::from(127u8)) - BigInt::from(1u8),
+    )
+    .unwrap();
+    assert_eq!(huge_period.to_repeating_decimal_string(1000), None);
+}
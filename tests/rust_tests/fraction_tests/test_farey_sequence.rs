@@ -0,0 +1,61 @@
+use proptest::prelude::*;
+use traiter::numbers::Gcd;
+
+use crate::strategies::{BigInt, Fraction};
+
+fn totients_sum(order: u16) -> usize {
+    (1..=order)
+        .map(|denominator| {
+            (1..=denominator)
+                .filter(|&numerator| numerator.gcd(denominator) == 1)
+                .count()
+        })
+        .sum()
+}
+
+proptest! {
+    #[test]
+    fn test_properties(order in 1u16..48) {
+        let terms = Fraction::farey_sequence(BigInt::from(order))
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        prop_assert_eq!(terms.len(), totients_sum(order) + 1);
+        prop_assert_eq!(
+            terms.first(),
+            Some(&Fraction::from(BigInt::from(0u8)))
+        );
+        prop_assert_eq!(
+            terms.last(),
+            Some(&Fraction::from(BigInt::from(1u8)))
+        );
+        prop_assert!(terms
+            .iter()
+            .all(|term| term.denominator() <= &BigInt::from(order)));
+        let are_neighbours = terms.windows(2).all(|window| {
+            window[0].denominator() * window[1].numerator()
+                - window[0].numerator() * window[1].denominator()
+                == 1u8
+        });
+        prop_assert!(are_neighbours);
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    assert!(Fraction::farey_sequence(BigInt::from(0u8)).is_none());
+    assert!(Fraction::farey_sequence(BigInt::from(-1i8)).is_none());
+    assert_eq!(
+        Fraction::farey_sequence(BigInt::from(1u8))
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![
+            Fraction::from(BigInt::from(0u8)),
+            Fraction::from(BigInt::from(1u8)),
+        ]
+    );
+    assert_eq!(
+        Fraction::farey_sequence(BigInt::from(5u8)).unwrap().count(),
+        11
+    );
+}
@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_between(first in fractions(), second in fractions()) {
+        let result = first.mediant(&second);
+
+        if first < second {
+            prop_assert!(first < result && result < second);
+        } else if first > second {
+            prop_assert!(second < result && result < first);
+        } else {
+            prop_assert_eq!(result, first);
+        }
+    }
+
+    #[test]
+    fn test_commutativity(first in fractions(), second in fractions()) {
+        prop_assert_eq!(first.mediant(&second), second.mediant(&first));
+    }
+}
+
+#[test]
+fn test_neighbours() {
+    let zero = Fraction::from(BigInt::from(0u8));
+    let one = Fraction::from(BigInt::from(1u8));
+
+    assert_eq!(
+        zero.mediant(&one),
+        Fraction::new(BigInt::from(1u8), BigInt::from(2u8)).unwrap()
+    );
+    assert_eq!(
+        Fraction::new(BigInt::from(1u8), BigInt::from(3u8))
+            .unwrap()
+            .mediant(
+                &Fraction::new(BigInt::from(2u8), BigInt::from(3u8)).unwrap()
+            ),
+        Fraction::new(BigInt::from(1u8), BigInt::from(2u8)).unwrap()
+    );
+}
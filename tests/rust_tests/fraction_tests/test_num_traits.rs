@@ -0,0 +1,191 @@
+use std::convert::TryFrom;
+
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive,
+    Num, ToPrimitive,
+};
+use proptest::prelude::*;
+use traiter::numbers::{
+    Abs, FromStrRadix, One, Pow, Sign, Signed, Trunc, Zero, Zeroable,
+};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+fn non_zero_fractions() -> impl Strategy<Value = Fraction> {
+    fractions().prop_filter("zero", |value| !value.is_zero())
+}
+
+proptest! {
+    #[test]
+    fn test_from_str_radix(value in fractions(), radix in 2u32..=36) {
+        let string = value.to_string();
+
+        prop_assert_eq!(
+            <Fraction as Num>::from_str_radix(&string, 10),
+            <Fraction as FromStrRadix>::from_str_radix(&string, 10)
+        );
+        prop_assert_eq!(
+            <Fraction as Num>::from_str_radix(&string, radix),
+            <Fraction as FromStrRadix>::from_str_radix(&string, radix)
+        );
+    }
+
+    #[test]
+    fn test_signed(first in fractions(), second in fractions()) {
+        prop_assert_eq!(
+            num_traits::Signed::abs(&first),
+            Abs::abs(&first)
+        );
+        prop_assert_eq!(
+            num_traits::Signed::is_positive(&first),
+            Signed::is_positive(&first)
+        );
+        prop_assert_eq!(
+            num_traits::Signed::is_negative(&first),
+            Signed::is_negative(&first)
+        );
+        prop_assert_eq!(
+            num_traits::Signed::signum(&first),
+            match Signed::sign(&first) {
+                Sign::Negative => -Fraction::one(),
+                Sign::Positive => Fraction::one(),
+                Sign::Zero => Fraction::zero(),
+            }
+        );
+        prop_assert_eq!(
+            num_traits::Signed::abs_sub(&first, &second),
+            if first <= second {
+                Fraction::zero()
+            } else {
+                &first - &second
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_primitive(value in fractions()) {
+        let truncated = (&value).trunc();
+
+        prop_assert_eq!(value.to_i64(), i64::try_from(&truncated).ok());
+        prop_assert_eq!(value.to_i128(), i128::try_from(&truncated).ok());
+        prop_assert_eq!(value.to_u64(), u64::try_from(&truncated).ok());
+        prop_assert_eq!(value.to_u128(), u128::try_from(&truncated).ok());
+        prop_assert_eq!(value.to_f64(), f64::try_from(&value).ok());
+    }
+
+    #[test]
+    fn test_from_primitive(
+        signed in any::<i128>(),
+        unsigned in any::<u128>(),
+        float in any::<f64>(),
+    ) {
+        prop_assert_eq!(
+            Fraction::from_i64(signed as i64),
+            Some(Fraction::from(BigInt::from(signed as i64)))
+        );
+        prop_assert_eq!(
+            Fraction::from_i128(signed),
+            Some(Fraction::from(BigInt::from(signed)))
+        );
+        prop_assert_eq!(
+            Fraction::from_u64(unsigned as u64),
+            Some(Fraction::from(BigInt::from(unsigned as u64)))
+        );
+        prop_assert_eq!(
+            Fraction::from_u128(unsigned),
+            Some(Fraction::from(BigInt::from(unsigned)))
+        );
+        prop_assert_eq!(
+            Fraction::from_f64(float),
+            Fraction::try_from(float).ok()
+        );
+    }
+
+    #[test]
+    fn test_pow(base in non_zero_fractions(), exponent in -8i8..8) {
+        let expected = Pow::pow(&base, BigInt::from(exponent));
+
+        prop_assert_eq!(
+            num_traits::Pow::pow(&base, exponent),
+            expected.clone()
+        );
+        prop_assert_eq!(
+            num_traits::Pow::pow(base.clone(), exponent),
+            expected.clone()
+        );
+        prop_assert_eq!(
+            num_traits::Pow::pow(&base, &BigInt::from(exponent)),
+            expected.clone()
+        );
+        prop_assert_eq!(
+            num_traits::Pow::pow(base, BigInt::from(exponent)),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic(first in fractions(), second in fractions()) {
+        prop_assert_eq!(first.checked_add(&second), Some(&first + &second));
+        prop_assert_eq!(first.checked_sub(&second), Some(&first - &second));
+        prop_assert_eq!(first.checked_mul(&second), Some(&first * &second));
+        prop_assert_eq!(
+            CheckedDiv::checked_div(&first, &second),
+            traiter::numbers::CheckedDiv::checked_div(&first, &second)
+        );
+        prop_assert_eq!(
+            CheckedRem::checked_rem(&first, &second),
+            traiter::numbers::CheckedRem::checked_rem(&first, &second)
+        );
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    let half = Fraction::new(BigInt::from(1u8), BigInt::from(2u8)).unwrap();
+
+    assert_eq!(
+        (Fraction::from(BigInt::from(i64::MAX)) + &half).to_i64(),
+        Some(i64::MAX)
+    );
+    assert_eq!(
+        (Fraction::from(BigInt::from(i64::MIN)) - &half).to_i64(),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        (Fraction::from(BigInt::from(i64::MAX)) + Fraction::one()).to_i64(),
+        None
+    );
+    assert_eq!(
+        (Fraction::from(BigInt::from(i64::MIN)) - Fraction::one()).to_i64(),
+        None
+    );
+    assert_eq!(
+        (Fraction::from(BigInt::from(u64::MAX)) + &half).to_u64(),
+        Some(u64::MAX)
+    );
+    assert_eq!(
+        (Fraction::from(BigInt::from(u64::MAX)) + Fraction::one()).to_u64(),
+        None
+    );
+    assert_eq!((-&half).to_u64(), Some(0));
+    assert_eq!((-Fraction::one()).to_u64(), None);
+    assert_eq!(Fraction::from_f64(f64::NAN), None);
+    assert_eq!(Fraction::from_f64(f64::INFINITY), None);
+    assert_eq!(Fraction::from_f64(0.5), Some(half));
+    assert!(<Fraction as Num>::from_str_radix("1/2", 1).is_err());
+    assert!(<Fraction as Num>::from_str_radix("1/2", 37).is_err());
+    assert!(<Fraction as Num>::from_str_radix("1/2", 2).is_err());
+    assert!(<Fraction as Num>::from_str_radix("1/0", 10).is_err());
+    assert_eq!(
+        <Fraction as Num>::from_str_radix("-f/10", 16),
+        Ok(Fraction::new(BigInt::from(-15i8), BigInt::from(16u8)).unwrap())
+    );
+    assert!(
+        CheckedDiv::checked_div(&Fraction::one(), &Fraction::zero()).is_none()
+    );
+    assert!(
+        CheckedRem::checked_rem(&Fraction::one(), &Fraction::zero()).is_none()
+    );
+    assert!(num_traits::Zero::is_zero(&Fraction::zero()));
+    assert!(num_traits::One::is_one(&Fraction::one()));
+}
@@ -0,0 +1,76 @@
+use proptest::prelude::*;
+use rithm::{fraction, NumericHash};
+use traiter::numbers::One;
+
+use crate::strategies::{
+    big_ints, i128_fractions, i64_fractions, i8_fractions, u128_fractions,
+    u8_fractions, BigInt, Fraction,
+};
+
+macro_rules! primitive_fraction_numeric_hash_tests {
+    ($($module:ident => $fractions:ident)*) => ($(
+        mod $module {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn test_big_int_connection(value in $fractions()) {
+                    prop_assert_eq!(
+                        Fraction::from(value.clone()).numeric_hash(),
+                        value.numeric_hash()
+                    );
+                }
+            }
+        }
+    )*)
+}
+
+primitive_fraction_numeric_hash_tests!(
+    i8_fractions_tests => i8_fractions
+    i64_fractions_tests => i64_fractions
+    i128_fractions_tests => i128_fractions
+    u8_fractions_tests => u8_fractions
+    u128_fractions_tests => u128_fractions
+);
+
+proptest! {
+    #[test]
+    fn test_integer_connection(value in big_ints()) {
+        prop_assert_eq!(
+            Fraction::from(value.clone()).numeric_hash(),
+            value.numeric_hash()
+        );
+    }
+
+    #[test]
+    fn test_float_connection(numerator in any::<i32>(), exponent in 0..64u32) {
+        let denominator = 1u64 << exponent;
+        let value =
+            Fraction::new(BigInt::from(numerator), BigInt::from(denominator))
+                .unwrap();
+
+        prop_assert_eq!(
+            value.numeric_hash(),
+            (f64::from(numerator) / denominator as f64).numeric_hash()
+        );
+    }
+
+}
+
+#[test]
+fn test_boundaries() {
+    assert_eq!(
+        Fraction::new(BigInt::one(), BigInt::from(2u8))
+            .unwrap()
+            .numeric_hash(),
+        0.5f64.numeric_hash()
+    );
+    assert_eq!(
+        fraction::Fraction::new(i128::MIN, i128::MAX)
+            .unwrap()
+            .numeric_hash(),
+        Fraction::new(BigInt::from(i128::MIN), BigInt::from(i128::MAX))
+            .unwrap()
+            .numeric_hash()
+    );
+}
@@ -0,0 +1,64 @@
+use proptest::prelude::*;
+use rithm::big_int::FromOrderPreservingBytesError;
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in fractions(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_order_preserving_bytes();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(
+            Fraction::from_order_preserving_bytes(&bytes),
+            Ok((value, size))
+        );
+    }
+
+    #[test]
+    fn test_order(first in fractions(), second in fractions()) {
+        prop_assert_eq!(
+            first
+                .to_order_preserving_bytes()
+                .cmp(&second.to_order_preserving_bytes()),
+            first.cmp(&second)
+        );
+    }
+
+    #[test]
+    fn test_composite_keys_order(
+        first in (fractions(), fractions()),
+        second in (fractions(), fractions()),
+    ) {
+        let to_key = |(major, minor): &(Fraction, Fraction)| {
+            let mut result = major.to_order_preserving_bytes();
+            result.extend(minor.to_order_preserving_bytes());
+            result
+        };
+
+        prop_assert_eq!(to_key(&first).cmp(&to_key(&second)), first.cmp(&second));
+    }
+}
+
+#[test]
+fn test_non_canonical() {
+    let mut bytes =
+        Fraction::from(BigInt::from(1u8)).to_order_preserving_bytes();
+    bytes.pop();
+    bytes.extend(
+        Fraction::from(BigInt::from(1u8))
+            .to_order_preserving_bytes()
+            .into_iter()
+            .map(|byte| !byte),
+    );
+
+    assert_eq!(
+        Fraction::from_order_preserving_bytes(&bytes),
+        Err(FromOrderPreservingBytesError::NonCanonical)
+    );
+    assert_eq!(
+        Fraction::from_order_preserving_bytes(&bytes[..1]),
+        Err(FromOrderPreservingBytesError::Truncated)
+    );
+}
@@ -0,0 +1,143 @@
+use proptest::prelude::*;
+use traiter::numbers::{Pow, TieBreaking};
+
+use crate::strategies::{big_ints, BigInt, Fraction};
+
+const MAX_DISPLAY_SCALE: usize = 0x3fff;
+
+fn terminating_fractions() -> impl Strategy<Value = Fraction> {
+    (big_ints(), 0u32..64, 0u32..64).prop_map(
+        |(numerator, twos_exponent, fives_exponent)| {
+            Fraction::new(
+                numerator,
+                BigInt::from(2u8).pow(BigInt::from(twos_exponent))
+                    * BigInt::from(5u8).pow(BigInt::from(fives_exponent)),
+            )
+            .unwrap()
+        },
+    )
+}
+
+fn power_of_two_reciprocal(exponent: u32) -> Fraction {
+    Fraction::new(
+        BigInt::from(1u8),
+        BigInt::from(2u8).pow(BigInt::from(exponent)),
+    )
+    .unwrap()
+}
+
+fn power_of_five_reciprocal(exponent: u32) -> Fraction {
+    Fraction::new(
+        BigInt::from(1u8),
+        BigInt::from(5u8).pow(BigInt::from(exponent)),
+    )
+    .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn test_terminating_round_trip(
+        value in terminating_fractions(),
+        non_terminating_scale in 0usize..32,
+    ) {
+        let bytes = value
+            .to_pg_numeric(non_terminating_scale, TieBreaking::ToEven)
+            .unwrap();
+
+        prop_assert_eq!(Fraction::from_pg_numeric(&bytes), Ok(value));
+    }
+}
+
+#[test]
+fn test_non_terminating() {
+    let value = Fraction::new(BigInt::from(2u8), BigInt::from(3u8)).unwrap();
+
+    assert_eq!(
+        Fraction::from_pg_numeric(
+            &value.to_pg_numeric(5, TieBreaking::ToEven).unwrap()
+        ),
+        Ok(
+            Fraction::new(BigInt::from(66667u32), BigInt::from(100000u32))
+                .unwrap()
+        )
+    );
+    assert_eq!(
+        value.to_pg_numeric(MAX_DISPLAY_SCALE + 1, TieBreaking::ToEven),
+        None
+    );
+}
+
+#[test]
+fn test_scale_limit() {
+    let value = power_of_two_reciprocal(MAX_DISPLAY_SCALE as u32);
+
+    assert_eq!(
+        Fraction::from_pg_numeric(
+            &value.to_pg_numeric(0, TieBreaking::ToEven).unwrap()
+        ),
+        Ok(value)
+    );
+    assert_eq!(
+        power_of_two_reciprocal(MAX_DISPLAY_SCALE as u32 + 1)
+            .to_pg_numeric(0, TieBreaking::ToEven),
+        None
+    );
+    assert_eq!(
+        power_of_two_reciprocal(20000).to_pg_numeric(10, TieBreaking::ToEven),
+        None
+    );
+}
+
+#[test]
+fn test_non_terminating_with_large_terminating_factor() {
+    let value = Fraction::new(
+        BigInt::from(1u8),
+        BigInt::from(3u8) * BigInt::from(2u8).pow(BigInt::from(20000u32)),
+    )
+    .unwrap();
+
+    assert_eq!(
+        Fraction::from_pg_numeric(
+            &value.to_pg_numeric(10, TieBreaking::ToEven).unwrap()
+        ),
+        Ok(Fraction::from(BigInt::from(0u8)))
+    );
+}
+
+#[test]
+fn test_fives_scale_limit() {
+    let value = power_of_five_reciprocal(MAX_DISPLAY_SCALE as u32);
+
+    assert_eq!(
+        Fraction::from_pg_numeric(
+            &value.to_pg_numeric(0, TieBreaking::ToEven).unwrap()
+        ),
+        Ok(value)
+    );
+    assert_eq!(
+        power_of_five_reciprocal(MAX_DISPLAY_SCALE as u32 + 1)
+            .to_pg_numeric(0, TieBreaking::ToEven),
+        None
+    );
+    assert_eq!(
+        power_of_five_reciprocal(20000).to_pg_numeric(10, TieBreaking::ToEven),
+        None
+    );
+}
+
+#[test]
+fn test_mixed_factors_scale() {
+    let value = Fraction::new(
+        BigInt::from(7u8),
+        BigInt::from(2u8).pow(BigInt::from(100u8))
+            * BigInt::from(5u8).pow(BigInt::from(30u8)),
+    )
+    .unwrap();
+
+    assert_eq!(
+        Fraction::from_pg_numeric(
+            &value.to_pg_numeric(0, TieBreaking::ToEven).unwrap()
+        ),
+        Ok(value)
+    );
+}
@@ -0,0 +1,28 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::One;
+
+use crate::strategies::{fractions, Fraction};
+
+proptest! {
+    #[test]
+    fn test_matches_fold(values in vec(fractions(), 0..32)) {
+        let expected = values
+            .iter()
+            .fold(Fraction::one(), |product, value| product * value);
+
+        prop_assert_eq!(
+            values.iter().product::<Fraction>(),
+            expected.clone()
+        );
+        prop_assert_eq!(values.into_iter().product::<Fraction>(), expected);
+    }
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(
+        Vec::<Fraction>::new().into_iter().product::<Fraction>(),
+        Fraction::one()
+    );
+}
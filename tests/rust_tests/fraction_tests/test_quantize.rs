@@ -0,0 +1,168 @@
+use proptest::prelude::*;
+use traiter::numbers::{
+    Abs, CheckedDiv, One, Pow, TieBreaking, Unitary, Zero, Zeroable,
+};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+fn non_zero_fractions() -> impl Strategy<Value = Fraction> {
+    fractions().prop_filter("zero", |value| !value.is_zero())
+}
+
+fn tie_breakings() -> impl Strategy<Value = TieBreaking> {
+    prop_oneof![
+        Just(TieBreaking::AwayFromZero),
+        Just(TieBreaking::ToEven),
+        Just(TieBreaking::ToOdd),
+        Just(TieBreaking::TowardZero),
+    ]
+}
+
+fn is_multiple(value: &Fraction, step: &Fraction) -> bool {
+    value.checked_div(step).unwrap().denominator().is_one()
+}
+
+fn digits_step(digits_count: isize, base: usize) -> Fraction {
+    let scale =
+        BigInt::from(base).pow(BigInt::from(digits_count.unsigned_abs()));
+    if digits_count.is_negative() {
+        Fraction::from(scale)
+    } else {
+        Fraction::new(BigInt::one(), scale).unwrap()
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_floor_to_multiple(
+        value in fractions(),
+        step in non_zero_fractions(),
+    ) {
+        let result = value.floor_to_multiple(&step).unwrap();
+        let step_modulus = (&step).abs();
+
+        prop_assert!(is_multiple(&result, &step));
+        prop_assert!(result <= value);
+        prop_assert!(value < &result + &step_modulus);
+    }
+
+    #[test]
+    fn test_ceil_to_multiple(
+        value in fractions(),
+        step in non_zero_fractions(),
+    ) {
+        let result = value.ceil_to_multiple(&step).unwrap();
+        let step_modulus = (&step).abs();
+
+        prop_assert!(is_multiple(&result, &step));
+        prop_assert!(result >= value);
+        prop_assert!(value > &result - &step_modulus);
+    }
+
+    #[test]
+    fn test_round_to_multiple(
+        value in fractions(),
+        step in non_zero_fractions(),
+        tie_breaking in tie_breakings(),
+    ) {
+        let result = value.round_to_multiple(&step, tie_breaking).unwrap();
+        let half_step =
+            (&step).abs() / Fraction::from(BigInt::from(2u8));
+
+        prop_assert!(is_multiple(&result, &step));
+        prop_assert!((&result - &value).abs() <= half_step);
+    }
+
+    #[test]
+    fn test_to_digits_connection(
+        value in fractions(),
+        digits_count in -8isize..8,
+        base in 2usize..17,
+        tie_breaking in tie_breakings(),
+    ) {
+        let step = digits_step(digits_count, base);
+
+        prop_assert_eq!(
+            value.floor_to_digits(digits_count, base),
+            value.floor_to_multiple(&step)
+        );
+        prop_assert_eq!(
+            value.ceil_to_digits(digits_count, base),
+            value.ceil_to_multiple(&step)
+        );
+        prop_assert_eq!(
+            value.round_to_digits(digits_count, base, tie_breaking),
+            value.round_to_multiple(&step, tie_breaking)
+        );
+    }
+
+    #[test]
+    fn test_idempotence(
+        value in fractions(),
+        step in non_zero_fractions(),
+    ) {
+        let result = value.floor_to_multiple(&step).unwrap();
+
+        prop_assert_eq!(result.ceil_to_multiple(&step), Some(result.clone()));
+        prop_assert_eq!(
+            result.round_to_multiple(&step, TieBreaking::ToEven),
+            Some(result)
+        );
+    }
+}
+
+#[test]
+fn test_zero_step() {
+    let value = Fraction::from(BigInt::one());
+
+    assert_eq!(value.floor_to_multiple(&Fraction::zero()), None);
+    assert_eq!(value.ceil_to_multiple(&Fraction::zero()), None);
+    assert_eq!(
+        value.round_to_multiple(&Fraction::zero(), TieBreaking::ToEven),
+        None
+    );
+}
+
+#[test]
+fn test_invalid_base() {
+    let value = Fraction::from(BigInt::one());
+
+    for base in [0, 1] {
+        assert_eq!(value.floor_to_digits(1, base), None);
+        assert_eq!(value.ceil_to_digits(1, base), None);
+        assert_eq!(value.round_to_digits(1, base, TieBreaking::ToEven), None);
+    }
+}
+
+#[test]
+fn test_ties() {
+    let half = Fraction::new(BigInt::one(), BigInt::from(2u8)).unwrap();
+    let step = Fraction::from(BigInt::one());
+
+    assert_eq!(
+        half.round_to_multiple(&step, TieBreaking::ToEven),
+        Some(Fraction::zero())
+    );
+    assert_eq!(
+        half.round_to_multiple(&step, TieBreaking::AwayFromZero),
+        Some(step.clone())
+    );
+    assert_eq!(
+        (-&half).round_to_multiple(&step, TieBreaking::AwayFromZero),
+        Some(-step)
+    );
+    assert_eq!(
+        Fraction::from(BigInt::from(1250u16)).round_to_digits(
+            -2,
+            10,
+            TieBreaking::ToEven
+        ),
+        Some(Fraction::from(BigInt::from(1200u16)))
+    );
+    assert_eq!(
+        Fraction::new(BigInt::one(), BigInt::from(3u8))
+            .unwrap()
+            .round_to_digits(2, 10, TieBreaking::ToEven),
+        Some(Fraction::new(BigInt::from(33u8), BigInt::from(100u8)).unwrap())
+    );
+}
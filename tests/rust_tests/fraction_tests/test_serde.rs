@@ -0,0 +1,31 @@
+use proptest::prelude::*;
+
+use crate::strategies::{fractions, Fraction};
+
+proptest! {
+    #[test]
+    fn test_human_readable_round_trip(value in fractions()) {
+        let serialized = serde_json::to_string(&value).unwrap();
+
+        prop_assert_eq!(serde_json::from_str::<Fraction>(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn test_compact_round_trip(value in fractions()) {
+        let serialized = rmp_serde::to_vec(&value).unwrap();
+
+        prop_assert_eq!(rmp_serde::from_slice::<Fraction>(&serialized).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_non_ascii_characters() {
+    assert!(serde_json::from_str::<Fraction>("\"1€\"").is_err());
+    assert!(serde_json::from_str::<Fraction>("\"1/2€\"").is_err());
+}
+
+#[test]
+fn test_zero_denominator() {
+    assert!(serde_json::from_str::<Fraction>("\"1/0\"").is_err());
+    assert!(rmp_serde::from_slice::<Fraction>(&[0x92, 0x01, 0x00]).is_err());
+}
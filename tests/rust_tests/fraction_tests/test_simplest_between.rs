@@ -0,0 +1,87 @@
+use proptest::prelude::*;
+use traiter::numbers::Floor;
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+fn small_fractions() -> impl Strategy<Value = Fraction> {
+    (any::<i8>(), 1u8..32).prop_map(|(numerator, denominator)| {
+        Fraction::new(BigInt::from(numerator), BigInt::from(denominator))
+            .unwrap()
+    })
+}
+
+fn is_strictly_between(
+    value: &Fraction,
+    first: &Fraction,
+    second: &Fraction,
+) -> bool {
+    (first < value && value < second) || (second < value && value < first)
+}
+
+proptest! {
+    #[test]
+    fn test_between(first in fractions(), second in fractions()) {
+        let result = first.simplest_between(&second);
+
+        if first == second {
+            prop_assert!(result.is_none());
+        } else {
+            prop_assert!(is_strictly_between(
+                &result.unwrap(),
+                &first,
+                &second
+            ));
+        }
+    }
+
+    #[test]
+    fn test_commutativity(first in fractions(), second in fractions()) {
+        prop_assert_eq!(
+            first.simplest_between(&second),
+            second.simplest_between(&first)
+        );
+    }
+
+    #[test]
+    fn test_simplest(first in small_fractions(), second in small_fractions()) {
+        prop_assume!(first != second);
+
+        let result = first.simplest_between(&second).unwrap();
+
+        let (lower, upper) = if first < second {
+            (&first, &second)
+        } else {
+            (&second, &first)
+        };
+        let mut denominator = BigInt::from(1u8);
+        while &denominator < result.denominator() {
+            let candidate = Fraction::new(
+                (lower * &denominator).floor() + BigInt::from(1u8),
+                denominator.clone(),
+            )
+            .unwrap();
+            prop_assert!(&candidate >= upper);
+            denominator += BigInt::from(1u8);
+        }
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    let zero = Fraction::from(BigInt::from(0u8));
+    let one = Fraction::from(BigInt::from(1u8));
+
+    assert_eq!((-&one).simplest_between(&one), Some(zero.clone()));
+    assert_eq!(
+        zero.simplest_between(&one),
+        Some(Fraction::new(BigInt::from(1u8), BigInt::from(2u8)).unwrap())
+    );
+    assert_eq!(
+        Fraction::new(BigInt::from(3u8), BigInt::from(10u8))
+            .unwrap()
+            .simplest_between(
+                &Fraction::new(BigInt::from(4u8), BigInt::from(10u8)).unwrap()
+            ),
+        Some(Fraction::new(BigInt::from(1u8), BigInt::from(3u8)).unwrap())
+    );
+}
@@ -0,0 +1,71 @@
+use proptest::prelude::*;
+use rithm::fraction::SternBrocotDirection;
+use traiter::numbers::{Abs, Signed, Zeroable};
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in fractions()) {
+        prop_assume!(!(&value).is_zero());
+        let value = (&value).abs();
+
+        let path = value.stern_brocot_path().unwrap().collect::<Vec<_>>();
+
+        prop_assert_eq!(Fraction::from_stern_brocot_path(path), Some(value));
+    }
+
+    #[test]
+    fn test_path_shape(value in fractions()) {
+        prop_assume!(!(&value).is_zero());
+
+        let path = (&value)
+            .abs()
+            .stern_brocot_path()
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        prop_assert!(path
+            .iter()
+            .all(|(_, steps_count)| steps_count.is_positive()));
+        prop_assert!(path
+            .windows(2)
+            .all(|window| window[0].0 != window[1].0));
+    }
+
+    #[test]
+    fn test_non_positive(value in fractions()) {
+        prop_assume!(value.numerator() <= &BigInt::from(0u8));
+
+        prop_assert!(value.stern_brocot_path().is_none());
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    let one = Fraction::from(BigInt::from(1u8));
+
+    assert_eq!(one.stern_brocot_path().unwrap().count(), 0);
+    assert_eq!(
+        Fraction::from_stern_brocot_path(Vec::<(_, BigInt)>::new()),
+        Some(one)
+    );
+    assert_eq!(
+        Fraction::new(BigInt::from(3u8), BigInt::from(7u8))
+            .unwrap()
+            .stern_brocot_path()
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![
+            (SternBrocotDirection::Left, BigInt::from(2u8)),
+            (SternBrocotDirection::Right, BigInt::from(2u8)),
+        ]
+    );
+    assert_eq!(
+        Fraction::from_stern_brocot_path(vec![(
+            SternBrocotDirection::Right,
+            BigInt::from(-1i8),
+        )]),
+        None
+    );
+}
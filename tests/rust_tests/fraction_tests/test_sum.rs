@@ -0,0 +1,25 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::Zero;
+
+use crate::strategies::{fractions, Fraction};
+
+proptest! {
+    #[test]
+    fn test_matches_fold(values in vec(fractions(), 0..32)) {
+        let expected = values
+            .iter()
+            .fold(Fraction::zero(), |sum, value| sum + value);
+
+        prop_assert_eq!(values.iter().sum::<Fraction>(), expected.clone());
+        prop_assert_eq!(values.into_iter().sum::<Fraction>(), expected);
+    }
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(
+        Vec::<Fraction>::new().into_iter().sum::<Fraction>(),
+        Fraction::zero()
+    );
+}
@@ -0,0 +1,102 @@
+use proptest::prelude::*;
+use rithm::big_int::{FloatOverflow, FloatRounding, TryIntoFloatError};
+use traiter::numbers::Pow;
+
+use crate::strategies::{fractions, BigInt, Fraction};
+
+proptest! {
+    #[test]
+    fn test_nearest_even(numerator in any::<i32>(), denominator in 1i32..) {
+        let value = Fraction::new(
+            BigInt::from(numerator),
+            BigInt::from(denominator),
+        )
+        .unwrap();
+
+        prop_assert_eq!(
+            value.try_to_f64_rounded(
+                FloatRounding::NearestEven,
+                FloatOverflow::Error
+            ),
+            Ok(f64::from(numerator) / f64::from(denominator))
+        );
+    }
+
+    #[test]
+    fn test_directed(value in fractions()) {
+        let round = |rounding| {
+            value
+                .try_to_f64_rounded(rounding, FloatOverflow::Error)
+                .unwrap()
+        };
+        let lower = round(FloatRounding::TowardNegative);
+        let upper = round(FloatRounding::TowardPositive);
+
+        prop_assert!(lower <= value && value <= upper);
+        if lower == upper {
+            prop_assert!(value == lower);
+        } else {
+            prop_assert!(
+                upper - lower <= f64::EPSILON * lower.abs().max(upper.abs())
+                    || upper.abs().min(lower.abs()) < f64::MIN_POSITIVE
+            );
+        }
+        let nearest = round(FloatRounding::NearestEven);
+        prop_assert!(nearest == lower || nearest == upper);
+    }
+}
+
+#[test]
+fn test_subnormal() {
+    let value = Fraction::new(
+        BigInt::from(1u8),
+        BigInt::from(2u8).pow(BigInt::from(1075u16)),
+    )
+    .unwrap();
+
+    assert_eq!(
+        value.try_to_f64_rounded(
+            FloatRounding::NearestEven,
+            FloatOverflow::Error
+        ),
+        Ok(0.0)
+    );
+    assert_eq!(
+        value.try_to_f64_rounded(
+            FloatRounding::TowardPositive,
+            FloatOverflow::Error
+        ),
+        Ok(f64::from_bits(1))
+    );
+}
+
+#[test]
+fn test_overflow() {
+    let value = Fraction::new(
+        BigInt::from(10u8).pow(BigInt::from(400u16)),
+        BigInt::from(3u8),
+    )
+    .unwrap();
+
+    assert_eq!(
+        value.try_to_f64_rounded(
+            FloatRounding::NearestEven,
+            FloatOverflow::Error
+        ),
+        Err(TryIntoFloatError::TooLarge)
+    );
+    assert_eq!(
+        value.try_to_f64_rounded(
+            FloatRounding::TowardZero,
+            FloatOverflow::Error
+        ),
+        Ok(f64::MAX)
+    );
+    assert_eq!(
+        (-value).try_to_f64_rounded(
+            FloatRounding::TowardPositive,
+            FloatOverflow::Error
+        ),
+        Ok(-f64::MAX)
+    );
+}
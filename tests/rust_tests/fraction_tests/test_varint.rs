@@ -0,0 +1,31 @@
+use proptest::prelude::*;
+use rithm::fraction::FromVarintPairError;
+
+use crate::strategies::{fractions, Fraction};
+
+proptest! {
+    #[test]
+    fn test_round_trip(value in fractions(), suffix in any::<Vec<u8>>()) {
+        let mut bytes = value.to_varint_pair();
+        let size = bytes.len();
+        bytes.extend(suffix);
+
+        prop_assert_eq!(Fraction::from_varint_pair(&bytes), Ok((value, size)));
+    }
+}
+
+#[test]
+fn test_non_normalized() {
+    assert_eq!(
+        Fraction::from_varint_pair(&[0x04, 0x04]),
+        Ok((Fraction::new(2u8.into(), 4u8.into()).unwrap(), 2))
+    );
+}
+
+#[test]
+fn test_zero_denominator() {
+    assert_eq!(
+        Fraction::from_varint_pair(&[0x02, 0x00]),
+        Err(FromVarintPairError::ZeroDenominator)
+    );
+}
@@ -0,0 +1,4 @@
+mod big_int_tests;
+mod fraction_tests;
+mod rational_tests;
+mod strategies;
@@ -0,0 +1,2 @@
+mod test_arithmetic;
+mod test_numeric_hash;
@@ -0,0 +1,302 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+use proptest::prelude::*;
+use traiter::numbers::{
+    Abs, Ceil, CheckedDiv, CheckedDivEuclid, CheckedDivRemEuclid, CheckedPow,
+    CheckedRem, CheckedRemEuclid, DivEuclid, Floor, Pow, RemEuclid, Round,
+    TieBreaking, Trunc, Zeroable,
+};
+
+use crate::strategies::{rationals, BigInt, Fraction, Rational};
+
+fn is_small(value: &Fraction) -> bool {
+    rithm::fraction::Fraction::<i64>::try_from(value).is_ok()
+}
+
+proptest! {
+    #[test]
+    fn test_ring_operations(first in rationals(), second in rationals()) {
+        let (first_exact, second_exact) =
+            (Fraction::from(&first), Fraction::from(&second));
+
+        for (result, expected) in [
+            (&first + &second, &first_exact + &second_exact),
+            (&first - &second, &first_exact - &second_exact),
+            (&first * &second, &first_exact * &second_exact),
+        ] {
+            prop_assert_eq!(result.is_small(), is_small(&expected));
+            prop_assert_eq!(Fraction::from(&result), expected);
+        }
+        prop_assert_eq!(
+            &first + &second,
+            first.clone() + second.clone()
+        );
+        prop_assert_eq!(
+            &first - &second,
+            first.clone() - second.clone()
+        );
+        prop_assert_eq!(
+            &first * &second,
+            first.clone() * second.clone()
+        );
+    }
+
+    #[test]
+    fn test_division_operations(
+        first in rationals(),
+        second in rationals(),
+    ) {
+        let (first_exact, second_exact) =
+            (Fraction::from(&first), Fraction::from(&second));
+
+        if (&second).is_zero() {
+            prop_assert!((&first).checked_div(&second).is_none());
+            prop_assert!((&first).checked_rem(&second).is_none());
+            prop_assert!((&first).checked_div_euclid(&second).is_none());
+            prop_assert!((&first).checked_rem_euclid(&second).is_none());
+            prop_assert!(
+                (&first).checked_div_rem_euclid(&second).is_none()
+            );
+        } else {
+            let quotient = &first / &second;
+            prop_assert_eq!(
+                quotient.is_small(),
+                is_small(&(&first_exact / &second_exact))
+            );
+            prop_assert_eq!(
+                Fraction::from(&quotient),
+                &first_exact / &second_exact
+            );
+            prop_assert_eq!(
+                (&first).checked_div(&second),
+                Some(first.clone() / second.clone())
+            );
+            prop_assert_eq!(
+                Fraction::from(&(&first % &second)),
+                &first_exact % &second_exact
+            );
+            prop_assert_eq!(
+                (&first).checked_rem(&second),
+                Some(first.clone() % second.clone())
+            );
+            prop_assert_eq!(
+                (&first).div_euclid(&second),
+                (&first_exact).div_euclid(&second_exact)
+            );
+            prop_assert_eq!(
+                (&first).checked_div_euclid(&second),
+                Some(first.clone().div_euclid(second.clone()))
+            );
+            prop_assert_eq!(
+                Fraction::from(&(&first).rem_euclid(&second)),
+                (&first_exact).rem_euclid(&second_exact)
+            );
+            prop_assert_eq!(
+                (&first).checked_rem_euclid(&second),
+                Some(first.clone().rem_euclid(second.clone()))
+            );
+            prop_assert_eq!(
+                (&first).checked_div_rem_euclid(&second),
+                first.clone().checked_div_rem_euclid(second.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn test_division_results_demotion(
+        first in rationals(),
+        second in rationals(),
+    ) {
+        prop_assume!(!(&second).is_zero());
+        let (first_exact, second_exact) =
+            (Fraction::from(&first), Fraction::from(&second));
+
+        let (quotient, remainder) =
+            (&first).checked_div_rem_euclid(&second).unwrap();
+
+        let (expected_quotient, expected_remainder) =
+            (&first_exact).checked_div_rem_euclid(&second_exact).unwrap();
+        prop_assert_eq!(quotient, expected_quotient);
+        prop_assert_eq!(remainder.is_small(), is_small(&expected_remainder));
+        prop_assert_eq!(Fraction::from(&remainder), expected_remainder);
+        for (result, expected) in [
+            (
+                (&first).checked_rem(&second).unwrap(),
+                (&first_exact).checked_rem(&second_exact).unwrap(),
+            ),
+            (
+                (&first).checked_rem_euclid(&second).unwrap(),
+                (&first_exact).checked_rem_euclid(&second_exact).unwrap(),
+            ),
+            (&first % &second, &first_exact % &second_exact),
+            (
+                first.clone().rem_euclid(second.clone()),
+                first_exact.clone().rem_euclid(second_exact.clone()),
+            ),
+        ] {
+            prop_assert_eq!(result.is_small(), is_small(&expected));
+            prop_assert_eq!(Fraction::from(&result), expected);
+        }
+        prop_assert_eq!(
+            first.clone().checked_div_euclid(second.clone()),
+            (&first_exact).checked_div_euclid(&second_exact)
+        );
+    }
+
+    #[test]
+    fn test_comparisons(first in rationals(), second in rationals()) {
+        let (first_exact, second_exact) =
+            (Fraction::from(&first), Fraction::from(&second));
+
+        prop_assert_eq!(first.cmp(&second), first_exact.cmp(&second_exact));
+        prop_assert_eq!(
+            first.partial_cmp(&second),
+            first_exact.partial_cmp(&second_exact)
+        );
+        prop_assert_eq!(first == second, first_exact == second_exact);
+    }
+
+    #[test]
+    fn test_rounding(value in rationals()) {
+        let exact = Fraction::from(&value);
+
+        prop_assert_eq!((&value).floor(), (&exact).floor());
+        prop_assert_eq!((&value).ceil(), (&exact).ceil());
+        prop_assert_eq!((&value).trunc(), (&exact).trunc());
+        prop_assert_eq!(value.clone().floor(), (&exact).floor());
+        prop_assert_eq!(value.clone().ceil(), (&exact).ceil());
+        prop_assert_eq!(value.clone().trunc(), (&exact).trunc());
+        for tie_breaking in [
+            TieBreaking::AwayFromZero,
+            TieBreaking::ToEven,
+            TieBreaking::ToOdd,
+            TieBreaking::TowardZero,
+        ] {
+            prop_assert_eq!(
+                (&value).round(tie_breaking),
+                (&exact).round(tie_breaking)
+            );
+            prop_assert_eq!(
+                value.clone().round(tie_breaking),
+                (&exact).round(tie_breaking)
+            );
+        }
+    }
+
+    #[test]
+    fn test_powers(value in rationals(), exponent in -8i8..8) {
+        let exact = Fraction::from(&value);
+
+        let result = value.clone().checked_pow(BigInt::from(exponent));
+
+        let expected = exact.checked_pow(BigInt::from(exponent));
+        prop_assert_eq!(result.is_some(), expected.is_some());
+        if let (Some(result), Some(expected)) = (result, expected) {
+            prop_assert_eq!(result.is_small(), is_small(&expected));
+            prop_assert_eq!(
+                value.pow(BigInt::from(exponent)),
+                result.clone()
+            );
+            prop_assert_eq!(Fraction::from(&result), expected);
+        }
+    }
+
+    #[test]
+    fn test_sign_operations(value in rationals()) {
+        let exact = Fraction::from(&value);
+
+        for (result, expected) in [
+            ((&value).abs(), (&exact).abs()),
+            (value.clone().abs(), (&exact).abs()),
+            (-&value, -&exact),
+            (-value.clone(), -&exact),
+        ] {
+            prop_assert_eq!(result.is_small(), is_small(&expected));
+            prop_assert_eq!(Fraction::from(&result), expected);
+        }
+    }
+}
+
+#[test]
+fn test_promotion_on_overflow() {
+    let maximum = Rational::from(i64::MAX);
+    let one = Rational::from(1i64);
+
+    let sum = &maximum + &one;
+    assert!(!sum.is_small());
+    assert_eq!(
+        Fraction::from(&sum),
+        Fraction::from(&maximum) + Fraction::from(&one)
+    );
+    let difference = &sum - &one;
+    assert!(difference.is_small());
+    assert_eq!(difference, maximum);
+}
+
+#[test]
+fn test_small_boundaries() {
+    let minimum = Rational::from(i64::MIN);
+    let maximum = Rational::from(i64::MAX);
+    let one = Rational::from(1i64);
+
+    let modulus = (&minimum).abs();
+    assert!(!modulus.is_small());
+    assert_eq!(
+        Fraction::from(&modulus),
+        -Fraction::from(BigInt::from(i64::MIN))
+    );
+    let negated = -&minimum;
+    assert!(!negated.is_small());
+    assert_eq!(negated, modulus);
+    assert!((-&negated).is_small());
+    assert_eq!(-negated, minimum);
+    assert!(minimum < maximum);
+    assert!(modulus > maximum);
+    assert_eq!((&modulus - &one).cmp(&maximum), Ordering::Equal);
+    assert_eq!((&minimum).floor(), BigInt::from(i64::MIN));
+    assert_eq!((&minimum).ceil(), BigInt::from(i64::MIN));
+    assert_eq!((&minimum).trunc(), BigInt::from(i64::MIN));
+    assert_eq!(
+        (&maximum).round(TieBreaking::AwayFromZero),
+        BigInt::from(i64::MAX)
+    );
+    let almost_one = Rational::from(
+        rithm::fraction::Fraction::new((1i64 << 62) + 1, (1i64 << 62) + 3)
+            .unwrap(),
+    );
+    assert_eq!((&almost_one).round(TieBreaking::ToEven), BigInt::from(1u8));
+    let half_minimum = Rational::from(
+        rithm::fraction::Fraction::new(i64::MIN + 1, 2).unwrap(),
+    );
+    assert_eq!(
+        (&half_minimum).round(TieBreaking::AwayFromZero),
+        BigInt::from(i64::MIN / 2)
+    );
+    assert_eq!((&half_minimum).ceil(), BigInt::from(i64::MIN / 2 + 1));
+    let remainder = &minimum % &maximum;
+    assert!(remainder.is_small());
+    assert_eq!(remainder, Rational::from(-1i64));
+    let remainder = (&minimum).rem_euclid(&maximum);
+    assert!(remainder.is_small());
+    assert_eq!(remainder, Rational::from(i64::MAX - 1));
+    assert_eq!((&minimum).div_euclid(&maximum), BigInt::from(-2i8));
+    assert_eq!((&minimum).div_euclid(&one), BigInt::from(i64::MIN));
+    assert_eq!(
+        (&minimum).div_euclid(&Rational::from(-1i64)),
+        -BigInt::from(i64::MIN)
+    );
+    let reciprocal = minimum.clone().checked_pow(BigInt::from(-1i8)).unwrap();
+    assert!(!reciprocal.is_small());
+    assert_eq!(
+        Fraction::from(&reciprocal),
+        Fraction::new(BigInt::from(-1i8), -BigInt::from(i64::MIN)).unwrap()
+    );
+    assert!(maximum
+        .clone()
+        .checked_pow(BigInt::from(-1i8))
+        .unwrap()
+        .is_small());
+    assert!(!minimum.checked_pow(BigInt::from(2u8)).unwrap().is_small());
+    assert_eq!(Rational::from(0i64).checked_pow(BigInt::from(-1i8)), None);
+}
@@ -0,0 +1,26 @@
+use proptest::prelude::*;
+use rithm::NumericHash;
+
+use crate::strategies::{fractions, i64_fractions, Fraction, Rational};
+
+proptest! {
+    #[test]
+    fn test_small_connection(value in i64_fractions()) {
+        prop_assert_eq!(
+            Rational::from(value.clone()).numeric_hash(),
+            value.numeric_hash()
+        );
+        prop_assert_eq!(
+            Rational::from(Fraction::from(value.clone())).numeric_hash(),
+            value.numeric_hash()
+        );
+    }
+
+    #[test]
+    fn test_big_connection(value in fractions()) {
+        prop_assert_eq!(
+            Rational::from(value.clone()).numeric_hash(),
+            value.numeric_hash()
+        );
+    }
+}
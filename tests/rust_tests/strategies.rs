@@ -0,0 +1,69 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use traiter::numbers::{Endianness, FromBytes, Zeroable};
+
+pub(crate) type BigInt = rithm::big_int::BigInt<u32, 31>;
+pub(crate) type Fraction = rithm::fraction::Fraction<BigInt>;
+pub(crate) type Rational = rithm::rational::Rational<u32, 31>;
+pub(crate) type SmallDigitBigInt = rithm::big_int::BigInt<u8, 7>;
+
+pub(crate) fn big_ints() -> impl Strategy<Value = BigInt> {
+    vec(any::<u8>(), 1..48)
+        .prop_map(|bytes| BigInt::from_bytes(&bytes, Endianness::Little))
+}
+
+pub(crate) fn non_zero_big_ints() -> impl Strategy<Value = BigInt> {
+    big_ints().prop_filter("zero", |value| !value.is_zero())
+}
+
+pub(crate) fn fractions() -> impl Strategy<Value = Fraction> {
+    (big_ints(), non_zero_big_ints()).prop_map(|(numerator, denominator)| {
+        Fraction::new(numerator, denominator).unwrap()
+    })
+}
+
+pub(crate) fn rationals() -> impl Strategy<Value = Rational> {
+    prop_oneof![
+        (any::<i64>(), 1..=i64::MAX).prop_map(|(numerator, denominator)| {
+            Rational::from(
+                rithm::fraction::Fraction::new(numerator, denominator)
+                    .unwrap(),
+            )
+        }),
+        any::<i8>().prop_map(|value| Rational::from(i64::from(value))),
+        fractions().prop_map(Rational::from),
+    ]
+}
+
+macro_rules! integer_fractions_impl {
+    ($($name:ident => $integer:ty)*) => ($(
+        pub(crate) fn $name(
+        ) -> impl Strategy<Value = rithm::fraction::Fraction<$integer>> {
+            (
+                prop_oneof![
+                    Just(<$integer>::MIN),
+                    Just(<$integer>::MAX),
+                    any::<$integer>(),
+                    any::<i8>().prop_map(|value| value as $integer),
+                ],
+                prop_oneof![
+                    Just(<$integer>::MAX),
+                    1..=<$integer>::MAX,
+                    1..=(16 as $integer),
+                ],
+            )
+                .prop_map(|(numerator, denominator)| {
+                    rithm::fraction::Fraction::new(numerator, denominator)
+                        .unwrap()
+                })
+        }
+    )*)
+}
+
+integer_fractions_impl!(
+    i8_fractions => i8
+    i64_fractions => i64
+    i128_fractions => i128
+    u8_fractions => u8
+    u128_fractions => u128
+);